@@ -8,8 +8,10 @@ use crate::idx::planner::executor::QueryExecutor;
 use crate::idx::planner::{IterationStage, QueryPlanner};
 use crate::idx::trees::store::IndexStores;
 use crate::kvs;
+use crate::sql::permission::Permission;
 use crate::sql::value::Value;
 use channel::Sender;
+use chrono_tz::Tz;
 use futures::lock::MutexLockFuture;
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -22,7 +24,7 @@ use std::fmt::{self, Debug};
 	feature = "kv-tikv",
 ))]
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use trice::Instant;
@@ -62,6 +64,12 @@ pub struct Context<'a> {
 	index_stores: IndexStores,
 	// Capabilities
 	capabilities: Arc<Capabilities>,
+	// The fallback permission for guests on tables without their own
+	guest_permissions: Option<Arc<Permission>>,
+	// The default timezone used when formatting datetimes
+	timezone: Option<Tz>,
+	// The number of records examined by iterators, shared with child contexts
+	examined: Arc<AtomicUsize>,
 	#[cfg(any(
 		feature = "kv-mem",
 		feature = "kv-surrealkv",
@@ -96,6 +104,7 @@ impl<'a> Context<'a> {
 	pub(crate) fn from_ds(
 		time_out: Option<Duration>,
 		capabilities: Capabilities,
+		timezone: Option<Tz>,
 		index_stores: IndexStores,
 		#[cfg(any(
 			feature = "kv-mem",
@@ -116,6 +125,9 @@ impl<'a> Context<'a> {
 			query_executor: None,
 			iteration_stage: None,
 			capabilities: Arc::new(capabilities),
+			guest_permissions: None,
+			timezone,
+			examined: Arc::new(AtomicUsize::new(0)),
 			index_stores,
 			#[cfg(any(
 				feature = "kv-mem",
@@ -144,6 +156,9 @@ impl<'a> Context<'a> {
 			query_executor: None,
 			iteration_stage: None,
 			capabilities: Arc::new(Capabilities::default()),
+			guest_permissions: None,
+			timezone: None,
+			examined: Arc::new(AtomicUsize::new(0)),
 			index_stores: IndexStores::default(),
 			#[cfg(any(
 				feature = "kv-mem",
@@ -169,6 +184,9 @@ impl<'a> Context<'a> {
 			query_executor: parent.query_executor.clone(),
 			iteration_stage: parent.iteration_stage.clone(),
 			capabilities: parent.capabilities.clone(),
+			guest_permissions: parent.guest_permissions.clone(),
+			timezone: parent.timezone,
+			examined: parent.examined.clone(),
 			index_stores: parent.index_stores.clone(),
 			#[cfg(any(
 				feature = "kv-mem",
@@ -360,6 +378,31 @@ impl<'a> Context<'a> {
 		self.capabilities.clone()
 	}
 
+	/// Set the fallback permission for guests on tables without their own
+	pub fn add_guest_permissions(&mut self, perm: Option<Arc<Permission>>) {
+		self.guest_permissions = perm;
+	}
+
+	/// Get the fallback permission for guests on tables without their own
+	pub fn guest_permissions(&self) -> Option<&Permission> {
+		self.guest_permissions.as_deref()
+	}
+
+	/// Get the default timezone used when formatting datetimes
+	pub fn timezone(&self) -> Option<Tz> {
+		self.timezone
+	}
+
+	/// Count a record examined by an iterator, whether or not it ends up in the results
+	pub(crate) fn add_examined(&self) {
+		self.examined.fetch_add(1, Ordering::Relaxed);
+	}
+
+	/// Get the number of records examined by iterators in this context and its children
+	pub(crate) fn examined(&self) -> usize {
+		self.examined.load(Ordering::Relaxed)
+	}
+
 	/// Check if scripting is allowed
 	#[allow(dead_code)]
 	pub fn check_allowed_scripting(&self) -> Result<(), Error> {
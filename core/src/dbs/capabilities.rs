@@ -17,17 +17,27 @@ pub struct FuncTarget(pub String, pub Option<String>);
 impl fmt::Display for FuncTarget {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match &self.1 {
-			Some(name) => write!(f, "{}:{}", self.0, name),
+			Some(name) => write!(f, "{}::{}", self.0, name),
 			None => write!(f, "{}::*", self.0),
 		}
 	}
 }
 
+impl FuncTarget {
+	/// Check if a function name matches this target's name, which may end with a `::*` wildcard
+	fn matches_name(pattern: &str, name: &str) -> bool {
+		match pattern.strip_suffix('*') {
+			Some(prefix) => name.starts_with(prefix),
+			None => pattern == name,
+		}
+	}
+}
+
 impl Target for FuncTarget {
 	fn matches(&self, elem: &FuncTarget) -> bool {
 		match self {
 			Self(family, Some(name)) => {
-				family == &elem.0 && (elem.1.as_ref().is_some_and(|n| n == name))
+				family == &elem.0 && (elem.1.as_ref().is_some_and(|n| Self::matches_name(name, n)))
 			}
 			Self(family, None) => family == &elem.0,
 		}
@@ -41,7 +51,7 @@ impl Target<str> for FuncTarget {
 				return false;
 			};
 
-			f == self.0 && r == x
+			f == self.0 && Self::matches_name(x, r)
 		} else {
 			let f = elem.split_once("::").map(|(f, _)| f).unwrap_or(elem);
 			f == self.0
@@ -65,7 +75,7 @@ impl fmt::Display for ParseFuncTargetError {
 			ParseFuncTargetError::InvalidWildcardFamily => {
 				write!(
 					f,
-					"invalid function target wildcard family, only first part of function can be wildcarded"
+					"invalid function target wildcard, only the last part of a function can be wildcarded"
 				)
 			}
 		}
@@ -82,19 +92,25 @@ impl std::str::FromStr for FuncTarget {
 			return Err(ParseFuncTargetError::InvalidName);
 		}
 
-		if let Some(family) = s.strip_suffix("::*") {
-			if family.contains("::") {
+		if let Some(prefix) = s.strip_suffix("::*") {
+			if prefix.contains('*') {
 				return Err(ParseFuncTargetError::InvalidWildcardFamily);
 			}
 
-			if !family.bytes().all(|x| x.is_ascii_alphanumeric()) {
+			if !prefix.bytes().all(|x| x.is_ascii_alphanumeric() || x == b'_' || x == b':') {
 				return Err(ParseFuncTargetError::InvalidName);
 			}
 
-			return Ok(FuncTarget(family.to_string(), None));
+			// A nested wildcard such as `fn::billing::*` matches every function in that module
+			return Ok(match prefix.split_once("::") {
+				Some((family, module)) => {
+					FuncTarget(family.to_string(), Some(format!("{module}::*")))
+				}
+				None => FuncTarget(prefix.to_string(), None),
+			});
 		}
 
-		if !s.bytes().all(|x| x.is_ascii_alphanumeric() || x == b':') {
+		if !s.bytes().all(|x| x.is_ascii_alphanumeric() || x == b'_' || x == b':') {
 			return Err(ParseFuncTargetError::InvalidName);
 		}
 
@@ -364,6 +380,7 @@ mod tests {
 	#[test]
 	fn test_invalid_func_target() {
 		FuncTarget::from_str("te::*st").unwrap_err();
+		FuncTarget::from_str("fn::*::x::*").unwrap_err();
 		FuncTarget::from_str("\0::st").unwrap_err();
 		FuncTarget::from_str("").unwrap_err();
 		FuncTarget::from_str("❤️").unwrap_err();
@@ -381,6 +398,17 @@ mod tests {
 
 		assert!(FuncTarget::from_str("test::name").unwrap().matches("test::name"));
 		assert!(!FuncTarget::from_str("test::name").unwrap().matches("test::name2"));
+
+		assert!(FuncTarget::from_str("fn::get_user").unwrap().matches("fn::get_user"));
+		assert!(!FuncTarget::from_str("fn::get_user").unwrap().matches("fn::get_users"));
+
+		assert!(FuncTarget::from_str("fn::billing::*").unwrap().matches("fn::billing::invoice"));
+		assert!(FuncTarget::from_str("fn::billing::*").unwrap().matches("fn::billing::tax::rate"));
+		assert!(!FuncTarget::from_str("fn::billing::*").unwrap().matches("fn::billing"));
+		assert!(!FuncTarget::from_str("fn::billing::*").unwrap().matches("fn::billings::invoice"));
+		assert!(FuncTarget::from_str("fn::billing::*")
+			.unwrap()
+			.matches(&FuncTarget::from_str("fn::billing::invoice").unwrap()));
 	}
 
 	#[test]
@@ -134,6 +134,7 @@ impl<'a> Executor<'a> {
 		Response {
 			time: v.time,
 			result: Err(Error::QueryCancelled),
+			examined: v.examined,
 			query_type: QueryType::Other,
 		}
 	}
@@ -143,6 +144,10 @@ impl<'a> Executor<'a> {
 			true => Response {
 				time: v.time,
 				result: match v.result {
+					// Keep conflicts recognisable so that the transaction can be retried
+					Ok(_) if matches!(commit_error, Some(Error::TxRetryable)) => {
+						Err(Error::TxRetryable)
+					}
 					Ok(_) => Err(commit_error
 						.as_ref()
 						.map(|e| Error::QueryNotExecutedDetail {
@@ -151,6 +156,7 @@ impl<'a> Executor<'a> {
 						.unwrap_or(Error::QueryNotExecuted)),
 					Err(e) => Err(e),
 				},
+				examined: v.examined,
 				query_type: QueryType::Other,
 			},
 			_ => v,
@@ -236,6 +242,8 @@ impl<'a> Executor<'a> {
 			}
 			// Get the statement start time
 			let now = Instant::now();
+			// Get the records examined so far by earlier statements
+			let examined = ctx.examined();
 			// Check if this is a LIVE statement
 			let is_stm_live = matches!(stm, Statement::Live(_));
 			// Check if this is a KILL statement
@@ -452,6 +460,7 @@ impl<'a> Executor<'a> {
 					self.err = true;
 					e
 				}),
+				examined: ctx.examined() - examined,
 				query_type: match (is_stm_live, is_stm_kill) {
 					(true, _) => {
 						if let Some(lqs) = self.consume_committed_live_query_registrations().await {
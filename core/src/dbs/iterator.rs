@@ -596,6 +596,8 @@ impl Iterator {
 		stm: &Statement<'_>,
 		res: Result<Value, Error>,
 	) {
+		// Count the record, even if it was filtered out
+		ctx.add_examined();
 		// Process the result
 		match res {
 			Err(Error::Ignore) => {
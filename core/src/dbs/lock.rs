@@ -0,0 +1,19 @@
+//! Advisory locks on records
+//!
+//! A lock is stored under its own key next to the table of the locked record, so it is not
+//! visible to queries, exports or `INFO` statements, and can only be released with its token.
+use crate::sql::{Datetime, Uuid};
+use derive::Store;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+
+/// An advisory lock held on a record until it is released or expires
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, Store)]
+#[non_exhaustive]
+pub struct RecordLock {
+	/// The token of the caller which claimed the lock
+	pub token: Uuid,
+	/// When the lock expires
+	pub expires: Datetime,
+}
@@ -6,6 +6,7 @@ mod distinct;
 mod executor;
 mod group;
 mod iterator;
+mod lock;
 mod notification;
 mod options;
 mod plan;
@@ -17,6 +18,7 @@ mod statement;
 mod store;
 mod transaction;
 mod variables;
+mod version;
 
 pub mod capabilities;
 pub mod lifecycle;
@@ -28,9 +30,11 @@ pub use self::notification::*;
 pub use self::options::*;
 pub use self::response::*;
 pub use self::session::*;
+pub use self::version::*;
 
 pub(crate) use self::executor::*;
 pub(crate) use self::iterator::*;
+pub(crate) use self::lock::*;
 pub(crate) use self::statement::*;
 pub(crate) use self::transaction::*;
 pub(crate) use self::variables::*;
@@ -26,6 +26,8 @@ pub enum QueryType {
 pub struct Response {
 	pub time: Duration,
 	pub result: Result<Value, Error>,
+	/// The number of records examined while executing the statement
+	pub examined: usize,
 	// Record the query type in case processing the response is necessary (such as tracking live queries).
 	pub query_type: QueryType,
 }
@@ -0,0 +1,19 @@
+//! Versions of records
+//!
+//! The version of a record is stored under its own key next to the table of the record, so it
+//! is kept for as long as the record, however long the change feed of its table retains changes.
+use crate::sql::Datetime;
+use derive::Store;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+
+/// The version of a record on a table with a change feed, and when it was last modified
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, Store)]
+#[non_exhaustive]
+pub struct RecordVersion {
+	/// The number of times the record has been written, starting at `1` when it is created
+	pub version: u64,
+	/// When the record was last written
+	pub modified: Datetime,
+}
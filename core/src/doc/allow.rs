@@ -30,6 +30,15 @@ impl<'a> Document<'a> {
 				} else {
 					&tb.permissions.update
 				};
+				// Guests fall back to the configured permission when reading tables without their own
+				let perms = match ctx.guest_permissions() {
+					Some(guest)
+						if stm.is_select() && tb.default_permissions && opt.auth.is_anon() =>
+					{
+						guest
+					}
+					_ => perms,
+				};
 				// Process the table permissions
 				match perms {
 					Permission::None => return Err(Error::Ignore),
@@ -1,8 +1,10 @@
 use crate::ctx::Context;
 use crate::dbs::Options;
+use crate::dbs::RecordVersion;
 use crate::dbs::Statement;
 use crate::doc::Document;
 use crate::err::Error;
+use crate::sql::Datetime;
 
 impl<'a> Document<'a> {
 	pub async fn changefeeds(
@@ -36,6 +38,21 @@ impl<'a> Document<'a> {
 				self.current.doc.clone(),
 				cf.store_diff,
 			);
+			// Keep the version of the record with it, as the change feed only retains changes for a while
+			let key = crate::key::table::rv::new(opt.ns()?, opt.db()?, tb, &id.id);
+			if self.current.doc.is_some() {
+				let version = match run.get(key.clone()).await? {
+					Some(v) => RecordVersion::from(v).version + 1,
+					None => 1,
+				};
+				let val = RecordVersion {
+					version,
+					modified: Datetime::default(),
+				};
+				run.set(key, val).await?;
+			} else {
+				run.del(key).await?;
+			}
 		}
 		// Carry on
 		Ok(())
@@ -3,6 +3,7 @@ use crate::dbs::Options;
 use crate::dbs::{Force, Statement};
 use crate::doc::{CursorDoc, Document};
 use crate::err::Error;
+use crate::idx::builds::Writes;
 use crate::idx::ft::FtIndex;
 use crate::idx::trees::mtree::MTreeIndex;
 use crate::idx::IndexKeyBase;
@@ -13,6 +14,7 @@ use crate::sql::index::{HnswParams, Index, MTreeParams, SearchParams};
 use crate::sql::statements::DefineIndexStatement;
 use crate::sql::{Part, Thing, Value};
 use reblessive::tree::Stk;
+use std::borrow::Cow;
 
 impl<'a> Document<'a> {
 	pub async fn index(
@@ -41,6 +43,11 @@ impl<'a> Document<'a> {
 		}
 		// Get the record id
 		let rid = self.id.as_ref().unwrap();
+		// Track the write for indexes being built which this transaction can't see
+		if !targeted_force {
+			let builds = ctx.get_index_stores().builds();
+			builds.record(opt.ns()?, opt.db()?, rid, &ixs, &self.initial.doc);
+		}
 		// Loop through all index statements
 		for ix in ixs.iter() {
 			// Calculate old values
@@ -55,13 +62,7 @@ impl<'a> Document<'a> {
 				let mut ic = IndexOperation::new(opt, ix, o, n, rid);
 
 				// Index operation dispatching
-				match &ix.index {
-					Index::Uniq => ic.index_unique(ctx).await?,
-					Index::Idx => ic.index_non_unique(ctx).await?,
-					Index::Search(p) => ic.index_full_text(stk, ctx, p).await?,
-					Index::MTree(p) => ic.index_mtree(stk, ctx, p).await?,
-					Index::Hnsw(p) => ic.index_hnsw(ctx, p).await?,
-				};
+				ic.compute(stk, ctx).await?;
 			}
 		}
 		// Carry on
@@ -69,6 +70,44 @@ impl<'a> Document<'a> {
 	}
 }
 
+/// Brings an index up to date with the records written to its table while it was being built
+///
+/// The entries for the values the records had before they were written are removed, and the
+/// entries for their current values are added where they are missing.
+pub(crate) async fn catch_up_index(
+	stk: &mut Stk,
+	ctx: &Context<'_>,
+	opt: &Options,
+	ix: &DefineIndexStatement,
+	writes: Writes,
+) -> Result<(), Error> {
+	for (rid, before) in writes {
+		// Fetch the record as it is now
+		let key = crate::key::thing::new(opt.ns()?, opt.db()?, &rid.tb, &rid.id);
+		let val = ctx.tx_lock().await.get(key).await?;
+		let current = match val {
+			Some(v) => Value::from(v),
+			None => Value::None,
+		};
+		let doc = CursorDoc::new(Some(&rid), None, Cow::Owned(current));
+		let n = build_opt_values(stk, ctx, opt, ix, &doc).await?;
+		for value in before {
+			let doc = CursorDoc::new(Some(&rid), None, Cow::Owned(value));
+			let o = build_opt_values(stk, ctx, opt, ix, &doc).await?;
+			if o != n {
+				IndexOperation::new(opt, ix, o, None, &rid).compute(stk, ctx).await?;
+			}
+		}
+		let mut ic = IndexOperation::new(opt, ix, n.clone(), n, &rid);
+		match &ix.index {
+			// Entries which are already there are left alone rather than written again
+			Index::Uniq | Index::Idx => ic.index_missing(ctx).await?,
+			_ => ic.compute(stk, ctx).await?,
+		}
+	}
+	Ok(())
+}
+
 /// Extract from the given document, the values required by the index and put then in an array.
 /// Eg. IF the index is composed of the columns `name` and `instrument`
 /// Given this doc: { "id": 1, "instrument":"piano", "name":"Tobie" }
@@ -257,6 +296,16 @@ impl<'a> IndexOperation<'a> {
 		}
 	}
 
+	async fn compute(&mut self, stk: &mut Stk, ctx: &Context<'_>) -> Result<(), Error> {
+		match &self.ix.index {
+			Index::Uniq => self.index_unique(ctx).await,
+			Index::Idx => self.index_non_unique(ctx).await,
+			Index::Search(p) => self.index_full_text(stk, ctx, p).await,
+			Index::MTree(p) => self.index_mtree(stk, ctx, p).await,
+			Index::Hnsw(p) => self.index_hnsw(ctx, p).await,
+		}
+	}
+
 	fn get_unique_index_key(&self, v: &'a Array) -> Result<key::index::Index, Error> {
 		Ok(crate::key::index::Index::new(
 			self.opt.ns()?,
@@ -341,6 +390,40 @@ impl<'a> IndexOperation<'a> {
 		Ok(())
 	}
 
+	/// Adds the entries of the new values which are missing from a unique or non-unique index
+	async fn index_missing(&mut self, ctx: &Context<'_>) -> Result<(), Error> {
+		let mut run = ctx.tx_lock().await;
+		if let Some(n) = self.n.take() {
+			let unique = matches!(self.ix.index, Index::Uniq);
+			let i = Indexable::new(n, self.ix);
+			for n in i {
+				if unique && n.is_all_none_or_null() {
+					continue;
+				}
+				let key = match unique {
+					true => self.get_unique_index_key(&n)?,
+					false => self.get_non_unique_index_key(&n)?,
+				};
+				match run.get(key).await? {
+					None => {
+						let key = match unique {
+							true => self.get_unique_index_key(&n)?,
+							false => self.get_non_unique_index_key(&n)?,
+						};
+						run.set(key, self.rid).await?;
+					}
+					Some(val) => {
+						let rid: Thing = val.into();
+						if rid != *self.rid {
+							return self.err_index_exists(rid, n);
+						}
+					}
+				}
+			}
+		}
+		Ok(())
+	}
+
 	fn err_index_exists(&self, rid: Thing, n: Array) -> Result<(), Error> {
 		Err(Error::IndexExists {
 			thing: rid,
@@ -82,8 +82,13 @@ impl<'a> Document<'a> {
 		if opt.check_perms(stm.into())? {
 			// Get the table
 			let tb = self.tb(ctx, opt).await?;
+			// Guests fall back to the configured permission when reading tables without their own
+			let perms = match ctx.guest_permissions() {
+				Some(guest) if tb.default_permissions && opt.auth.is_anon() => guest,
+				_ => &tb.permissions.select,
+			};
 			// Process the table permissions
-			match &tb.permissions.select {
+			match perms {
 				Permission::None => return Err(Error::Ignore),
 				Permission::Full => return Ok(()),
 				Permission::Specific(e) => {
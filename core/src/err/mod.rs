@@ -70,6 +70,10 @@ pub enum Error {
 	#[error("There was a problem with the underlying datastore: {0}")]
 	Ds(String),
 
+	/// The underlying datastore could not be opened or reached
+	#[error("Unable to connect to the underlying datastore: {0}")]
+	DsUnavailable(String),
+
 	/// There was a problem with a datastore transaction
 	#[error("There was a problem with a datastore transaction: {0}")]
 	Tx(String),
@@ -86,6 +90,10 @@ pub enum Error {
 	#[error("Couldn't write to a read only transaction")]
 	TxReadonly,
 
+	/// The transaction conflicted with a concurrent transaction and can be retried
+	#[error("Failed to commit transaction due to a conflict with a concurrent transaction. This transaction can be retried")]
+	TxRetryable,
+
 	/// The conditional value in the request was not equal
 	#[error("Value being checked was not correct")]
 	TxConditionNotMet,
@@ -376,6 +384,12 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The requested table does not have a change feed
+	#[error("The table '{value}' does not have a change feed defined")]
+	NoChangeFeed {
+		value: String,
+	},
+
 	/// The requested live query does not exist
 	#[error("The live query '{value}' does not exist")]
 	LvNotFound {
@@ -403,6 +417,13 @@ pub enum Error {
 	#[error("Unsupported distance: {0}")]
 	UnsupportedDistance(Distance),
 
+	/// The storage engine does not support the requested feature
+	#[error("The `{engine}` storage engine does not support {feature}")]
+	Unsupported {
+		engine: String,
+		feature: String,
+	},
+
 	/// The requested root user does not exist
 	#[error("The root user '{value}' does not exist")]
 	UserRootNotFound {
@@ -1086,7 +1107,10 @@ impl From<rocksdb::Error> for Error {
 #[cfg(feature = "kv-surrealkv")]
 impl From<surrealkv::Error> for Error {
 	fn from(e: surrealkv::Error) -> Error {
-		Error::Tx(e.to_string())
+		match e {
+			surrealkv::Error::TransactionReadConflict => Error::TxRetryable,
+			_ => Error::Tx(e.to_string()),
+		}
 	}
 }
 
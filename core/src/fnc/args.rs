@@ -74,6 +74,12 @@ impl FromArg for Bytes {
 	}
 }
 
+impl FromArg for bool {
+	fn from_arg(arg: Value) -> Result<Self, Error> {
+		arg.coerce_to_bool()
+	}
+}
+
 impl FromArg for i64 {
 	fn from_arg(arg: Value) -> Result<Self, Error> {
 		arg.coerce_to_i64()
@@ -317,6 +323,34 @@ impl<A: FromArg, B: FromArg, C: FromArg, D: FromArg> FromArgs for (A, B, C, Opti
 	}
 }
 
+// Some functions take 5 or 6 arguments, so the sixth argument is optional.
+impl<A: FromArg, B: FromArg, C: FromArg, D: FromArg, E: FromArg, F: FromArg> FromArgs
+	for (A, B, C, D, E, Option<F>)
+{
+	fn from_args(name: &str, args: Vec<Value>) -> Result<Self, Error> {
+		let err = || Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("Expected 5 or 6 arguments."),
+		};
+		// Process the function arguments
+		let mut args = args.into_iter();
+
+		let a: A = get_arg(name, 1, &mut args, err)?;
+		let b: B = get_arg(name, 2, &mut args, err)?;
+		let c: C = get_arg(name, 3, &mut args, err)?;
+		let d: D = get_arg(name, 4, &mut args, err)?;
+		let e: E = get_arg(name, 5, &mut args, err)?;
+		let f: Option<F> = get_opt_arg(name, 6, &mut args)?;
+
+		// Process additional function arguments
+		if args.next().is_some() {
+			// Too many arguments
+			return Err(err());
+		}
+		Ok((a, b, c, d, e, f))
+	}
+}
+
 // Some functions take 0, 1, or 2 arguments, so both arguments are optional.
 // It is safe to assume that, if the first argument is None, the second argument will also be None.
 impl<A: FromArg, B: FromArg> FromArgs for (Option<A>, Option<B>) {
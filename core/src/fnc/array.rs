@@ -11,8 +11,33 @@ use crate::sql::array::Transpose;
 use crate::sql::array::Union;
 use crate::sql::array::Uniq;
 use crate::sql::value::Value;
+use crate::sql::Object;
 
 use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::BTreeMap;
+
+/// Returns an error if an array of this many values is too much to allocate
+pub(crate) fn limit(name: &str, n: usize) -> Result<(), Error> {
+	const LIMIT: usize = 2usize.pow(20);
+	if n > LIMIT {
+		Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: format!("Output must not exceed {LIMIT} values."),
+		})
+	} else {
+		Ok(())
+	}
+}
+
+/// Converts a count argument, which must not be negative
+fn count(name: &str, n: i64) -> Result<usize, Error> {
+	usize::try_from(n).map_err(|_| Error::InvalidArguments {
+		name: name.to_owned(),
+		message: String::from("The count must not be negative."),
+	})
+}
 
 pub fn add((mut array, value): (Array, Value)) -> Result<Value, Error> {
 	match value {
@@ -54,6 +79,19 @@ pub fn at((array, i): (Array, i64)) -> Result<Value, Error> {
 	Ok(array.get(idx).cloned().unwrap_or_default())
 }
 
+/// Finds a value in an array sorted in ascending order
+///
+/// Returns the index of a matching value, or `-(pos + 1)` for the position at which the value
+/// would be inserted to keep the array sorted. The array is assumed to be sorted already, and
+/// the result is meaningless if it is not.
+pub fn binary_search((array, value): (Array, Value)) -> Result<Value, Error> {
+	Ok(match array.binary_search(&value) {
+		Ok(i) => i as i64,
+		Err(i) => -(i as i64) - 1,
+	}
+	.into())
+}
+
 pub fn boolean_and((lh, rh): (Array, Array)) -> Result<Value, Error> {
 	let longest_length = lh.len().max(rh.len());
 	let mut results = Array::with_capacity(longest_length);
@@ -149,6 +187,12 @@ pub fn filter_index((array, value): (Array, Value)) -> Result<Value, Error> {
 		.into())
 }
 
+pub fn fill((value, n): (Value, i64)) -> Result<Value, Error> {
+	let n = count("array::fill", n)?;
+	limit("array::fill", n)?;
+	Ok(vec![value; n].into())
+}
+
 pub fn find_index((array, value): (Array, Value)) -> Result<Value, Error> {
 	Ok(array
 		.iter()
@@ -157,6 +201,14 @@ pub fn find_index((array, value): (Array, Value)) -> Result<Value, Error> {
 		.map_or(Value::Null, |(i, _v)| i.into()))
 }
 
+pub fn find_last_index((array, value): (Array, Value)) -> Result<Value, Error> {
+	Ok(array
+		.iter()
+		.enumerate()
+		.rfind(|(_i, v)| **v == value)
+		.map_or(Value::Null, |(i, _v)| i.into()))
+}
+
 pub fn first((array,): (Array,)) -> Result<Value, Error> {
 	if let [first, ..] = &array[0..] {
 		Ok(first.to_owned())
@@ -169,6 +221,21 @@ pub fn flatten((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.flatten().into())
 }
 
+/// Counts how many times each distinct value appears, keyed by the value as a raw string
+///
+/// Values are told apart by equality, so `1` and `1.0` are counted together. Distinct values
+/// with the same raw string, such as `1` and `'1'`, share a key and their counts are added up.
+pub fn frequencies((array,): (Array,)) -> Result<Value, Error> {
+	let mut counts: BTreeMap<Value, usize> = BTreeMap::new();
+	for value in array {
+		*counts.entry(value).or_default() += 1;
+	}
+	// Key each value by its SurrealQL form, so the string '1' and the number 1 stay apart
+	let frequencies: BTreeMap<String, Value> =
+		counts.into_iter().map(|(value, count)| (value.to_string(), count.into())).collect();
+	Ok(Object::from(frequencies).into())
+}
+
 pub fn group((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.flatten().uniq().into())
 }
@@ -329,11 +396,42 @@ pub fn remove((mut array, mut index): (Array, i64)) -> Result<Value, Error> {
 	Ok(array.into())
 }
 
+pub fn repeat((array, n): (Array, i64)) -> Result<Value, Error> {
+	let n = count("array::repeat", n)?;
+	let len = array.len().saturating_mul(n);
+	limit("array::repeat", len)?;
+	Ok(array.0.iter().cycle().take(len).cloned().collect::<Vec<_>>().into())
+}
+
 pub fn reverse((mut array,): (Array,)) -> Result<Value, Error> {
 	array.reverse();
 	Ok(array.into())
 }
 
+pub fn rotate((mut array, n): (Array, i64)) -> Result<Value, Error> {
+	if !array.is_empty() {
+		// Negative values rotate right, and values past the length wrap around
+		let mid = n.rem_euclid(array.len() as i64) as usize;
+		array.rotate_left(mid);
+	}
+	Ok(array.into())
+}
+
+pub fn sample((mut array, count, seed): (Array, i64, Option<i64>)) -> Result<Value, Error> {
+	if count < 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::sample"),
+			message: String::from("The second argument must be a non-negative number."),
+		});
+	}
+	let count = (count as usize).min(array.len());
+	let sample = match seed {
+		Some(seed) => array.0.partial_shuffle(&mut StdRng::seed_from_u64(seed as u64), count).0,
+		None => array.0.partial_shuffle(&mut rand::thread_rng(), count).0,
+	};
+	Ok(Value::from(sample.to_vec()))
+}
+
 pub fn shuffle((mut array,): (Array,)) -> Result<Value, Error> {
 	let mut rng = rand::thread_rng();
 	array.0.shuffle(&mut rng);
@@ -361,6 +459,21 @@ pub fn slice((array, beg, lim): (Array, Option<isize>, Option<isize>)) -> Result
 	.into())
 }
 
+/// Removes `count` values at `start` and inserts the given values in their place, where a
+/// negative `start` counts from the end and out of range positions are clamped to the array
+pub fn splice(
+	(mut array, start, count, items): (Array, i64, i64, Option<Array>),
+) -> Result<Value, Error> {
+	let len = array.len();
+	let start = match start {
+		v if v < 0 => len.saturating_sub(v.unsigned_abs() as usize),
+		v => (v as usize).min(len),
+	};
+	let end = start.saturating_add(count.max(0) as usize).min(len);
+	array.0.splice(start..end, items.unwrap_or_default());
+	Ok(array.into())
+}
+
 pub fn sort((mut array, order): (Array, Option<Value>)) -> Result<Value, Error> {
 	match order {
 		// If "asc", sort ascending
@@ -391,8 +504,55 @@ pub fn sort((mut array, order): (Array, Option<Value>)) -> Result<Value, Error>
 	}
 }
 
-pub fn transpose((array,): (Array,)) -> Result<Value, Error> {
-	Ok(array.transpose().into())
+pub fn transpose((array, opts): (Array, Option<Object>)) -> Result<Value, Error> {
+	let ragged = match opts.as_ref().and_then(|o| o.get("ragged")) {
+		None | Some(Value::None | Value::Null) => "error",
+		Some(Value::Strand(v)) if matches!(v.as_str(), "compact" | "error" | "pad") => v.as_str(),
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: String::from("array::transpose"),
+				message: format!(
+					"Expected the 'ragged' option to be one of 'compact', 'error' or 'pad', found {v}"
+				),
+			})
+		}
+	};
+	// Values which are not arrays are treated as rows of one value
+	let width = |v: &Value| match v {
+		Value::Array(v) => v.len(),
+		_ => 1,
+	};
+	let longest = array.iter().map(width).max().unwrap_or_default();
+	match ragged {
+		"error" => {
+			if let Some((i, v)) = array.iter().enumerate().find(|(_, v)| width(v) != longest) {
+				return Err(Error::InvalidArguments {
+					name: String::from("array::transpose"),
+					message: format!(
+						"Every row must have the same length, but row {i} has {} values and the longest row has {longest}.",
+						width(v)
+					),
+				});
+			}
+			Ok(array.transpose().into())
+		}
+		"pad" => {
+			let array: Array = array
+				.into_iter()
+				.map(|v| {
+					let mut row = match v {
+						Value::Array(v) => v,
+						v => Array::from(vec![v]),
+					};
+					row.resize(longest, Value::Null);
+					Value::Array(row)
+				})
+				.collect::<Vec<_>>()
+				.into();
+			Ok(array.transpose().into())
+		}
+		_ => Ok(array.transpose().into()),
+	}
 }
 
 pub fn union((array, other): (Array, Array)) -> Result<Value, Error> {
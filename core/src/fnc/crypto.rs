@@ -6,6 +6,29 @@ use sha1::Sha1;
 use sha2::Sha256;
 use sha2::Sha512;
 
+pub fn adler32((arg,): (Value,)) -> Result<Value, Error> {
+	let bytes = checksum_input("crypto::adler32", arg)?;
+	let mut hasher = adler::Adler32::new();
+	hasher.write_slice(&bytes);
+	Ok(hasher.checksum().into())
+}
+
+pub fn crc32((arg,): (Value,)) -> Result<Value, Error> {
+	let bytes = checksum_input("crypto::crc32", arg)?;
+	Ok(crc32fast::hash(&bytes).into())
+}
+
+/// Checksums are calculated over the bytes of a value, or the UTF-8 encoding of a string
+fn checksum_input(name: &str, arg: Value) -> Result<Vec<u8>, Error> {
+	match arg.convert_to_bytes() {
+		Ok(v) => Ok(v.into_inner()),
+		Err(_) => Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The first argument must be a string or bytes."),
+		}),
+	}
+}
+
 pub fn md5((arg,): (String,)) -> Result<Value, Error> {
 	let mut hasher = Md5::new();
 	hasher.update(arg.as_str());
@@ -141,14 +164,100 @@ pub mod bcrypt {
 	}
 }
 
+pub mod jwt {
+
+	use crate::err::Error;
+	use crate::sql::{Object, Value};
+	use crate::syn;
+	use jsonwebtoken::errors::ErrorKind;
+	use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+
+	/// The algorithms a token may be signed with, all of which use a shared secret
+	const ALGORITHMS: [Algorithm; 3] = [Algorithm::HS256, Algorithm::HS384, Algorithm::HS512];
+
+	fn invalid(name: &str, message: &str) -> Error {
+		Error::InvalidArguments {
+			name: name.to_owned(),
+			message: message.to_owned(),
+		}
+	}
+
+	pub fn encode(
+		(mut claims, secret, opts): (Object, String, Option<Object>),
+	) -> Result<Value, Error> {
+		const NAME: &str = "crypto::jwt::encode";
+		let opts = opts.unwrap_or_default();
+		let alg = match opts.get("alg") {
+			None | Some(Value::None | Value::Null) => Algorithm::HS256,
+			Some(Value::Strand(alg)) => match alg.to_uppercase().as_str() {
+				"HS256" => Algorithm::HS256,
+				"HS384" => Algorithm::HS384,
+				"HS512" => Algorithm::HS512,
+				_ => {
+					return Err(invalid(
+						NAME,
+						"The 'alg' option must be one of 'HS256', 'HS384' or 'HS512'.",
+					))
+				}
+			},
+			Some(_) => return Err(invalid(NAME, "The 'alg' option must be a string.")),
+		};
+		match opts.get("exp") {
+			None | Some(Value::None | Value::Null) => {}
+			Some(Value::Duration(exp)) => {
+				let exp = jsonwebtoken::get_current_timestamp()
+					.checked_add(exp.as_secs())
+					.and_then(|exp| i64::try_from(exp).ok())
+					.ok_or_else(|| invalid(NAME, "The 'exp' option is too large."))?;
+				claims.insert("exp".to_owned(), exp.into());
+			}
+			Some(_) => return Err(invalid(NAME, "The 'exp' option must be a duration.")),
+		}
+		let claims = Value::from(claims).into_json();
+		let key = EncodingKey::from_secret(secret.as_bytes());
+		match jsonwebtoken::encode(&Header::new(alg), &claims, &key) {
+			Ok(token) => Ok(token.into()),
+			Err(_) => Err(invalid(NAME, "The claims could not be encoded.")),
+		}
+	}
+
+	pub fn decode((token, secret): (String, String)) -> Result<Value, Error> {
+		const NAME: &str = "crypto::jwt::decode";
+		let mut validation = Validation::new(Algorithm::HS256);
+		validation.algorithms = ALGORITHMS.to_vec();
+		// Only check the expiry and start time when the token has them
+		validation.required_spec_claims.clear();
+		validation.leeway = 0;
+		validation.validate_nbf = true;
+		let key = DecodingKey::from_secret(secret.as_bytes());
+		match jsonwebtoken::decode::<serde_json::Value>(&token, &key, &validation) {
+			Ok(data) => syn::json(&data.claims.to_string()),
+			Err(e) => Err(invalid(
+				NAME,
+				match e.kind() {
+					ErrorKind::ExpiredSignature => "The token has expired.",
+					ErrorKind::ImmatureSignature => "The token is not valid yet.",
+					ErrorKind::InvalidSignature => "The token signature is invalid.",
+					ErrorKind::InvalidAlgorithm => {
+						"The token must be signed with 'HS256', 'HS384' or 'HS512'."
+					}
+					_ => "The token is malformed.",
+				},
+			)),
+		}
+	}
+}
+
 pub mod pbkdf2 {
 
 	use super::COST_ALLOWANCE;
 	use crate::err::Error;
 	use crate::sql::value::Value;
+	use crate::sql::Number;
+	use crate::sql::Object;
 	use pbkdf2::{
 		password_hash::{PasswordHash, PasswordHasher, SaltString},
-		Pbkdf2,
+		Algorithm, Params, Pbkdf2,
 	};
 	use rand::rngs::OsRng;
 
@@ -169,9 +278,61 @@ pub mod pbkdf2 {
 			.into())
 	}
 
-	pub fn gen((pass,): (String,)) -> Result<Value, Error> {
-		let salt = SaltString::generate(&mut OsRng);
-		let hash = Pbkdf2.hash_password(pass.as_ref(), &salt).unwrap().to_string();
+	pub fn gen((pass, opts): (String, Option<Object>)) -> Result<Value, Error> {
+		let invalid = |message: String| Error::InvalidArguments {
+			name: String::from("crypto::pbkdf2::generate"),
+			message,
+		};
+		let opt = |key: &str| match opts.as_ref().and_then(|o| o.get(key)) {
+			None | Some(Value::None | Value::Null) => None,
+			Some(v) => Some(v),
+		};
+		let max_rounds = Params::default().rounds.saturating_mul(COST_ALLOWANCE);
+		let rounds = match opt("iterations") {
+			None => Params::default().rounds,
+			Some(Value::Number(Number::Int(v))) if (1..=max_rounds as i64).contains(v) => *v as u32,
+			Some(v) => {
+				return Err(invalid(format!(
+					"Expected the 'iterations' option to be an integer between 1 and {max_rounds}, found {v}"
+				)))
+			}
+		};
+		let salt = match opt("salt") {
+			None => SaltString::generate(&mut OsRng),
+			// Longer or shorter salts don't fit in a PHC string once encoded
+			Some(Value::Strand(v)) if (3..=48).contains(&v.len()) => {
+				SaltString::encode_b64(v.as_bytes()).unwrap()
+			}
+			Some(Value::Strand(_)) => {
+				return Err(invalid(String::from(
+					"Expected the 'salt' option to be a string between 3 and 48 bytes long",
+				)))
+			}
+			Some(v) => {
+				return Err(invalid(format!(
+					"Expected the 'salt' option to be a string, found {}",
+					v.kindof()
+				)))
+			}
+		};
+		let algorithm = match opt("algo") {
+			None => Algorithm::Pbkdf2Sha256,
+			Some(Value::Strand(v)) if v.as_str() == "sha256" => Algorithm::Pbkdf2Sha256,
+			Some(Value::Strand(v)) if v.as_str() == "sha512" => Algorithm::Pbkdf2Sha512,
+			Some(v) => {
+				return Err(invalid(format!(
+					"Expected the 'algo' option to be one of 'sha256' or 'sha512', found {v}"
+				)))
+			}
+		};
+		let params = Params {
+			rounds,
+			..Default::default()
+		};
+		let hash = Pbkdf2
+			.hash_password_customized(pass.as_ref(), Some(algorithm.ident()), None, params, &salt)
+			.unwrap()
+			.to_string();
 		Ok(hash.into())
 	}
 }
@@ -1,6 +1,58 @@
 use crate::err::Error;
 use crate::sql::duration::Duration;
 use crate::sql::value::Value;
+use crate::sql::Object;
+
+/// The components of a duration, along with the number of seconds in each
+const COMPONENTS: [(&str, u64); 4] =
+	[("days", 86_400), ("hours", 3_600), ("minutes", 60), ("seconds", 1)];
+
+/// Splits a duration into days, hours, minutes, seconds and nanoseconds, where each
+/// component other than days is smaller than one of the next largest component
+pub fn components((val,): (Duration,)) -> Result<Value, Error> {
+	let mut secs = val.secs();
+	let mut out = Object::default();
+	for (name, size) in COMPONENTS {
+		out.insert(name.to_owned(), (secs / size).into());
+		secs %= size;
+	}
+	out.insert("nanos".to_owned(), val.0.subsec_nanos().into());
+	Ok(out.into())
+}
+
+/// Builds a duration from an object with any of the fields returned by `duration::components`,
+/// where components larger than one of the next largest component carry over into it
+pub fn from_components((val,): (Object,)) -> Result<Value, Error> {
+	let invalid = |message: String| Error::InvalidArguments {
+		name: String::from("duration::from_components"),
+		message,
+	};
+	let overflow = || invalid(String::from("The resulting duration is too large."));
+	let component = |name: &str| match val.get(name) {
+		None | Some(Value::None | Value::Null) => Ok(0),
+		Some(Value::Number(v)) if v.is_integer() && v.to_int() >= 0 => Ok(v.to_int() as u64),
+		Some(v) => Err(invalid(format!(
+			"Expected the '{name}' field to be a non-negative integer, found {}",
+			v.kindof()
+		))),
+	};
+	if let Some(key) =
+		val.keys().find(|k| k.as_str() != "nanos" && !COMPONENTS.iter().any(|(n, _)| n == k))
+	{
+		return Err(invalid(format!(
+			"Unexpected field '{key}', expected one of 'days', 'hours', 'minutes', 'seconds' or 'nanos'"
+		)));
+	}
+	let nanos = component("nanos")?;
+	let mut secs = nanos / 1_000_000_000;
+	for (name, size) in COMPONENTS {
+		secs = component(name)?
+			.checked_mul(size)
+			.and_then(|v| v.checked_add(secs))
+			.ok_or_else(overflow)?;
+	}
+	Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32).into())
+}
 
 pub fn days((val,): (Duration,)) -> Result<Value, Error> {
 	Ok(val.days().into())
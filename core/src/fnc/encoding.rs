@@ -16,3 +16,49 @@ pub mod base64 {
 		})?)))
 	}
 }
+
+pub mod json {
+	use crate::err::Error;
+	use crate::sql::{Object, Value};
+	use crate::syn;
+
+	pub fn parse((arg,): (String,)) -> Result<Value, Error> {
+		syn::json(&arg).map_err(|e| Error::InvalidArguments {
+			name: "encoding::json::parse".to_owned(),
+			message: match e {
+				Error::InvalidQuery(e) => match e.snippets.first().map(|s| s.location()) {
+					Some(l) => {
+						format!("Invalid JSON at line {}, column {}: {}", l.line, l.column, e.text)
+					}
+					None => format!("Invalid JSON: {}", e.text),
+				},
+				e => format!("Invalid JSON: {e}"),
+			},
+		})
+	}
+
+	pub fn stringify((arg, opts): (Value, Option<Object>)) -> Result<Value, Error> {
+		let pretty = match opts.as_ref().and_then(|o| o.get("pretty")) {
+			None | Some(Value::None | Value::Null) => false,
+			Some(Value::Bool(v)) => *v,
+			Some(v) => {
+				return Err(Error::InvalidArguments {
+					name: "encoding::json::stringify".to_owned(),
+					message: format!(
+						"Expected the 'pretty' option to be a bool, found {}",
+						v.kindof()
+					),
+				})
+			}
+		};
+		let json = arg.into_json();
+		let out = match pretty {
+			true => serde_json::to_string_pretty(&json),
+			false => serde_json::to_string(&json),
+		};
+		Ok(Value::from(out.map_err(|e| Error::InvalidArguments {
+			name: "encoding::json::stringify".to_owned(),
+			message: e.to_string(),
+		})?))
+	}
+}
@@ -3,24 +3,15 @@ use crate::sql::geometry::Geometry;
 use crate::sql::value::Value;
 use geo::algorithm::bearing::HaversineBearing;
 use geo::algorithm::centroid::Centroid;
-use geo::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+use geo::algorithm::geodesic_area::GeodesicArea;
+use geo::algorithm::geodesic_length::GeodesicLength;
 use geo::algorithm::haversine_distance::HaversineDistance;
+use geo::algorithm::simplify::Simplify;
 
+/// Returns the area in square metres on the WGS84 ellipsoid, which is zero for points and lines
 pub fn area((arg,): (Value,)) -> Result<Value, Error> {
 	match arg {
-		Value::Geometry(v) => match v {
-			Geometry::Point(v) => Ok(v.chamberlain_duquette_unsigned_area().into()),
-			Geometry::Line(v) => Ok(v.chamberlain_duquette_unsigned_area().into()),
-			Geometry::Polygon(v) => Ok(v.chamberlain_duquette_unsigned_area().into()),
-			Geometry::MultiPoint(v) => Ok(v.chamberlain_duquette_unsigned_area().into()),
-			Geometry::MultiLine(v) => Ok(v.chamberlain_duquette_unsigned_area().into()),
-			Geometry::MultiPolygon(v) => Ok(v.chamberlain_duquette_unsigned_area().into()),
-			Geometry::Collection(v) => Ok(v
-				.into_iter()
-				.collect::<geo::Geometry<f64>>()
-				.chamberlain_duquette_unsigned_area()
-				.into()),
-		},
+		Value::Geometry(v) => Ok(geo::Geometry::from(v).geodesic_area_unsigned().into()),
 		_ => Ok(Value::None),
 	}
 }
@@ -59,6 +50,53 @@ pub fn distance(points: (Value, Value)) -> Result<Value, Error> {
 	})
 }
 
+/// Returns the length in metres on the WGS84 ellipsoid of lines, and of the perimeter of polygons
+pub fn length((arg,): (Value,)) -> Result<Value, Error> {
+	match arg {
+		Value::Geometry(v) => Ok(geodesic_length(&v).into()),
+		_ => Ok(Value::None),
+	}
+}
+
+fn geodesic_length(v: &Geometry) -> f64 {
+	match v {
+		Geometry::Point(_) | Geometry::MultiPoint(_) => 0.0,
+		Geometry::Line(v) => v.geodesic_length(),
+		Geometry::Polygon(v) => v.geodesic_perimeter(),
+		Geometry::MultiLine(v) => v.geodesic_length(),
+		Geometry::MultiPolygon(v) => v.geodesic_perimeter(),
+		Geometry::Collection(v) => v.iter().map(geodesic_length).sum(),
+	}
+}
+
+pub fn simplify((arg, tolerance): (Value, f64)) -> Result<Value, Error> {
+	if tolerance < 0.0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("geo::simplify"),
+			message: String::from("The second argument must be a non-negative number."),
+		});
+	}
+	Ok(match arg {
+		Value::Geometry(v) => Value::Geometry(simplify_geometry(v, tolerance)),
+		_ => Value::None,
+	})
+}
+
+/// Removes vertices using the Ramer–Douglas–Peucker algorithm, keeping the endpoints
+/// of lines, and leaving polygon rings unchanged if they would collapse entirely
+fn simplify_geometry(v: Geometry, tolerance: f64) -> Geometry {
+	match v {
+		Geometry::Line(v) => Geometry::Line(v.simplify(&tolerance)),
+		Geometry::Polygon(v) => Geometry::Polygon(v.simplify(&tolerance)),
+		Geometry::MultiLine(v) => Geometry::MultiLine(v.simplify(&tolerance)),
+		Geometry::MultiPolygon(v) => Geometry::MultiPolygon(v.simplify(&tolerance)),
+		Geometry::Collection(v) => {
+			Geometry::Collection(v.into_iter().map(|v| simplify_geometry(v, tolerance)).collect())
+		}
+		v => v,
+	}
+}
+
 pub mod hash {
 
 	use crate::err::Error;
@@ -1,4 +1,5 @@
 use crate::err::Error;
+use crate::fnc::array::limit;
 use crate::fnc::util::math::bottom::Bottom;
 use crate::fnc::util::math::deviation::Deviation;
 use crate::fnc::util::math::interquartile::Interquartile;
@@ -12,8 +13,10 @@ use crate::fnc::util::math::spread::Spread;
 use crate::fnc::util::math::top::Top;
 use crate::fnc::util::math::trimean::Trimean;
 use crate::fnc::util::math::variance::Variance;
+use crate::sql::array::Array;
 use crate::sql::number::{Number, Sort};
-use crate::sql::value::{TryPow, Value};
+use crate::sql::object::Object;
+use crate::sql::value::{TryAdd, TryPow, TrySub, Value};
 
 pub fn abs((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.abs().into())
@@ -54,6 +57,21 @@ pub fn clamp((arg, min, max): (Number, Number, Number)) -> Result<Value, Error>
 	Ok(arg.clamp(min, max).into())
 }
 
+pub fn combinations((n, k): (i64, i64)) -> Result<Value, Error> {
+	check_choose("math::combinations", n, k)?;
+	// Choosing k is the same as leaving out n - k, and the smaller of the two takes fewer steps
+	let k = k.min(n - k);
+	let mut out: i128 = 1;
+	for i in 1..=k {
+		// Each step is itself a binomial coefficient, so the division is always exact
+		out = out * (n - k + i) as i128 / i as i128;
+		if out > i64::MAX as i128 {
+			return Err(too_large("math::combinations"));
+		}
+	}
+	Ok(Value::from(out as i64))
+}
+
 pub fn cos((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.cos().into())
 }
@@ -61,10 +79,47 @@ pub fn cot((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.cot().into())
 }
 
+/// Returns the running totals of the numbers, so each output is the sum of the inputs up to it
+pub fn cumsum((array,): (Vec<Number>,)) -> Result<Value, Error> {
+	let mut total: Option<Number> = None;
+	let mut out = Vec::with_capacity(array.len());
+	for n in array {
+		let next = match total {
+			Some(total) => total.try_add(n)?,
+			None => n,
+		};
+		out.push(Value::from(next.clone()));
+		total = Some(next);
+	}
+	Ok(out.into())
+}
+
 pub fn deg2rad((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.deg2rad().into())
 }
 
+/// Returns the differences between consecutive numbers, which is one shorter than the input
+pub fn diff((array,): (Vec<Number>,)) -> Result<Value, Error> {
+	Ok(array
+		.windows(2)
+		.map(|w| w[1].clone().try_sub(w[0].clone()).map(Value::from))
+		.collect::<Result<Vec<_>, _>>()?
+		.into())
+}
+
+pub fn factorial((n,): (i64,)) -> Result<Value, Error> {
+	if n < 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("math::factorial"),
+			message: String::from("The argument must be a non-negative integer."),
+		});
+	}
+	(1..=n)
+		.try_fold(1i64, i64::checked_mul)
+		.map(Value::from)
+		.ok_or_else(|| too_large("math::factorial"))
+}
+
 pub fn fixed((arg, p): (Number, i64)) -> Result<Value, Error> {
 	if p > 0 {
 		Ok(arg.fixed(p as usize).into())
@@ -80,6 +135,101 @@ pub fn floor((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.floor().into())
 }
 
+pub fn gcd((a, b): (i64, i64)) -> Result<Value, Error> {
+	i64::try_from(euclid(a.unsigned_abs(), b.unsigned_abs())).map(Value::from).map_err(|_| {
+		Error::InvalidArguments {
+			name: String::from("math::gcd"),
+			message: String::from("The result is too large to be represented as an integer."),
+		}
+	})
+}
+
+pub fn histogram((array, buckets): (Vec<Number>, Value)) -> Result<Value, Error> {
+	let invalid = |message: &str| Error::InvalidArguments {
+		name: String::from("math::histogram"),
+		message: message.to_owned(),
+	};
+	if array.is_empty() {
+		return Err(invalid("The first argument must be a non-empty array of numbers."));
+	}
+	let values: Vec<f64> = array.iter().map(Number::to_float).collect();
+	let bucket = |min: Option<f64>, max: Option<f64>, count: usize| {
+		let mut bucket = Object::default();
+		if let Some(min) = min {
+			bucket.insert("min".to_owned(), min.into());
+		}
+		if let Some(max) = max {
+			bucket.insert("max".to_owned(), max.into());
+		}
+		bucket.insert("count".to_owned(), count.into());
+		Value::from(bucket)
+	};
+	match buckets {
+		// Equal width buckets spanning the range of the values
+		Value::Number(n) if n.is_int() && n.to_int() > 0 => {
+			let n = n.to_int() as usize;
+			limit("math::histogram", n)?;
+			let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+			let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+			let width = (max - min) / n as f64;
+			let mut counts = vec![0; n];
+			for value in values {
+				let index = match width > 0.0 {
+					true => (((value - min) / width) as usize).min(n - 1),
+					false => 0,
+				};
+				counts[index] += 1;
+			}
+			let edge = |i: usize| match i {
+				i if i == n => max,
+				i => min + width * i as f64,
+			};
+			let buckets = counts
+				.into_iter()
+				.enumerate()
+				.map(|(i, count)| bucket(Some(edge(i)), Some(edge(i + 1)), count))
+				.collect::<Vec<_>>();
+			Ok(buckets.into())
+		}
+		// Buckets between explicit boundaries, with overflow buckets on either side
+		Value::Array(boundaries) => {
+			let boundaries = boundaries
+				.into_iter()
+				.map(|boundary| match boundary {
+					Value::Number(n) => Ok(n.to_float()),
+					_ => Err(invalid("The second argument must be an array of numbers.")),
+				})
+				.collect::<Result<Vec<f64>, Error>>()?;
+			if boundaries.is_empty() || boundaries.windows(2).any(|w| w[0] >= w[1]) {
+				return Err(invalid(
+					"The boundaries must be a non-empty array of numbers in ascending order.",
+				));
+			}
+			let last = boundaries.len() - 1;
+			// The first and last counts are the overflow buckets
+			let mut counts = vec![0; boundaries.len() + 1];
+			for value in values {
+				let index = match boundaries.partition_point(|b| *b <= value) {
+					// The last boundary closes the last bucket
+					i if i == boundaries.len() && value == boundaries[last] && last > 0 => last,
+					i => i,
+				};
+				counts[index] += 1;
+			}
+			let mut buckets = Vec::with_capacity(counts.len());
+			buckets.push(bucket(None, Some(boundaries[0]), counts[0]));
+			for i in 0..last {
+				buckets.push(bucket(Some(boundaries[i]), Some(boundaries[i + 1]), counts[i + 1]));
+			}
+			buckets.push(bucket(Some(boundaries[last]), None, counts[last + 1]));
+			Ok(buckets.into())
+		}
+		_ => Err(invalid(
+			"The second argument must be an integer greater than 0 or an array of boundaries.",
+		)),
+	}
+}
+
 pub fn interquartile((mut array,): (Vec<Number>,)) -> Result<Value, Error> {
 	Ok(array.sorted().interquartile().into())
 }
@@ -92,6 +242,18 @@ pub fn lerpangle((from, to, factor): (Number, Number, Number)) -> Result<Value,
 	Ok(factor.lerp_angle(from, to).into())
 }
 
+pub fn lcm((a, b): (i64, i64)) -> Result<Value, Error> {
+	if a == 0 || b == 0 {
+		return Ok(Value::from(0));
+	}
+	let (x, y) = (a.unsigned_abs(), b.unsigned_abs());
+	(x / euclid(x, y))
+		.checked_mul(y)
+		.and_then(|v| i64::try_from(v).ok())
+		.map(Value::from)
+		.ok_or_else(|| Error::TryMul(a.to_string(), b.to_string()))
+}
+
 pub fn ln((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.ln().into())
 }
@@ -137,8 +299,45 @@ pub fn min((array,): (Vec<Number>,)) -> Result<Value, Error> {
 	})
 }
 
-pub fn mode((array,): (Vec<Number>,)) -> Result<Value, Error> {
-	Ok(array.mode().into())
+pub fn mode((array,): (Array,)) -> Result<Value, Error> {
+	let mut modes = array.mode();
+	match modes.len() {
+		0 => Err(Error::InvalidArguments {
+			name: String::from("math::mode"),
+			message: String::from("The array must not be empty."),
+		}),
+		1 => Ok(modes.remove(0)),
+		_ => Ok(modes.into()),
+	}
+}
+
+/// Scales the numbers in an array linearly so the smallest maps to 0 and the largest to 1
+///
+/// A target range can be given as an object with `min` and `max` fields. When all the numbers
+/// are equal they all map to the middle of the target range, 0.5 by default.
+pub fn normalize((array, range): (Vec<Number>, Option<Object>)) -> Result<Value, Error> {
+	let (out_min, out_max) = match range {
+		Some(range) => match (range.get("min"), range.get("max")) {
+			(Some(Value::Number(min)), Some(Value::Number(max))) => {
+				(min.to_float(), max.to_float())
+			}
+			_ => return Err(Error::InvalidArguments {
+				name: String::from("math::normalize"),
+				message: String::from(
+					"The second argument must be an object with numeric `min` and `max` fields.",
+				),
+			}),
+		},
+		None => (0.0, 1.0),
+	};
+	let values: Vec<f64> = array.iter().map(Number::to_float).collect();
+	let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+	let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+	let scaled = values.into_iter().map(|value| match min < max {
+		true => out_min + (value - min) / (max - min) * (out_max - out_min),
+		false => (out_min + out_max) / 2.0,
+	});
+	Ok(scaled.map(Value::from).collect::<Vec<_>>().into())
 }
 
 pub fn nearestrank((mut array, n): (Vec<Number>, Number)) -> Result<Value, Error> {
@@ -149,6 +348,14 @@ pub fn percentile((mut array, n): (Vec<Number>, Number)) -> Result<Value, Error>
 	Ok(array.sorted().percentile(n).into())
 }
 
+pub fn permutations((n, k): (i64, i64)) -> Result<Value, Error> {
+	check_choose("math::permutations", n, k)?;
+	(n - k + 1..=n)
+		.try_fold(1i64, i64::checked_mul)
+		.map(Value::from)
+		.ok_or_else(|| too_large("math::permutations"))
+}
+
 pub fn pow((arg, pow): (Number, Number)) -> Result<Value, Error> {
 	Ok(arg.try_pow(pow)?.into())
 }
@@ -165,6 +372,26 @@ pub fn round((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.round().into())
 }
 
+/// Maps a number linearly from one range onto another
+///
+/// The result is clamped to the output range, unless extrapolation is allowed.
+pub fn scale(
+	(arg, in_min, in_max, out_min, out_max, extrapolate): (f64, f64, f64, f64, f64, Option<bool>),
+) -> Result<Value, Error> {
+	if in_min == in_max {
+		return Err(Error::InvalidArguments {
+			name: String::from("math::scale"),
+			message: String::from("The input range must not be empty."),
+		});
+	}
+	let scaled = out_min + (arg - in_min) / (in_max - in_min) * (out_max - out_min);
+	Ok(match extrapolate.unwrap_or(false) {
+		true => scaled,
+		false => scaled.clamp(out_min.min(out_max), out_min.max(out_max)),
+	}
+	.into())
+}
+
 pub fn sign((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.sign().into())
 }
@@ -213,3 +440,33 @@ pub fn trimean((mut array,): (Vec<Number>,)) -> Result<Value, Error> {
 pub fn variance((array,): (Vec<Number>,)) -> Result<Value, Error> {
 	Ok(array.variance(true).into())
 }
+
+/// Checks the arguments for choosing `k` items out of `n`
+fn check_choose(name: &str, n: i64, k: i64) -> Result<(), Error> {
+	let message = if n < 0 {
+		"The first argument must be a non-negative integer."
+	} else if k < 0 || k > n {
+		"The second argument must be a non-negative integer no greater than the first."
+	} else {
+		return Ok(());
+	};
+	Err(Error::InvalidArguments {
+		name: name.to_owned(),
+		message: message.to_owned(),
+	})
+}
+
+fn too_large(name: &str) -> Error {
+	Error::InvalidArguments {
+		name: name.to_owned(),
+		message: String::from("The result is too large to be represented as an integer."),
+	}
+}
+
+/// Computes the greatest common divisor using the Euclidean algorithm
+fn euclid(mut a: u64, mut b: u64) -> u64 {
+	while b != 0 {
+		(a, b) = (b, a % b);
+	}
+	a
+}
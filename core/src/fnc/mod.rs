@@ -99,6 +99,7 @@ pub fn synchronous(
 		"array::any" => array::any,
 		"array::append" => array::append,
 		"array::at" => array::at,
+		"array::binary_search" => array::binary_search,
 		"array::boolean_and" => array::boolean_and,
 		"array::boolean_not" => array::boolean_not,
 		"array::boolean_or" => array::boolean_or,
@@ -109,10 +110,13 @@ pub fn synchronous(
 		"array::concat" => array::concat,
 		"array::difference" => array::difference,
 		"array::distinct" => array::distinct,
+		"array::fill" => array::fill,
 		"array::filter_index" => array::filter_index,
 		"array::find_index" => array::find_index,
+		"array::find_last_index" => array::find_last_index,
 		"array::first" => array::first,
 		"array::flatten" => array::flatten,
+		"array::frequencies" => array::frequencies,
 		"array::group" => array::group,
 		"array::insert" => array::insert,
 		"array::intersect" => array::intersect,
@@ -129,10 +133,14 @@ pub fn synchronous(
 		"array::prepend" => array::prepend,
 		"array::push" => array::push,
 		"array::remove" => array::remove,
+		"array::repeat" => array::repeat,
 		"array::reverse" => array::reverse,
+		"array::rotate" => array::rotate,
+		"array::sample" => array::sample,
 		"array::shuffle" => array::shuffle,
 		"array::slice" => array::slice,
 		"array::sort" => array::sort,
+		"array::splice" => array::splice,
 		"array::transpose" => array::transpose,
 		"array::union" => array::union,
 		"array::sort::asc" => array::sort::asc,
@@ -142,11 +150,16 @@ pub fn synchronous(
 		//
 		"count" => count::count,
 		//
+		"crypto::adler32" => crypto::adler32,
+		"crypto::crc32" => crypto::crc32,
 		"crypto::md5" => crypto::md5,
 		"crypto::sha1" => crypto::sha1,
 		"crypto::sha256" => crypto::sha256,
 		"crypto::sha512" => crypto::sha512,
+		"crypto::jwt::decode" => crypto::jwt::decode,
+		"crypto::jwt::encode" => crypto::jwt::encode,
 		//
+		"duration::components" => duration::components,
 		"duration::days" => duration::days,
 		"duration::hours" => duration::hours,
 		"duration::micros" => duration::micros,
@@ -156,6 +169,7 @@ pub fn synchronous(
 		"duration::secs" => duration::secs,
 		"duration::weeks" => duration::weeks,
 		"duration::years" => duration::years,
+		"duration::from_components" => duration::from_components,
 		"duration::from::days" => duration::from::days,
 		"duration::from::hours" => duration::from::hours,
 		"duration::from::micros" => duration::from::micros,
@@ -167,6 +181,8 @@ pub fn synchronous(
 		//
 		"encoding::base64::decode" => encoding::base64::decode,
 		"encoding::base64::encode" => encoding::base64::encode,
+		"encoding::json::parse" => encoding::json::parse,
+		"encoding::json::stringify" => encoding::json::stringify,
 		//
 		"geo::area" => geo::area,
 		"geo::bearing" => geo::bearing,
@@ -174,6 +190,8 @@ pub fn synchronous(
 		"geo::distance" => geo::distance,
 		"geo::hash::decode" => geo::hash::decode,
 		"geo::hash::encode" => geo::hash::encode,
+		"geo::length" => geo::length,
+		"geo::simplify" => geo::simplify,
 		//
 		"math::abs" => math::abs,
 		"math::acos" => math::acos,
@@ -183,12 +201,19 @@ pub fn synchronous(
 		"math::bottom" => math::bottom,
 		"math::ceil" => math::ceil,
 		"math::clamp" => math::clamp,
+		"math::combinations" => math::combinations,
 		"math::cos" => math::cos,
 		"math::cot" => math::cot,
+		"math::cumsum" => math::cumsum,
 		"math::deg2rad" => math::deg2rad,
+		"math::diff" => math::diff,
+		"math::factorial" => math::factorial,
 		"math::fixed" => math::fixed,
 		"math::floor" => math::floor,
+		"math::gcd" => math::gcd,
+		"math::histogram" => math::histogram,
 		"math::interquartile" => math::interquartile,
+		"math::lcm" => math::lcm,
 		"math::lerp" => math::lerp,
 		"math::lerpangle" => math::lerpangle,
 		"math::ln" => math::ln,
@@ -202,11 +227,14 @@ pub fn synchronous(
 		"math::min" => math::min,
 		"math::mode" => math::mode,
 		"math::nearestrank" => math::nearestrank,
+		"math::normalize" => math::normalize,
 		"math::percentile" => math::percentile,
+		"math::permutations" => math::permutations,
 		"math::pow" => math::pow,
 		"math::product" => math::product,
 		"math::rad2deg" => math::rad2deg,
 		"math::round" => math::round,
+		"math::scale" => math::scale,
 		"math::sign" => math::sign,
 		"math::sin" => math::sin,
 		"math::spread" => math::spread,
@@ -224,12 +252,20 @@ pub fn synchronous(
 		//
 		"not" => not::not,
 		//
+		"object::deep_get" => object::deep_get,
+		"object::deep_set" => object::deep_set,
 		"object::entries" => object::entries,
+		"object::flatten" => object::flatten,
 		"object::from_entries" => object::from_entries,
 		"object::keys" => object::keys,
 		"object::len" => object::len,
+		"object::omit" => object::omit,
+		"object::pick" => object::pick,
+		"object::unflatten" => object::unflatten,
 		"object::values" => object::values,
 		//
+		"parse::bytes" => parse::bytes,
+		"parse::duration" => parse::duration,
 		"parse::email::host" => parse::email::host,
 		"parse::email::user" => parse::email::user,
 		"parse::url::domain" => parse::url::domain,
@@ -247,6 +283,7 @@ pub fn synchronous(
 		"rand::guid" => rand::guid,
 		"rand::int" => rand::int,
 		"rand::string" => rand::string,
+		"rand::string::pattern" => rand::string::pattern,
 		"rand::time" => rand::time,
 		"rand::ulid" => rand::ulid,
 		"rand::uuid::v4" => rand::uuid::v4,
@@ -264,11 +301,21 @@ pub fn synchronous(
 		//
 		"string::concat" => string::concat,
 		"string::contains" => string::contains,
+		"string::count_matches" => string::count_matches,
+		"string::diff" => string::diff,
 		"string::endsWith" => string::ends_with,
+		"string::extract_emails" => string::extract_emails,
+		"string::extract_numbers" => string::extract_numbers,
+		"string::highlight" => string::highlight,
 		"string::join" => string::join,
 		"string::len" => string::len,
 		"string::lowercase" => string::lowercase,
+		"string::mask" => string::mask,
 		"string::matches" => string::matches,
+		"string::ngrams" => string::ngrams,
+		"string::pad_end" => string::pad_end,
+		"string::pad_start" => string::pad_start,
+		"string::redact_json" => string::redact_json,
 		"string::repeat" => string::repeat,
 		"string::replace" => string::replace,
 		"string::reverse" => string::reverse,
@@ -276,9 +323,11 @@ pub fn synchronous(
 		"string::slug" => string::slug,
 		"string::split" => string::split,
 		"string::startsWith" => string::starts_with,
+		"string::to_case" => string::to_case,
 		"string::trim" => string::trim,
 		"string::uppercase" => string::uppercase,
 		"string::words" => string::words,
+		"string::wrap" => string::wrap,
 		"string::distance::hamming" => string::distance::hamming,
 		"string::distance::levenshtein" => string::distance::levenshtein,
 		"string::html::encode" => string::html::encode,
@@ -313,10 +362,14 @@ pub fn synchronous(
 		"string::semver::set::minor" => string::semver::set::minor,
 		"string::semver::set::patch" => string::semver::set::patch,
 		//
+		"time::add" => time::add,
+		"time::age" => time::age,
+		"time::business_days_between" => time::business_days_between,
 		"time::ceil" => time::ceil,
 		"time::day" => time::day,
+		"time::day_of_year" => time::day_of_year,
 		"time::floor" => time::floor,
-		"time::format" => time::format,
+		"time::format" => time::format(ctx),
 		"time::group" => time::group,
 		"time::hour" => time::hour,
 		"time::max" => time::max,
@@ -327,14 +380,19 @@ pub fn synchronous(
 		"time::micros" => time::micros,
 		"time::millis" => time::millis,
 		"time::now" => time::now,
+		"time::quarter" => time::quarter,
 		"time::round" => time::round,
 		"time::second" => time::second,
+		"time::subtract" => time::subtract,
 		"time::timezone" => time::timezone,
 		"time::unix" => time::unix,
 		"time::wday" => time::wday,
 		"time::week" => time::week,
 		"time::yday" => time::yday,
 		"time::year" => time::year,
+		"time::is::business_day" => time::is::business_day,
+		"time::is::leap_year" => time::is::leap_year,
+		"time::is::weekend" => time::is::weekend,
 		"time::from::nanos" => time::from::nanos,
 		"time::from::micros" => time::from::micros,
 		"time::from::millis" => time::from::millis,
@@ -342,6 +400,7 @@ pub fn synchronous(
 		"time::from::unix" => time::from::unix,
 		//
 		"type::bool" => r#type::bool,
+		"type::coerce" => r#type::coerce,
 		"type::datetime" => r#type::datetime,
 		"type::decimal" => r#type::decimal,
 		"type::duration" => r#type::duration,
@@ -2,7 +2,65 @@ use std::collections::BTreeMap;
 
 use crate::err::Error;
 use crate::sql::value::Value;
-use crate::sql::{Array, Object, Strand};
+use crate::sql::{Array, Ident, Object, Part, Strand};
+
+pub fn deep_get((object, path): (Object, String)) -> Result<Value, Error> {
+	let mut current = &Value::Object(object);
+	for field in path.split('.') {
+		let next = match current {
+			Value::Object(v) => v.get(field),
+			Value::Array(v) => field.parse::<usize>().ok().and_then(|i| v.get(i)),
+			_ => None,
+		};
+		match next {
+			Some(v) => current = v,
+			None => return Ok(Value::None),
+		}
+	}
+	Ok(current.clone())
+}
+
+pub fn deep_set((object, path, value): (Object, String, Value)) -> Result<Value, Error> {
+	fn set(target: &mut Value, path: &str, fields: &[&str], value: Value) -> Result<(), Error> {
+		let Some((field, rest)) = fields.split_first() else {
+			*target = value;
+			return Ok(());
+		};
+		// Create the intermediate objects leading up to the value
+		if target.is_none() {
+			*target = Value::Object(Object::default());
+		}
+		let next = match target {
+			Value::Object(v) => v.entry(field.to_string()).or_insert(Value::None),
+			Value::Array(v) => {
+				let index = field.parse::<usize>().map_err(|_| Error::InvalidArguments {
+					name: String::from("object::deep_set"),
+					message: format!(
+						"Expected an array index in the path '{path}', found '{field}'"
+					),
+				})?;
+				if v.len() <= index {
+					v.resize(index + 1, Value::None);
+				}
+				&mut v[index]
+			}
+			v => {
+				return Err(Error::InvalidArguments {
+					name: String::from("object::deep_set"),
+					message: format!(
+						"Cannot set the field '{field}' of the path '{path}' on a value of type {}",
+						v.kindof()
+					),
+				})
+			}
+		};
+		set(next, path, rest, value)
+	}
+	let mut out = Value::Object(object);
+	let fields: Vec<&str> = path.split('.').collect();
+	set(&mut out, &path, &fields, value)?;
+	Ok(out)
+}
 
 pub fn entries((object,): (Object,)) -> Result<Value, Error> {
 	Ok(Value::Array(Array(
@@ -15,6 +73,31 @@ pub fn entries((object,): (Object,)) -> Result<Value, Error> {
 	)))
 }
 
+pub fn flatten((object,): (Object,)) -> Result<Value, Error> {
+	fn flatten_into(out: &mut BTreeMap<String, Value>, prefix: String, value: Value) {
+		match value {
+			Value::Object(v) if !v.is_empty() => {
+				for (k, v) in v {
+					flatten_into(out, format!("{prefix}.{k}"), v);
+				}
+			}
+			Value::Array(v) if !v.is_empty() => {
+				for (i, v) in v.into_iter().enumerate() {
+					flatten_into(out, format!("{prefix}.{i}"), v);
+				}
+			}
+			v => {
+				out.insert(prefix, v);
+			}
+		}
+	}
+	let mut out = BTreeMap::new();
+	for (k, v) in object {
+		flatten_into(&mut out, k, v);
+	}
+	Ok(Value::Object(Object(out)))
+}
+
 pub fn from_entries((array,): (Array,)) -> Result<Value, Error> {
 	let mut obj: BTreeMap<String, Value> = BTreeMap::default();
 
@@ -66,6 +149,87 @@ pub fn keys((object,): (Object,)) -> Result<Value, Error> {
 	Ok(Value::Array(Array(object.keys().map(|v| Value::Strand(Strand(v.to_owned()))).collect())))
 }
 
+pub fn omit((object, paths): (Object, Array)) -> Result<Value, Error> {
+	let mut out = Value::Object(object);
+	for path in field_paths("object::omit", paths)? {
+		out.cut(&path);
+	}
+	Ok(out)
+}
+
+pub fn pick((object, paths): (Object, Array)) -> Result<Value, Error> {
+	let object = Value::Object(object);
+	let mut out = Value::Object(Object::default());
+	for path in field_paths("object::pick", paths)? {
+		match object.pick(&path) {
+			Value::None => continue,
+			v => out.put(&path, v),
+		}
+	}
+	Ok(out)
+}
+
+pub fn unflatten((object,): (Object,)) -> Result<Value, Error> {
+	fn conflict(key: &str) -> Error {
+		Error::InvalidArguments {
+			name: String::from("object::unflatten"),
+			message: format!("Conflicting values found for the key '{key}'"),
+		}
+	}
+	fn insert(target: &mut Value, key: &str, path: &[&str], value: Value) -> Result<(), Error> {
+		let Some((field, rest)) = path.split_first() else {
+			return match target {
+				Value::None => {
+					*target = value;
+					Ok(())
+				}
+				_ => Err(conflict(key)),
+			};
+		};
+		// Create the container this field lives in, if it does not exist yet
+		if target.is_none() {
+			*target = match field.parse::<usize>() {
+				Ok(_) => Value::Array(Array::default()),
+				Err(_) => Value::Object(Object::default()),
+			};
+		}
+		let next = match target {
+			Value::Object(v) => v.entry(field.to_string()).or_insert(Value::None),
+			Value::Array(v) => {
+				let index = field.parse::<usize>().map_err(|_| conflict(key))?;
+				if v.len() <= index {
+					v.resize(index + 1, Value::None);
+				}
+				&mut v[index]
+			}
+			_ => return Err(conflict(key)),
+		};
+		insert(next, key, rest, value)
+	}
+	let mut out = Value::Object(Object::default());
+	for (k, v) in object {
+		let path: Vec<&str> = k.split('.').collect();
+		insert(&mut out, &k, &path, v)?;
+	}
+	Ok(out)
+}
+
 pub fn values((object,): (Object,)) -> Result<Value, Error> {
 	Ok(Value::Array(Array(object.values().map(|v| v.to_owned()).collect())))
 }
+
+/// Converts a list of dotted field paths like `"a.b"` into idiom parts
+fn field_paths(name: &str, paths: Array) -> Result<Vec<Vec<Part>>, Error> {
+	paths
+		.into_iter()
+		.map(|path| match path {
+			Value::Strand(path) => {
+				Ok(path.as_str().split('.').map(|field| Part::Field(Ident::from(field))).collect())
+			}
+			v => Err(Error::InvalidArguments {
+				name: name.to_owned(),
+				message: format!("Expected an array of field paths, found {}", v.kindof()),
+			}),
+		})
+		.collect()
+}
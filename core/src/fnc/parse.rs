@@ -1,3 +1,116 @@
+use crate::err::Error;
+use crate::sql::duration::Duration;
+use crate::sql::value::Value;
+
+/// Splits a human-readable quantity such as `1h30m` or `256 MiB` into
+/// its `(number, unit)` pairs, returning the offending token on failure.
+fn quantities(input: &str) -> Result<Vec<(&str, &str)>, &str> {
+	let mut out = Vec::new();
+	let mut rest = input.trim_start();
+	while !rest.is_empty() {
+		// Take the token which we are currently parsing
+		let token = rest.split_whitespace().next().unwrap_or(rest);
+		// Split off the numeric part of the quantity
+		let idx = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+		let (number, tail) = rest.split_at(idx);
+		// Split off the unit part of the quantity
+		let tail = tail.trim_start();
+		let idx = tail.find(|c: char| !c.is_alphabetic()).unwrap_or(tail.len());
+		let (unit, tail) = tail.split_at(idx);
+		// Ensure that a number was specified
+		if number.is_empty() || number.parse::<f64>().is_err() {
+			return Err(token);
+		}
+		out.push((number, unit));
+		rest = tail.trim_start();
+	}
+	match out.is_empty() {
+		true => Err(input),
+		false => Ok(out),
+	}
+}
+
+/// Multiplies a decimal number string by an integer unit size, keeping
+/// integer precision when the number has no fractional part.
+fn scale(number: &str, unit: u128) -> Option<u128> {
+	match number.parse::<u128>() {
+		Ok(v) => v.checked_mul(unit),
+		Err(_) => {
+			let v = number.parse::<f64>().ok()? * unit as f64;
+			match v.is_finite() && v < u128::MAX as f64 {
+				true => Some(v as u128),
+				false => None,
+			}
+		}
+	}
+}
+
+pub fn duration((string,): (String,)) -> Result<Value, Error> {
+	// Create the error for an invalid token
+	let fail = |token: &str| Error::InvalidArguments {
+		name: String::from("parse::duration"),
+		message: format!("Invalid duration token '{token}' in '{string}'"),
+	};
+	// Sum up each of the duration components
+	let mut nanos: u128 = 0;
+	for (number, unit) in quantities(&string).map_err(fail)? {
+		let size: u128 = match unit {
+			"ns" => 1,
+			"us" | "µs" | "μs" => 1_000,
+			"ms" => 1_000_000,
+			"s" => 1_000_000_000,
+			"m" => 60 * 1_000_000_000,
+			"h" => 60 * 60 * 1_000_000_000,
+			"d" => 24 * 60 * 60 * 1_000_000_000,
+			"w" => 7 * 24 * 60 * 60 * 1_000_000_000,
+			"y" => 365 * 24 * 60 * 60 * 1_000_000_000,
+			_ => return Err(fail(&format!("{number}{unit}"))),
+		};
+		nanos = scale(number, size)
+			.and_then(|v| nanos.checked_add(v))
+			.ok_or_else(|| fail(&format!("{number}{unit}")))?;
+	}
+	// Convert the total into a duration
+	let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| fail(&string))?;
+	let nanos = (nanos % 1_000_000_000) as u32;
+	Ok(Duration::from(std::time::Duration::new(secs, nanos)).into())
+}
+
+pub fn bytes((string,): (String,)) -> Result<Value, Error> {
+	// Create the error for an invalid token
+	let fail = |token: &str| Error::InvalidArguments {
+		name: String::from("parse::bytes"),
+		message: format!("Invalid byte size token '{token}' in '{string}'"),
+	};
+	// A byte size consists of exactly one quantity
+	let (number, unit) = match quantities(&string).map_err(fail)?.as_slice() {
+		[(number, unit)] => (*number, *unit),
+		_ => return Err(fail(&string)),
+	};
+	// Fetch the size of the unit
+	let size: u128 = match unit.to_ascii_lowercase().as_str() {
+		"" | "b" => 1,
+		"kb" => 1_000,
+		"mb" => 1_000_u128.pow(2),
+		"gb" => 1_000_u128.pow(3),
+		"tb" => 1_000_u128.pow(4),
+		"pb" => 1_000_u128.pow(5),
+		"eb" => 1_000_u128.pow(6),
+		"kib" => 1_024,
+		"mib" => 1_024_u128.pow(2),
+		"gib" => 1_024_u128.pow(3),
+		"tib" => 1_024_u128.pow(4),
+		"pib" => 1_024_u128.pow(5),
+		"eib" => 1_024_u128.pow(6),
+		_ => return Err(fail(&format!("{number}{unit}"))),
+	};
+	// Partial bytes are truncated
+	scale(number, size)
+		.and_then(|v| i64::try_from(v).ok())
+		.map(Value::from)
+		.ok_or_else(|| fail(&format!("{number}{unit}")))
+}
+
 pub mod email {
 
 	use crate::err::Error;
@@ -148,3 +261,56 @@ pub mod url {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::sql::duration::Duration;
+	use crate::sql::value::Value;
+
+	#[test]
+	fn duration() {
+		let value = super::duration(("1.5h".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(Duration::from_mins(90)));
+		let value = super::duration(("1h30m".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(Duration::from_mins(90)));
+		let value = super::duration(("0.25s 500ms".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(Duration::from_millis(750)));
+		let value = super::duration(("10µs".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(Duration::from_micros(10)));
+	}
+
+	#[test]
+	fn duration_invalid() {
+		let error = super::duration(("1h-5m".to_string(),)).unwrap_err();
+		assert!(error.to_string().contains("'-5m'"), "{error}");
+		let error = super::duration(("1.2.3h".to_string(),)).unwrap_err();
+		assert!(error.to_string().contains("'1.2.3h'"), "{error}");
+		let error = super::duration(("5 fortnights".to_string(),)).unwrap_err();
+		assert!(error.to_string().contains("'5fortnights'"), "{error}");
+		assert!(super::duration(("".to_string(),)).is_err());
+	}
+
+	#[test]
+	fn bytes() {
+		let value = super::bytes(("256MiB".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(268_435_456));
+		let value = super::bytes(("256MB".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(256_000_000));
+		let value = super::bytes(("1.5 KiB".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(1_536));
+		let value = super::bytes(("2.5gb".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(2_500_000_000_i64));
+		let value = super::bytes(("42".to_string(),)).unwrap();
+		assert_eq!(value, Value::from(42));
+	}
+
+	#[test]
+	fn bytes_invalid() {
+		let error = super::bytes(("12XB".to_string(),)).unwrap_err();
+		assert!(error.to_string().contains("'12XB'"), "{error}");
+		let error = super::bytes(("MiB".to_string(),)).unwrap_err();
+		assert!(error.to_string().contains("'MiB'"), "{error}");
+		assert!(super::bytes(("1KB 2KB".to_string(),)).is_err());
+		assert!(super::bytes(("100EiB".to_string(),)).is_err());
+	}
+}
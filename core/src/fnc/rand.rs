@@ -158,6 +158,51 @@ pub fn uuid(_: ()) -> Result<Value, Error> {
 	Ok(Uuid::new().into())
 }
 
+pub mod string {
+
+	use crate::err::Error;
+	use crate::sql::value::Value;
+	use rand::rngs::StdRng;
+	use rand::{Rng, SeedableRng};
+
+	/// Generates a random string from a template like `"??-####"`
+	///
+	/// Each `?` is replaced with a random ASCII letter and each `#` with a random digit. A
+	/// backslash makes the character after it literal. Any other character is copied as is.
+	/// Passing a seed generates the same string for the same template every time.
+	pub fn pattern((template, seed): (String, Option<i64>)) -> Result<Value, Error> {
+		match seed {
+			Some(seed) => fill(&template, &mut StdRng::seed_from_u64(seed as u64)),
+			None => fill(&template, &mut rand::thread_rng()),
+		}
+	}
+
+	fn fill(template: &str, rng: &mut impl Rng) -> Result<Value, Error> {
+		const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+		let mut out = String::with_capacity(template.len());
+		let mut chars = template.chars();
+		while let Some(c) = chars.next() {
+			match c {
+				'?' => out.push(LETTERS[rng.gen_range(0..LETTERS.len())] as char),
+				'#' => out.push(char::from(b'0' + rng.gen_range(0..10))),
+				'\\' => match chars.next() {
+					Some(c) => out.push(c),
+					None => {
+						return Err(Error::InvalidArguments {
+							name: String::from("rand::string::pattern"),
+							message: String::from(
+								"The template must not end with an unescaped backslash.",
+							),
+						})
+					}
+				},
+				c => out.push(c),
+			}
+		}
+		Ok(out.into())
+	}
+}
+
 pub mod uuid {
 
 	use crate::err::Error;
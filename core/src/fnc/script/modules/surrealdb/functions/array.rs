@@ -12,6 +12,7 @@ impl_module_def!(
 	"all" => run,
 	"any" => run,
 	"at" => run,
+	"binary_search" => run,
 	"append" => run,
 	"boolean_and" => run,
 	"boolean_not" => run,
@@ -23,10 +24,13 @@ impl_module_def!(
 	"concat" => run,
 	"difference" => run,
 	"distinct" => run,
+	"fill" => run,
 	"filter_index" => run,
 	"find_index" => run,
+	"find_last_index" => run,
 	"first" => run,
 	"flatten" => run,
+	"frequencies" => run,
 	"group" => run,
 	"insert" => run,
 	"intersect" => run,
@@ -44,10 +48,14 @@ impl_module_def!(
 	"push" => run,
 	"prepend" => run,
 	"remove" => run,
+	"repeat" => run,
 	"reverse" => run,
+	"rotate" => run,
+	"sample" => run,
 	"shuffle" => run,
 	"slice" => run,
 	"sort" => (sort::Package),
+	"splice" => run,
 	"transpose" => run,
 	"union" => run
 );
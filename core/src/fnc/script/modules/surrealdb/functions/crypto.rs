@@ -3,6 +3,7 @@ use crate::fnc::script::modules::impl_module_def;
 
 mod argon2;
 mod bcrypt;
+mod jwt;
 mod pbkdf2;
 mod scrypt;
 
@@ -12,12 +13,15 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"crypto",
+	"adler32" => run,
+	"crc32" => run,
 	"md5" => run,
 	"sha1" => run,
 	"sha256" => run,
 	"sha512" => run,
 	"argon2" => (argon2::Package),
 	"bcrypt" => (bcrypt::Package),
+	"jwt" => (jwt::Package),
 	"pbkdf2" => (pbkdf2::Package),
 	"scrypt" => (scrypt::Package)
 );
@@ -9,6 +9,7 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"duration",
+	"components" => run,
 	"days" => run,
 	"hours" => run,
 	"micros" => run,
@@ -18,5 +19,6 @@ impl_module_def!(
 	"secs" => run,
 	"weeks" => run,
 	"years" => run,
-	"from" => (from::Package)
+	"from" => (from::Package),
+	"from_components" => run
 );
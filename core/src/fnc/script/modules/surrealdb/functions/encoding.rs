@@ -1,6 +1,7 @@
 use crate::fnc::script::modules::impl_module_def;
 
 mod base64;
+mod json;
 
 #[non_exhaustive]
 pub struct Package;
@@ -8,5 +9,6 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"encoding",
-	"base64" => (base64::Package)
+	"base64" => (base64::Package),
+	"json" => (json::Package)
 );
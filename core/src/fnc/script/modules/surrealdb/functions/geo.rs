@@ -13,5 +13,7 @@ impl_module_def!(
 	"bearing" => run,
 	"centroid" => run,
 	"distance" => run,
+	"length" => run,
+	"simplify" => run,
 	"hash" => (hash::Package)
 );
@@ -15,12 +15,19 @@ impl_module_def!(
 	"bottom" => run,
 	"ceil" => run,
 	"clamp" => run,
+	"combinations" => run,
 	"cos" => run,
 	"cot" => run,
+	"cumsum" => run,
 	"deg2rad" => run,
+	"diff" => run,
+	"factorial" => run,
 	"fixed" => run,
 	"floor" => run,
+	"gcd" => run,
+	"histogram" => run,
 	"interquartile" => run,
+	"lcm" => run,
 	"lerp" => run,
 	"lerpangle" => run,
 	"ln" => run,
@@ -34,11 +41,14 @@ impl_module_def!(
 	"min" => run,
 	"mode" => run,
 	"nearestrank" => run,
+	"normalize" => run,
 	"percentile" => run,
+	"permutations" => run,
 	"pow" => run,
 	"product" => run,
 	"rad2deg" => run,
 	"round" => run,
+	"scale" => run,
 	"sign" => run,
 	"sin" => run,
 	"spread" => run,
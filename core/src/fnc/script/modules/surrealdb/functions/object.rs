@@ -7,9 +7,15 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"object",
+	"deep_get" => run,
+	"deep_set" => run,
 	"entries" => run,
+	"flatten" => run,
 	"from_entries" => run,
 	"keys" => run,
 	"len" => run,
+	"omit" => run,
+	"pick" => run,
+	"unflatten" => run,
 	"values" => run
 );
@@ -1,3 +1,4 @@
+use super::run;
 use crate::fnc::script::modules::impl_module_def;
 
 mod email;
@@ -9,6 +10,8 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"parse",
+	"bytes" => run,
+	"duration" => run,
 	"email" => (email::Package),
 	"url" => (url::Package)
 );
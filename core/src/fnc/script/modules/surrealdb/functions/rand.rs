@@ -3,6 +3,7 @@ use js::{prelude::Rest, Ctx};
 use super::run;
 use crate::sql::value::Value;
 
+mod string;
 mod uuid;
 
 #[non_exhaustive]
@@ -42,7 +43,12 @@ impl js::module::ModuleDef for Package {
 		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand", "int", run,);
 		exports.export("int", value.clone())?;
 		default.set("int", value)?;
-		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand", "string", run,);
+		let value = crate::fnc::script::modules::impl_module_def!(
+			ctx,
+			"rand",
+			"string",
+			(string::Package),
+		);
 		exports.export("string", value.clone())?;
 		default.set("string", value)?;
 		let value = crate::fnc::script::modules::impl_module_def!(ctx, "rand", "time", run,);
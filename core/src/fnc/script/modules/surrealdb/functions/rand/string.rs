@@ -0,0 +1,27 @@
+use js::{prelude::Rest, Ctx};
+
+use super::super::run;
+use crate::sql::value::Value;
+
+#[non_exhaustive]
+pub struct Package;
+
+impl js::module::ModuleDef for Package {
+	fn declare(decls: &js::module::Declarations) -> js::Result<()> {
+		decls.declare("default")?;
+		decls.declare("pattern")?;
+		Ok(())
+	}
+	fn evaluate<'js>(ctx: &js::Ctx<'js>, exports: &js::module::Exports<'js>) -> js::Result<()> {
+		let default = js::Function::new(ctx.clone(), |ctx: Ctx<'js>, args: Rest<Value>| {
+			run(ctx, "rand::string", args.0)
+		})?
+		.with_name("string")?;
+		let value =
+			crate::fnc::script::modules::impl_module_def!(ctx, "rand::string", "pattern", run,);
+		exports.export("pattern", value.clone())?;
+		default.set("pattern", value)?;
+		exports.export("default", default)?;
+		Ok(())
+	}
+}
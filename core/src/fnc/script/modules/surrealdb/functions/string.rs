@@ -15,14 +15,24 @@ impl_module_def!(
 	"string",
 	"concat" => run,
 	"contains" => run,
+	"count_matches" => run,
+	"diff" => run,
 	"distance" => (distance::Package),
 	"endsWith" => run,
+	"extract_emails" => run,
+	"extract_numbers" => run,
+	"highlight" => run,
 	"html" => (html::Package),
 	"is" => (is::Package),
 	"join" => run,
 	"len" => run,
 	"lowercase" => run,
+	"mask" => run,
 	"matches" => run,
+	"ngrams" => run,
+	"pad_end" => run,
+	"pad_start" => run,
+	"redact_json" => run,
 	"repeat" => run,
 	"replace" => run,
 	"reverse" => run,
@@ -31,8 +41,10 @@ impl_module_def!(
 	"slug" => run,
 	"split" => run,
 	"startsWith" => run,
+	"to_case" => run,
 	"trim" => run,
 	"uppercase" => run,
 	"words" => run,
+	"wrap" => run,
 	"semver" => (semver::Package)
 );
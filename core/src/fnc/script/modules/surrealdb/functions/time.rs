@@ -2,6 +2,7 @@ use super::run;
 use crate::fnc::script::modules::impl_module_def;
 
 mod from;
+mod is;
 
 #[non_exhaustive]
 pub struct Package;
@@ -9,8 +10,12 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"time",
+	"add" => run,
+	"age" => run,
+	"business_days_between" => run,
 	"ceil" => run,
 	"day" => run,
+	"day_of_year" => run,
 	"floor" => run,
 	"format" => run,
 	"group" => run,
@@ -24,14 +29,17 @@ impl_module_def!(
 	"micros" => run,
 	"millis" => run,
 	"now" => run,
+	"quarter" => run,
 	"round" => run,
 	"second" => run,
 	"secs" => run,
+	"subtract" => run,
 	"timezone" => run,
 	"unix" => run,
 	"wday" => run,
 	"week" => run,
 	"yday" => run,
 	"year" => run,
-	"from" => (from::Package)
+	"from" => (from::Package),
+	"is" => (is::Package)
 );
@@ -0,0 +1,13 @@
+use super::run;
+use crate::fnc::script::modules::impl_module_def;
+
+#[non_exhaustive]
+pub struct Package;
+
+impl_module_def!(
+	Package,
+	"time::is",
+	"business_day" => run,
+	"leap_year" => run,
+	"weekend" => run
+);
@@ -1,7 +1,16 @@
 use crate::err::Error;
 use crate::fnc::util::string;
 use crate::sql::value::Value;
+use crate::sql::Number;
+use crate::sql::Object;
 use crate::sql::Regex;
+use once_cell::sync::Lazy;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[rustfmt::skip] static NUMBER_RE: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r"[-+]?\d+(\.\d+)?").unwrap());
+#[rustfmt::skip] static EMAIL_RE: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+").unwrap());
 
 /// Returns `true` if a string of this length is too much to allocate.
 fn limit(name: &str, n: usize) -> Result<(), Error> {
@@ -26,10 +35,226 @@ pub fn contains((val, check): (String, String)) -> Result<Value, Error> {
 	Ok(val.contains(&check).into())
 }
 
+pub fn count_matches((val, check, opts): (String, String, Option<Object>)) -> Result<Value, Error> {
+	let overlapping = match opts.as_ref().and_then(|o| o.get("overlapping")) {
+		None | Some(Value::None | Value::Null) => false,
+		Some(Value::Bool(v)) => *v,
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: "string::count_matches".to_owned(),
+				message: format!(
+					"Expected the 'overlapping' option to be a bool, found {}",
+					v.kindof()
+				),
+			})
+		}
+	};
+	// An empty string would match between every character
+	if check.is_empty() {
+		return Ok(0.into());
+	}
+	let count = match overlapping {
+		true => val.char_indices().filter(|(i, _)| val[*i..].starts_with(&check)).count(),
+		false => val.matches(&check).count(),
+	};
+	Ok(count.into())
+}
+
+/// Compares two strings line by line, or word by word, returning the operations which turn the
+/// first into the second
+pub fn diff((a, b, opts): (String, String, Option<Object>)) -> Result<Value, Error> {
+	let words = match opts.as_ref().and_then(|o| o.get("mode")) {
+		None | Some(Value::None | Value::Null) => false,
+		Some(Value::Strand(v)) if v.as_str() == "line" => false,
+		Some(Value::Strand(v)) if v.as_str() == "word" => true,
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: "string::diff".to_owned(),
+				message: format!("Expected the 'mode' option to be 'line' or 'word', found {v}"),
+			})
+		}
+	};
+	let op = |op: &str, text: String| {
+		Value::from(Object::from(map! {
+			"op" => Value::from(op),
+			"text" => Value::from(text),
+		}))
+	};
+	if a == b {
+		return Ok(vec![op("equal", a)].into());
+	}
+	let parts = match words {
+		true => (split_words(&a), split_words(&b)),
+		false => (a.split_inclusive('\n').collect(), b.split_inclusive('\n').collect()),
+	};
+	// Each distinct line or word is stood in for by a single character, so that a
+	// character diff of the two texts is a diff of their lines or words
+	let mut chars = HashMap::new();
+	let (a, b) = (encode_parts(&parts.0, &mut chars)?, encode_parts(&parts.1, &mut chars)?);
+	let decode: HashMap<char, &str> = chars.into_iter().map(|(part, c)| (c, part)).collect();
+	let ops = dmp::new()
+		.diff_main(&a, &b, false)
+		.into_iter()
+		.map(|diff| {
+			let text = diff.text.chars().filter_map(|c| decode.get(&c).copied()).collect();
+			match diff.operation {
+				-1 => op("delete", text),
+				1 => op("insert", text),
+				_ => op("equal", text),
+			}
+		})
+		.collect::<Vec<_>>();
+	Ok(ops.into())
+}
+
+const MAX_DIFF_PARTS: usize = 2usize.pow(20);
+
+/// Encodes each part as a character, reusing the character of any part seen before
+fn encode_parts<'a>(
+	parts: &[&'a str],
+	chars: &mut HashMap<&'a str, char>,
+) -> Result<String, Error> {
+	parts
+		.iter()
+		.map(|part| {
+			let next = chars.len() as u32 + 1;
+			match chars.entry(part) {
+				Entry::Occupied(e) => Ok(*e.get()),
+				Entry::Vacant(e) => {
+					// Skip over the surrogate code points, which are not characters
+					let c = match next < 0xD800 {
+						true => char::from_u32(next),
+						false => char::from_u32(next + 0x800),
+					};
+					match c.filter(|_| (next as usize) <= MAX_DIFF_PARTS) {
+						Some(c) => Ok(*e.insert(c)),
+						None => Err(Error::InvalidArguments {
+							name: "string::diff".to_owned(),
+							message: format!(
+								"The strings must not have more than {MAX_DIFF_PARTS} distinct lines or words."
+							),
+						}),
+					}
+				}
+			}
+		})
+		.collect()
+}
+
+/// Splits a string into alternating runs of whitespace and other characters
+fn split_words(text: &str) -> Vec<&str> {
+	let mut parts = Vec::new();
+	let mut start = 0;
+	let mut chars = text.char_indices().peekable();
+	while let Some((_, c)) = chars.next() {
+		if let Some((i, next)) = chars.peek() {
+			if c.is_whitespace() != next.is_whitespace() {
+				parts.push(&text[start..*i]);
+				start = *i;
+			}
+		}
+	}
+	if start < text.len() {
+		parts.push(&text[start..]);
+	}
+	parts
+}
+
 pub fn ends_with((val, chr): (String, String)) -> Result<Value, Error> {
 	Ok(val.ends_with(&chr).into())
 }
 
+/// Returns the email addresses in a string, leaving out any which are not valid
+pub fn extract_emails((val,): (String,)) -> Result<Value, Error> {
+	let emails = EMAIL_RE
+		.find_iter(&val)
+		.filter_map(|m| {
+			// A sentence may end straight after an address
+			let email = m.as_str().trim_end_matches('.');
+			// Skip candidates which are part of a longer malformed address
+			let end = m.start() + email.len();
+			let joined = val[..m.start()].ends_with('@') || val[end..].starts_with('@');
+			match joined || addr::parse_email_address(email).is_err() {
+				true => None,
+				false => Some(Value::from(email)),
+			}
+		})
+		.collect::<Vec<_>>();
+	Ok(emails.into())
+}
+
+/// Returns the integers and decimals in a string, in the order they appear
+///
+/// Digits which are part of a word, or of a malformed number such as `1.2.3`, are left out.
+pub fn extract_numbers((val,): (String,)) -> Result<Value, Error> {
+	let numbers = NUMBER_RE
+		.find_iter(&val)
+		.filter_map(|m| {
+			let before = val[..m.start()].chars().next_back();
+			let mut after = val[m.end()..].chars();
+			let joined = matches!(before, Some(c) if c.is_alphanumeric() || c == '_' || c == '.')
+				|| match after.next() {
+					Some('.') => after.next().is_some_and(|c| c.is_ascii_digit()),
+					Some(c) => c.is_alphanumeric() || c == '_',
+					None => false,
+				};
+			if joined {
+				return None;
+			}
+			let number = m.as_str().trim_start_matches('+');
+			match number.contains('.') {
+				false => match number.parse::<i64>() {
+					Ok(v) => Some(Number::Int(v)),
+					Err(_) => number.parse::<f64>().ok().map(Number::Float),
+				},
+				true => number.parse::<f64>().ok().map(Number::Float),
+			}
+		})
+		.map(Value::Number)
+		.collect::<Vec<_>>();
+	Ok(numbers.into())
+}
+
+/// Finds the leftmost, non-overlapping occurrences of `term` in `text`, returning
+/// `{ start, end }` objects with character offsets, where `end` is exclusive
+pub fn highlight((text, term, opts): (String, String, Option<Object>)) -> Result<Value, Error> {
+	let case_insensitive = match opts.as_ref().and_then(|o| o.get("case_insensitive")) {
+		None | Some(Value::None | Value::Null) => false,
+		Some(Value::Bool(v)) => *v,
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: String::from("string::highlight"),
+				message: format!(
+					"Expected the 'case_insensitive' option to be a bool, found {}",
+					v.kindof()
+				),
+			})
+		}
+	};
+	let text: Vec<char> = text.chars().collect();
+	let term: Vec<char> = term.chars().collect();
+	let eq = |a: &char, b: &char| match case_insensitive {
+		true => a.to_lowercase().eq(b.to_lowercase()),
+		false => a == b,
+	};
+	let mut spans = Vec::new();
+	let mut start = 0;
+	while !term.is_empty() && start + term.len() <= text.len() {
+		match text[start..start + term.len()].iter().zip(&term).all(|(a, b)| eq(a, b)) {
+			true => {
+				let end = start + term.len();
+				let mut span = Object::default();
+				span.insert("start".to_owned(), start.into());
+				span.insert("end".to_owned(), end.into());
+				spans.push(Value::from(span));
+				start = end;
+			}
+			false => start += 1,
+		}
+	}
+	Ok(spans.into())
+}
+
 pub fn join(args: Vec<Value>) -> Result<Value, Error> {
 	let mut args = args.into_iter().map(Value::as_string);
 	let chr = args.next().ok_or_else(|| Error::InvalidArguments {
@@ -60,6 +285,103 @@ pub fn lowercase((string,): (String,)) -> Result<Value, Error> {
 	Ok(string.to_lowercase().into())
 }
 
+/// Replaces the characters between the first `keep_start` and the last `keep_end`
+/// characters with `mask_char`, preserving the length of the string
+pub fn mask((val, opts): (String, Option<Object>)) -> Result<Value, Error> {
+	let count = |key: &str, default: usize| match opts.as_ref().and_then(|o| o.get(key)) {
+		None | Some(Value::None | Value::Null) => Ok(default),
+		Some(Value::Number(v)) if v.is_integer() && v.to_int() >= 0 => Ok(v.to_usize()),
+		Some(v) => Err(Error::InvalidArguments {
+			name: String::from("string::mask"),
+			message: format!(
+				"Expected the '{key}' option to be a non-negative integer, found {}",
+				v.kindof()
+			),
+		}),
+	};
+	let keep_start = count("keep_start", 0)?;
+	let keep_end = count("keep_end", 4)?;
+	let mask_char = match opts.as_ref().and_then(|o| o.get("mask_char")) {
+		None | Some(Value::None | Value::Null) => '*',
+		Some(Value::Strand(v)) if v.chars().count() == 1 => v.chars().next().unwrap_or('*'),
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: String::from("string::mask"),
+				message: format!(
+					"Expected the 'mask_char' option to be a single character, found {v}"
+				),
+			})
+		}
+	};
+	let len = val.chars().count();
+	if keep_start.saturating_add(keep_end) >= len {
+		return Ok(val.into());
+	}
+	limit("string::mask", val.len().saturating_add(len.saturating_mul(mask_char.len_utf8())))?;
+	Ok(val
+		.chars()
+		.enumerate()
+		.map(|(i, c)| match i < keep_start || i >= len - keep_end {
+			true => c,
+			false => mask_char,
+		})
+		.collect::<String>()
+		.into())
+}
+
+pub fn pad_end((val, len, fill): (String, usize, String)) -> Result<Value, Error> {
+	let padding = padding("string::pad_end", &val, len, &fill)?;
+	Ok((val + &padding).into())
+}
+
+pub fn pad_start((val, len, fill): (String, usize, String)) -> Result<Value, Error> {
+	let padding = padding("string::pad_start", &val, len, &fill)?;
+	Ok((padding + &val).into())
+}
+
+/// Repeats the fill string, truncated to the characters needed to reach the target length
+fn padding(name: &str, val: &str, len: usize, fill: &str) -> Result<String, Error> {
+	let needed = len.saturating_sub(val.chars().count());
+	if needed == 0 {
+		return Ok(String::new());
+	}
+	if fill.is_empty() {
+		return Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The fill string must not be empty."),
+		});
+	}
+	limit(name, val.len().saturating_add(needed.saturating_mul(fill.len())))?;
+	Ok(fill.chars().cycle().take(needed).collect())
+}
+
+/// Replaces the values at the given dotted paths of a JSON document with `"[REDACTED]"`
+///
+/// Numeric path segments index into arrays, and paths which do not exist are ignored.
+pub fn redact_json((val, paths): (String, Vec<String>)) -> Result<Value, Error> {
+	let mut json: serde_json::Value =
+		serde_json::from_str(&val).map_err(|e| Error::InvalidArguments {
+			name: String::from("string::redact_json"),
+			message: format!("Invalid JSON: {e}"),
+		})?;
+	for path in paths {
+		let mut current = Some(&mut json);
+		for field in path.split('.') {
+			current = match current {
+				Some(serde_json::Value::Object(v)) => v.get_mut(field),
+				Some(serde_json::Value::Array(v)) => {
+					field.parse::<usize>().ok().and_then(|i| v.get_mut(i))
+				}
+				_ => None,
+			};
+		}
+		if let Some(value) = current {
+			*value = serde_json::Value::from("[REDACTED]");
+		}
+	}
+	Ok(json.to_string().into())
+}
+
 pub fn repeat((val, num): (String, usize)) -> Result<Value, Error> {
 	limit("string::repeat", val.len().saturating_mul(num))?;
 	Ok(val.repeat(num).into())
@@ -69,6 +391,51 @@ pub fn matches((val, regex): (String, Regex)) -> Result<Value, Error> {
 	Ok(regex.0.is_match(&val).into())
 }
 
+/// Returns the consecutive groups of `n` characters, or of `n` words in `word` mode
+pub fn ngrams((val, n, opts): (String, i64, Option<Object>)) -> Result<Value, Error> {
+	if n <= 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("string::ngrams"),
+			message: String::from("The second argument must be a positive number."),
+		});
+	}
+	let n = n as usize;
+	// Each n-gram holds at least `n` bytes
+	let check = |len: usize| {
+		let count = (len + 1).saturating_sub(n);
+		crate::fnc::array::limit("string::ngrams", count)?;
+		limit("string::ngrams", count.saturating_mul(n))
+	};
+	match opts.as_ref().and_then(|o| o.get("mode")) {
+		None | Some(Value::None | Value::Null) => (),
+		Some(Value::Strand(v)) if v.as_str() == "char" => (),
+		Some(Value::Strand(v)) if v.as_str() == "word" => {
+			let words: Vec<&str> = val.split_whitespace().collect();
+			check(words.len())?;
+			return Ok(words
+				.windows(n)
+				.map(|w| Value::from(w.join(" ")))
+				.collect::<Vec<_>>()
+				.into());
+		}
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: String::from("string::ngrams"),
+				message: format!(
+					"Expected the 'mode' option to be one of 'char' or 'word', found {v}"
+				),
+			})
+		}
+	}
+	let chars: Vec<char> = val.chars().collect();
+	check(chars.len())?;
+	Ok(chars
+		.windows(n)
+		.map(|w| Value::from(w.iter().collect::<String>()))
+		.collect::<Vec<_>>()
+		.into())
+}
+
 pub fn replace((val, old_or_regexp, new): (String, Value, String)) -> Result<Value, Error> {
 	match old_or_regexp {
 		Value::Strand(old) => {
@@ -91,8 +458,9 @@ pub fn replace((val, old_or_regexp, new): (String, Value, String)) -> Result<Val
 		}),
 	}
 }
+/// Reverses a string by grapheme cluster, so that combining marks and emoji sequences stay intact
 pub fn reverse((string,): (String,)) -> Result<Value, Error> {
-	Ok(string.chars().rev().collect::<String>().into())
+	Ok(string.graphemes(true).rev().collect::<String>().into())
 }
 
 pub fn slice((val, beg, lim): (String, Option<isize>, Option<isize>)) -> Result<Value, Error> {
@@ -137,6 +505,27 @@ pub fn starts_with((val, chr): (String, String)) -> Result<Value, Error> {
 	Ok(val.starts_with(&chr).into())
 }
 
+pub fn to_case((val, case): (String, String)) -> Result<Value, Error> {
+	let words = string::case::words(val);
+	let lower = || words.iter().map(String::as_str);
+	let capital = || words.iter().map(|w| string::case::capitalize(w));
+	Ok(match case.as_str() {
+		"snake" => lower().collect::<Vec<_>>().join("_"),
+		"kebab" => lower().collect::<Vec<_>>().join("-"),
+		"constant" => words.join("_").to_uppercase(),
+		"pascal" => capital().collect::<String>(),
+		"camel" => lower().take(1).map(str::to_owned).chain(capital().skip(1)).collect::<String>(),
+		"title" => capital().collect::<Vec<_>>().join(" "),
+		_ => {
+			return Err(Error::InvalidArguments {
+				name: String::from("string::to_case"),
+				message: format!("Expected one of 'snake', 'kebab', 'camel', 'pascal', 'constant' or 'title', but found '{case}'."),
+			})
+		}
+	}
+	.into())
+}
+
 pub fn trim((string,): (String,)) -> Result<Value, Error> {
 	Ok(string.trim().into())
 }
@@ -149,6 +538,58 @@ pub fn words((string,): (String,)) -> Result<Value, Error> {
 	Ok(string.split_whitespace().collect::<Vec<&str>>().into())
 }
 
+pub fn wrap((val, width, opts): (String, i64, Option<Object>)) -> Result<Value, Error> {
+	if width <= 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("string::wrap"),
+			message: String::from("The second argument must be a positive number."),
+		});
+	}
+	let width = width as usize;
+	let break_long_words = match opts.as_ref().and_then(|o| o.get("break_long_words")) {
+		None | Some(Value::None | Value::Null) => false,
+		Some(Value::Bool(v)) => *v,
+		Some(v) => {
+			return Err(Error::InvalidArguments {
+				name: String::from("string::wrap"),
+				message: format!(
+					"Expected the 'break_long_words' option to be a boolean, found {}",
+					v.kindof()
+				),
+			})
+		}
+	};
+	let mut out = String::with_capacity(val.len());
+	// Existing line breaks are kept, and the text between them is wrapped on its own
+	for (i, line) in val.split('\n').enumerate() {
+		if i > 0 {
+			out.push('\n');
+		}
+		let mut len = 0;
+		for mut word in line.split_whitespace() {
+			let mut chars = word.chars().count();
+			if len > 0 && len + 1 + chars <= width {
+				out.push(' ');
+				len += 1;
+			} else if len > 0 {
+				out.push('\n');
+				len = 0;
+			}
+			// Words longer than a whole line are either split up or left to overflow it
+			while break_long_words && chars > width {
+				let end = word.char_indices().nth(width).map_or(word.len(), |(i, _)| i);
+				out.push_str(&word[..end]);
+				out.push('\n');
+				word = &word[end..];
+				chars -= width;
+			}
+			out.push_str(word);
+			len += chars;
+		}
+	}
+	Ok(out.into())
+}
+
 pub mod distance {
 
 	use crate::err::Error;
@@ -244,7 +685,7 @@ pub mod is {
 	}
 
 	pub fn numeric((arg,): (String,)) -> Result<Value, Error> {
-		Ok(arg.chars().all(char::is_numeric).into())
+		Ok((!arg.is_empty() && arg.chars().all(char::is_numeric)).into())
 	}
 
 	pub fn semver((arg,): (String,)) -> Result<Value, Error> {
@@ -252,7 +693,9 @@ pub mod is {
 	}
 
 	pub fn url((arg,): (String,)) -> Result<Value, Error> {
-		Ok(Url::parse(&arg).is_ok().into())
+		// The parser strips whitespace and control characters, so reject those up front
+		let clean = !arg.chars().any(|c| c.is_whitespace() || c.is_control());
+		Ok((clean && Url::parse(&arg).is_ok()).into())
 	}
 
 	pub fn uuid((arg,): (Value,)) -> Result<Value, Error> {
@@ -401,7 +844,10 @@ pub mod semver {
 
 #[cfg(test)]
 mod tests {
-	use super::{contains, matches, replace, slice};
+	use super::{
+		contains, count_matches, extract_emails, extract_numbers, matches, pad_end, pad_start,
+		replace, reverse, slice, to_case,
+	};
 	use crate::sql::Value;
 
 	#[test]
@@ -426,6 +872,70 @@ mod tests {
 		test(string, Some(-2), Some(1), "世");
 	}
 
+	#[test]
+	fn string_count_matches() {
+		fn test(val: &str, check: &str, overlapping: bool, expected: usize) {
+			let opts = crate::sql::Object::from(map! {
+				"overlapping" => Value::from(overlapping),
+			});
+			assert_eq!(
+				count_matches((val.to_owned(), check.to_owned(), Some(opts))).unwrap(),
+				Value::from(expected)
+			);
+		}
+
+		test("aaa", "aa", true, 2);
+		test("aaa", "aa", false, 1);
+		test("abcabc", "bc", false, 2);
+		test("abc", "", true, 0);
+		test("abc", "", false, 0);
+		test("", "a", true, 0);
+	}
+
+	#[test]
+	fn string_extract_numbers() {
+		fn test(val: &str, expected: Vec<Value>) {
+			assert_eq!(extract_numbers((val.to_owned(),)).unwrap(), Value::from(expected));
+		}
+
+		test("3 apples cost 1.25, or -0.5 each", vec![3.into(), 1.25.into(), (-0.5).into()]);
+		test("version 1.2.3 of v2 was 3rd", vec![]);
+		test("+7 and 99999999999999999999", vec![7.into(), 1e20.into()]);
+		test("no numbers", vec![]);
+	}
+
+	#[test]
+	fn string_extract_emails() {
+		fn test(val: &str, expected: Vec<&str>) {
+			let expected: Vec<Value> = expected.into_iter().map(Value::from).collect();
+			assert_eq!(extract_emails((val.to_owned(),)).unwrap(), Value::from(expected));
+		}
+
+		test("Mail a@b.com or c.d+e@f.co.uk.", vec!["a@b.com", "c.d+e@f.co.uk"]);
+		test("Not a@b@c.com nor @d.com nor e@", vec![]);
+	}
+
+	#[test]
+	fn string_reverse() {
+		fn test(initial: &str, expected: &str) {
+			assert_eq!(reverse((initial.to_owned(),)).unwrap(), Value::from(expected));
+		}
+
+		test("", "");
+		test("abc", "cba");
+		// An `e` followed by a combining acute accent
+		let accented = "cafe\u{301}";
+		assert_eq!(accented.chars().rev().collect::<String>(), "\u{301}efac");
+		test(accented, "e\u{301}fac");
+		// A family emoji joined with zero width joiners
+		let family = "a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}b";
+		assert_eq!(
+			family.chars().rev().collect::<String>(),
+			"b\u{1F467}\u{200D}\u{1F469}\u{200D}\u{1F468}a"
+		);
+		test(family, "b\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}a");
+	}
+
 	#[test]
 	fn string_contains() {
 		fn test(base: &str, contained: &str, expected: bool) {
@@ -488,6 +998,16 @@ mod tests {
 		assert_eq!(value, Value::Bool(false));
 	}
 
+	#[test]
+	fn split_words() {
+		assert_eq!(
+			super::split_words("the  quick\tfox "),
+			["the", "  ", "quick", "\t", "fox", " "]
+		);
+		assert_eq!(super::split_words(" ä b"), [" ", "ä", " ", "b"]);
+		assert!(super::split_words("").is_empty());
+	}
+
 	#[test]
 	fn is_alpha() {
 		let value = super::is::alpha((String::from("abc"),)).unwrap();
@@ -689,4 +1209,45 @@ mod tests {
 		let value = super::semver::set::patch((String::from("1.2.3"), 9)).unwrap();
 		assert_eq!(value, Value::from("1.2.9"));
 	}
+
+	#[test]
+	fn string_pad() {
+		fn test(string: &str, len: usize, fill: &str, start: &str, end: &str) {
+			let args = || (string.to_owned(), len, fill.to_owned());
+			assert_eq!(pad_start(args()).unwrap(), Value::from(start));
+			assert_eq!(pad_end(args()).unwrap(), Value::from(end));
+		}
+
+		test("42", 6, "0", "000042", "420000");
+		test("abc", 8, "xyz", "xyzxyabc", "abcxyzxy");
+		test("ππ", 4, "é", "ééππ", "ππéé");
+		test("abcdef", 4, "0", "abcdef", "abcdef");
+		test("abcd", 4, "", "abcd", "abcd");
+		test("", 3, "ab", "aba", "aba");
+		assert!(pad_start(("a".to_owned(), 2, String::new())).is_err());
+	}
+
+	#[test]
+	fn string_to_case() {
+		fn test(string: &str, case: &str, expected: &str) {
+			assert_eq!(
+				to_case((string.to_owned(), case.to_owned())).unwrap(),
+				Value::from(expected)
+			);
+		}
+
+		let string = "fooBar_baz";
+		test(string, "snake", "foo_bar_baz");
+		test(string, "kebab", "foo-bar-baz");
+		test(string, "camel", "fooBarBaz");
+		test(string, "pascal", "FooBarBaz");
+		test(string, "constant", "FOO_BAR_BAZ");
+		test(string, "title", "Foo Bar Baz");
+
+		test("HTTPServer error", "snake", "http_server_error");
+		test("  --leading and trailing--  ", "camel", "leadingAndTrailing");
+		test("version2Update", "kebab", "version2-update");
+		test("", "pascal", "");
+		assert!(to_case(("foo".to_owned(), "upper".to_owned())).is_err());
+	}
 }
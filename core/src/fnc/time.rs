@@ -1,9 +1,134 @@
+use crate::ctx::Context;
 use crate::err::Error;
 use crate::sql::datetime::Datetime;
 use crate::sql::duration::Duration;
 use crate::sql::value::Value;
+use crate::sql::Array;
+use crate::sql::Object;
 use chrono::offset::TimeZone;
-use chrono::{DateTime, Datelike, DurationRound, Local, Timelike, Utc};
+use chrono::{DateTime, Datelike, DurationRound, Local, Months, NaiveDate, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use std::collections::HashSet;
+
+pub fn add((val, amount, unit): (Datetime, i64, String)) -> Result<Value, Error> {
+	shift("time::add", val, amount, unit)
+}
+
+/// Moves a datetime by a number of calendar units, clamping to the end of shorter months
+fn shift(name: &str, val: Datetime, amount: i64, unit: String) -> Result<Value, Error> {
+	let months = |n: i64| {
+		let n = u32::try_from(n.unsigned_abs()).ok()?;
+		match amount < 0 {
+			true => val.checked_sub_months(Months::new(n)),
+			false => val.checked_add_months(Months::new(n)),
+		}
+	};
+	let result = match unit.as_str() {
+		"year" | "years" => amount.checked_mul(12).and_then(months),
+		"month" | "months" => months(amount),
+		"week" | "weeks" => {
+			chrono::Duration::try_weeks(amount).and_then(|d| val.checked_add_signed(d))
+		}
+		"day" | "days" => chrono::Duration::try_days(amount).and_then(|d| val.checked_add_signed(d)),
+		"hour" | "hours" => {
+			chrono::Duration::try_hours(amount).and_then(|d| val.checked_add_signed(d))
+		}
+		"minute" | "minutes" => {
+			chrono::Duration::try_minutes(amount).and_then(|d| val.checked_add_signed(d))
+		}
+		"second" | "seconds" => {
+			chrono::Duration::try_seconds(amount).and_then(|d| val.checked_add_signed(d))
+		}
+		_ => {
+			return Err(Error::InvalidArguments {
+				name: name.to_owned(),
+				message: String::from("The third argument must be one of 'year', 'month', 'week', 'day', 'hour', 'minute' or 'second'."),
+			})
+		}
+	};
+	match result {
+		Some(v) => Ok(v.into()),
+		None => Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The resulting datetime is out of range."),
+		}),
+	}
+}
+
+/// The calendar span between a birth date and now, or the given reference date
+///
+/// Returns the whole years, months and days between the two dates, counting months the same
+/// way as `time::add`, so a birthday on the 29th of February falls on the 28th in other years.
+/// A birth date after the reference date is an error rather than a negative age.
+pub fn age((birth, now): (Datetime, Option<Datetime>)) -> Result<Value, Error> {
+	let invalid = |message: &str| Error::InvalidArguments {
+		name: String::from("time::age"),
+		message: message.to_owned(),
+	};
+	let from = birth.date_naive();
+	let to = now.unwrap_or_default().date_naive();
+	if from > to {
+		return Err(invalid("The birth date must not be after the reference date."));
+	}
+	let add = |months: i32| from.checked_add_months(Months::new(months as u32));
+	// Take the whole months which fit, and count the remaining days
+	let mut months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+	let mut date = add(months).ok_or_else(|| invalid("The resulting date is out of range."))?;
+	if date > to {
+		months -= 1;
+		date = add(months).ok_or_else(|| invalid("The resulting date is out of range."))?;
+	}
+	let mut out = Object::default();
+	out.insert("years".to_owned(), (months / 12).into());
+	out.insert("months".to_owned(), (months % 12).into());
+	out.insert("days".to_owned(), (to - date).num_days().into());
+	Ok(out.into())
+}
+
+pub fn business_days_between(
+	(start, end, holidays): (Datetime, Datetime, Option<Array>),
+) -> Result<Value, Error> {
+	let holidays = holiday_dates("time::business_days_between", "third", holidays)?;
+	// Count the days from the earlier date, up to but excluding the later date
+	let (from, to, sign) = match start.date_naive() <= end.date_naive() {
+		true => (start.date_naive(), end.date_naive(), 1),
+		false => (end.date_naive(), start.date_naive(), -1),
+	};
+	let days = (to - from).num_days();
+	let mut count = days / 7 * 5;
+	let mut day = from + chrono::Duration::days(days / 7 * 7);
+	while day < to {
+		if !is_weekend(day) {
+			count += 1;
+		}
+		day = day.succ_opt().unwrap_or(to);
+	}
+	count -= holidays.iter().filter(|d| **d >= from && **d < to && !is_weekend(**d)).count() as i64;
+	Ok((count * sign).into())
+}
+
+fn is_weekend(day: NaiveDate) -> bool {
+	matches!(day.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Collects the distinct calendar dates of an optional array of holidays
+fn holiday_dates(
+	name: &str,
+	arg: &str,
+	holidays: Option<Array>,
+) -> Result<HashSet<NaiveDate>, Error> {
+	holidays
+		.unwrap_or_default()
+		.into_iter()
+		.map(|v| match v.convert_to_datetime() {
+			Ok(v) => Ok(v.date_naive()),
+			Err(_) => Err(Error::InvalidArguments {
+				name: name.to_owned(),
+				message: format!("The {arg} argument must be an array of datetimes."),
+			}),
+		})
+		.collect()
+}
 
 pub fn ceil((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
 	match chrono::Duration::from_std(*duration) {
@@ -46,6 +171,10 @@ pub fn day((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
+pub fn day_of_year((val, tz): (Option<Datetime>, Option<String>)) -> Result<Value, Error> {
+	Ok(local_date("time::day_of_year", val, tz)?.ordinal().into())
+}
+
 pub fn floor((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
 	match chrono::Duration::from_std(*duration) {
 		Ok(d) => {
@@ -68,8 +197,12 @@ pub fn floor((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
 	}
 }
 
-pub fn format((val, format): (Datetime, String)) -> Result<Value, Error> {
-	Ok(val.format(&format).to_string().into())
+pub fn format(ctx: &Context, (val, format): (Datetime, String)) -> Result<Value, Error> {
+	Ok(match ctx.timezone() {
+		Some(tz) => val.with_timezone(&tz).format(&format).to_string(),
+		None => val.format(&format).to_string(),
+	}
+	.into())
 }
 
 pub fn group((val, group): (Datetime, String)) -> Result<Value, Error> {
@@ -171,6 +304,10 @@ pub fn now(_: ()) -> Result<Value, Error> {
 	Ok(Datetime::default().into())
 }
 
+pub fn quarter((val, tz): (Option<Datetime>, Option<String>)) -> Result<Value, Error> {
+	Ok((local_date("time::quarter", val, tz)?.month0() / 3 + 1).into())
+}
+
 pub fn round((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
 	match chrono::Duration::from_std(*duration) {
 		Ok(d) => {
@@ -200,6 +337,16 @@ pub fn second((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
+pub fn subtract((val, amount, unit): (Datetime, i64, String)) -> Result<Value, Error> {
+	match amount.checked_neg() {
+		Some(amount) => shift("time::subtract", val, amount, unit),
+		None => Err(Error::InvalidArguments {
+			name: String::from("time::subtract"),
+			message: String::from("The resulting datetime is out of range."),
+		}),
+	}
+}
+
 pub fn timezone(_: ()) -> Result<Value, Error> {
 	Ok(Local::now().offset().to_string().into())
 }
@@ -218,11 +365,8 @@ pub fn wday((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
-pub fn week((val,): (Option<Datetime>,)) -> Result<Value, Error> {
-	Ok(match val {
-		Some(v) => v.iso_week().week().into(),
-		None => Datetime::default().iso_week().week().into(),
-	})
+pub fn week((val, tz): (Option<Datetime>, Option<String>)) -> Result<Value, Error> {
+	Ok(local_date("time::week", val, tz)?.iso_week().week().into())
 }
 
 pub fn yday((val,): (Option<Datetime>,)) -> Result<Value, Error> {
@@ -239,6 +383,31 @@ pub fn year((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
+pub mod is {
+
+	use super::{holiday_dates, is_weekend, local_date, local_date_at};
+	use crate::err::Error;
+	use crate::sql::datetime::Datetime;
+	use crate::sql::value::Value;
+	use crate::sql::Array;
+
+	pub fn business_day(
+		(val, holidays, tz): (Datetime, Option<Array>, Option<String>),
+	) -> Result<Value, Error> {
+		let holidays = holiday_dates("time::is::business_day", "second", holidays)?;
+		let day = local_date_at("time::is::business_day", "third", Some(val), tz)?;
+		Ok((!is_weekend(day) && !holidays.contains(&day)).into())
+	}
+
+	pub fn leap_year((val, tz): (Option<Datetime>, Option<String>)) -> Result<Value, Error> {
+		Ok(local_date("time::is::leap_year", val, tz)?.leap_year().into())
+	}
+
+	pub fn weekend((val, tz): (Option<Datetime>, Option<String>)) -> Result<Value, Error> {
+		Ok(is_weekend(local_date("time::is::weekend", val, tz)?).into())
+	}
+}
+
 pub mod from {
 
 	use crate::err::Error;
@@ -301,3 +470,28 @@ pub mod from {
 		}
 	}
 }
+
+/// The calendar date of a datetime, in UTC or in the given timezone
+fn local_date(name: &str, val: Option<Datetime>, tz: Option<String>) -> Result<NaiveDate, Error> {
+	local_date_at(name, "second", val, tz)
+}
+
+/// The calendar date of a datetime, with the timezone given as the named argument
+fn local_date_at(
+	name: &str,
+	arg: &str,
+	val: Option<Datetime>,
+	tz: Option<String>,
+) -> Result<NaiveDate, Error> {
+	let val = val.unwrap_or_default();
+	match tz {
+		Some(tz) => match tz.parse::<Tz>() {
+			Ok(tz) => Ok(val.with_timezone(&tz).date_naive()),
+			Err(_) => Err(Error::InvalidArguments {
+				name: name.to_owned(),
+				message: format!("The {arg} argument must be a known timezone, but found '{tz}'."),
+			}),
+		},
+		None => Ok(val.date_naive()),
+	}
+}
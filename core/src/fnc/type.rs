@@ -15,6 +15,14 @@ pub fn bool((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_bool().map(Value::from)
 }
 
+pub fn coerce((val, kind): (Value, String)) -> Result<Value, Error> {
+	let kind = syn::kind(&kind).map_err(|_| Error::InvalidArguments {
+		name: String::from("type::coerce"),
+		message: format!("The second argument must be a valid type, but found '{kind}'."),
+	})?;
+	val.convert_to(&kind)
+}
+
 pub fn datetime((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_datetime().map(Value::from)
 }
@@ -88,10 +96,18 @@ pub fn string((val,): (Value,)) -> Result<Value, Error> {
 }
 
 pub fn table((val,): (Value,)) -> Result<Value, Error> {
-	Ok(Value::Table(Table(match val {
+	let name = match val {
 		Value::Thing(t) => t.tb,
+		Value::None | Value::Null => String::new(),
 		v => v.as_string(),
-	})))
+	};
+	if name.is_empty() {
+		return Err(Error::InvalidArguments {
+			name: String::from("type::table"),
+			message: String::from("The table name must not be empty."),
+		});
+	}
+	Ok(Value::Table(Table(name)))
 }
 
 pub fn thing((arg1, arg2): (Value, Option<Value>)) -> Result<Value, Error> {
@@ -240,7 +256,7 @@ pub mod is {
 	}
 
 	pub fn collection((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(matches!(arg, Value::Geometry(Geometry::Collection(_))).into())
+		Ok(matches!(as_geometry(arg), Some(Geometry::Collection(_))).into())
 	}
 
 	pub fn datetime((arg,): (Value,)) -> Result<Value, Error> {
@@ -260,7 +276,7 @@ pub mod is {
 	}
 
 	pub fn geometry((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(arg.is_geometry().into())
+		Ok(as_geometry(arg).is_some().into())
 	}
 
 	pub fn int((arg,): (Value,)) -> Result<Value, Error> {
@@ -268,7 +284,7 @@ pub mod is {
 	}
 
 	pub fn line((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(matches!(arg, Value::Geometry(Geometry::Line(_))).into())
+		Ok(matches!(as_geometry(arg), Some(Geometry::Line(_))).into())
 	}
 
 	pub fn none((arg,): (Value,)) -> Result<Value, Error> {
@@ -280,15 +296,15 @@ pub mod is {
 	}
 
 	pub fn multiline((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(matches!(arg, Value::Geometry(Geometry::MultiLine(_))).into())
+		Ok(matches!(as_geometry(arg), Some(Geometry::MultiLine(_))).into())
 	}
 
 	pub fn multipoint((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(matches!(arg, Value::Geometry(Geometry::MultiPoint(_))).into())
+		Ok(matches!(as_geometry(arg), Some(Geometry::MultiPoint(_))).into())
 	}
 
 	pub fn multipolygon((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(matches!(arg, Value::Geometry(Geometry::MultiPolygon(_))).into())
+		Ok(matches!(as_geometry(arg), Some(Geometry::MultiPolygon(_))).into())
 	}
 
 	pub fn number((arg,): (Value,)) -> Result<Value, Error> {
@@ -300,11 +316,11 @@ pub mod is {
 	}
 
 	pub fn point((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(matches!(arg, Value::Geometry(Geometry::Point(_))).into())
+		Ok(matches!(as_geometry(arg), Some(Geometry::Point(_))).into())
 	}
 
 	pub fn polygon((arg,): (Value,)) -> Result<Value, Error> {
-		Ok(matches!(arg, Value::Geometry(Geometry::Polygon(_))).into())
+		Ok(matches!(as_geometry(arg), Some(Geometry::Polygon(_))).into())
 	}
 
 	pub fn record((arg, table): (Value, Option<String>)) -> Result<Value, Error> {
@@ -321,6 +337,15 @@ pub mod is {
 	pub fn uuid((arg,): (Value,)) -> Result<Value, Error> {
 		Ok(arg.is_uuid().into())
 	}
+
+	/// Reads a geometry, or a GeoJSON object which is a valid geometry
+	fn as_geometry(arg: Value) -> Option<Geometry> {
+		match arg {
+			Value::Geometry(geometry) => Some(geometry),
+			Value::Object(object) => Geometry::try_from_object(&object),
+			_ => None,
+		}
+	}
 }
 
 #[cfg(test)]
@@ -1,21 +1,29 @@
-use crate::sql::number::Number;
+use crate::sql::array::Array;
+use crate::sql::value::Value;
 use std::collections::BTreeMap;
 
 pub trait Mode {
-	/// Find the most frequent number in collection
-	/// O(n*w) time complex s.t. w = distinct count
-	fn mode(self) -> Number;
+	/// Find the most frequent values in collection, in the order they first appear
+	/// O(n*log(w)) time complex s.t. w = distinct count
+	fn mode(self) -> Vec<Value>;
 }
 
-impl Mode for Vec<Number> {
-	fn mode(self) -> Number {
-		// Iterate over all numbers, and get their frequency
-		let frequencies = self.into_iter().fold(BTreeMap::new(), |mut freqs, value| {
-			let entry = freqs.entry(value).or_insert_with(|| 0u32);
-			*entry += 1;
-			freqs
-		});
-		// Get the maximum number by frequency
-		frequencies.into_iter().max_by_key(|(_, n)| *n).map(|(v, _)| v).unwrap_or(Number::NAN)
+impl Mode for Array {
+	fn mode(self) -> Vec<Value> {
+		// Iterate over all values, and get their frequency
+		let mut frequencies: BTreeMap<&Value, usize> = BTreeMap::new();
+		for value in self.iter() {
+			*frequencies.entry(value).or_default() += 1;
+		}
+		// Get the highest frequency
+		let max = frequencies.values().copied().max().unwrap_or_default();
+		// Keep every value with that frequency
+		let mut modes = Vec::new();
+		for value in self.iter() {
+			if frequencies.remove(value) == Some(max) {
+				modes.push(value.clone());
+			}
+		}
+		modes
 	}
 }
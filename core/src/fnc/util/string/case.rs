@@ -0,0 +1,33 @@
+/// Splits a string into lowercase words
+///
+/// Words are separated by any non-alphanumeric characters, and by case transitions such as
+/// `fooBar` and `HTTPServer`.
+pub fn words<S: AsRef<str>>(s: S) -> Vec<String> {
+	let mut words = Vec::new();
+	for part in s.as_ref().split(|c: char| !c.is_alphanumeric()).filter(|p| !p.is_empty()) {
+		let chars: Vec<char> = part.chars().collect();
+		let mut word = String::new();
+		for (i, &c) in chars.iter().enumerate() {
+			if i > 0 && c.is_uppercase() {
+				let prev = chars[i - 1];
+				let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+				// Split `fooBar` before the `B`, and `HTTPServer` before the `S`
+				if !prev.is_uppercase() || next_is_lower {
+					words.push(std::mem::take(&mut word));
+				}
+			}
+			word.extend(c.to_lowercase());
+		}
+		words.push(word);
+	}
+	words
+}
+
+/// Uppercases the first character of a lowercase word
+pub fn capitalize(word: &str) -> String {
+	let mut chars = word.chars();
+	match chars.next() {
+		Some(first) => first.to_uppercase().chain(chars).collect(),
+		None => String::new(),
+	}
+}
@@ -0,0 +1,100 @@
+use crate::sql::statements::DefineIndexStatement;
+use crate::sql::{Thing, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// The values each record had before it was written, for every record written to a table
+pub(crate) type Writes = HashMap<Thing, Vec<Value>>;
+
+/// The namespace, database and name of a table
+type TableKey = (String, String, String);
+
+/// An index being built, with the records written to its table which it is missing
+struct Build {
+	index: String,
+	writes: Mutex<Writes>,
+}
+
+/// Tracks the records written to tables while indexes are being built on them
+///
+/// A transaction which started before an index was defined does not update the index, so the
+/// records it writes while the index is being built are missing from it, or still indexed with
+/// the values they had before.
+#[derive(Clone, Default)]
+pub(crate) struct IndexBuilds(Arc<RwLock<HashMap<TableKey, Vec<Arc<Build>>>>>);
+
+impl IndexBuilds {
+	/// Starts tracking the records written to the table of an index, until the returned
+	/// writes are dropped
+	pub(crate) fn track(&self, ns: &str, db: &str, ix: &DefineIndexStatement) -> TrackedWrites {
+		let key = (ns.to_owned(), db.to_owned(), ix.what.to_raw());
+		let build = Arc::new(Build {
+			index: ix.name.to_raw(),
+			writes: Mutex::default(),
+		});
+		let mut builds = self.0.write().unwrap_or_else(|e| e.into_inner());
+		builds.entry(key.clone()).or_default().push(build.clone());
+		TrackedWrites {
+			builds: self.clone(),
+			key,
+			build,
+		}
+	}
+
+	/// Records a write to a record by a transaction which can only see the given indexes
+	pub(crate) fn record(
+		&self,
+		ns: &str,
+		db: &str,
+		rid: &Thing,
+		ixs: &[DefineIndexStatement],
+		before: &Value,
+	) {
+		let builds = self.0.read().unwrap_or_else(|e| e.into_inner());
+		if builds.is_empty() {
+			return;
+		}
+		let key = (ns.to_owned(), db.to_owned(), rid.tb.clone());
+		let Some(builds) = builds.get(&key) else {
+			return;
+		};
+		for build in builds {
+			// Transactions which can see the index keep it up to date themselves
+			if ixs.iter().any(|ix| ix.name.0 == build.index) {
+				continue;
+			}
+			let mut writes = build.writes.lock().unwrap_or_else(|e| e.into_inner());
+			let values = writes.entry(rid.clone()).or_default();
+			if !before.is_none() && !values.contains(before) {
+				values.push(before.clone());
+			}
+		}
+	}
+}
+
+/// The records written to the table of an index while it is being built
+pub(crate) struct TrackedWrites {
+	builds: IndexBuilds,
+	key: TableKey,
+	build: Arc<Build>,
+}
+
+impl TrackedWrites {
+	/// Takes the writes recorded since they were last taken
+	pub(crate) fn take(&self) -> Writes {
+		let mut writes = self.build.writes.lock().unwrap_or_else(|e| e.into_inner());
+		std::mem::take(&mut *writes)
+	}
+}
+
+impl Drop for TrackedWrites {
+	fn drop(&mut self) {
+		let mut builds = self.builds.0.write().unwrap_or_else(|e| e.into_inner());
+		if let Some(tracked) = builds.get_mut(&self.key) {
+			tracked.retain(|build| !Arc::ptr_eq(build, &self.build));
+			if tracked.is_empty() {
+				builds.remove(&self.key);
+			}
+		}
+	}
+}
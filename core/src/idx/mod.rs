@@ -1,3 +1,4 @@
+pub(crate) mod builds;
 pub mod docids;
 pub(crate) mod ft;
 pub mod planner;
@@ -6,6 +6,7 @@ pub(crate) mod tree;
 use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::err::Error;
+use crate::idx::builds::IndexBuilds;
 use crate::idx::trees::bkeys::{FstKeys, TrieKeys};
 use crate::idx::trees::btree::{BTreeNode, BTreeStore};
 use crate::idx::trees::mtree::{MTreeNode, MTreeStore};
@@ -220,6 +221,7 @@ struct Inner {
 	btree_trie_caches: TreeCaches<BTreeNode<TrieKeys>>,
 	mtree_caches: TreeCaches<MTreeNode>,
 	hnsw_indexes: HnswIndexes,
+	builds: IndexBuilds,
 }
 impl Default for IndexStores {
 	fn default() -> Self {
@@ -228,6 +230,7 @@ impl Default for IndexStores {
 			btree_trie_caches: TreeCaches::default(),
 			mtree_caches: TreeCaches::default(),
 			hnsw_indexes: HnswIndexes::default(),
+			builds: IndexBuilds::default(),
 		}))
 	}
 }
@@ -288,6 +291,10 @@ impl IndexStores {
 		Ok(self.0.hnsw_indexes.get(&ikb, p).await)
 	}
 
+	pub(crate) fn builds(&self) -> &IndexBuilds {
+		&self.0.builds
+	}
+
 	pub(crate) async fn index_removed(
 		&self,
 		tx: &mut Transaction,
@@ -74,8 +74,12 @@ pub enum KeyCategory {
 	TableView, // (ft = foreign table = view)
 	/// crate::key::table::ix                /*{ns}*{db}*{tb}!ix{ix}
 	IndexDefinition,
+	/// crate::key::table::lk                /*{ns}*{db}*{tb}!lk{id}
+	TableRecordLock,
 	/// crate::key::table::lq                /*{ns}*{db}*{tb}!lq{lq}
 	TableLiveQuery,
+	/// crate::key::table::rv                /*{ns}*{db}*{tb}!rv{id}
+	TableRecordVersion,
 	///
 	/// crate::key::index::all               /*{ns}*{db}*{tb}+{ix}
 	IndexRoot,
@@ -150,7 +154,9 @@ impl Display for KeyCategory {
 			KeyCategory::TableField => "TableField",
 			KeyCategory::TableView => "TableView",
 			KeyCategory::IndexDefinition => "IndexDefinition",
+			KeyCategory::TableRecordLock => "TableRecordLock",
 			KeyCategory::TableLiveQuery => "TableLiveQuery",
+			KeyCategory::TableRecordVersion => "TableRecordVersion",
 			KeyCategory::IndexRoot => "IndexRoot",
 			KeyCategory::IndexTermDocList => "IndexTermDocList",
 			KeyCategory::IndexBTreeNode => "IndexBTreeNode",
@@ -0,0 +1,70 @@
+//! Stores an advisory lock on a record
+use crate::key::error::KeyCategory;
+use crate::key::key_req::KeyRequirements;
+use crate::sql::id::Id;
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+#[non_exhaustive]
+pub struct Lk<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	_e: u8,
+	_f: u8,
+	pub id: Id,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, tb: &'a str, id: &Id) -> Lk<'a> {
+	Lk::new(ns, db, tb, id.to_owned())
+}
+
+impl KeyRequirements for Lk<'_> {
+	fn key_category(&self) -> KeyCategory {
+		KeyCategory::TableRecordLock
+	}
+}
+
+impl<'a> Lk<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, id: Id) -> Self {
+		Self {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'*',
+			tb,
+			_d: b'!',
+			_e: b'l',
+			_f: b'k',
+			id,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Lk::new(
+			"testns",
+			"testdb",
+			"testtb",
+			"testid".into(),
+		);
+		let enc = Lk::encode(&val).unwrap();
+		assert_eq!(enc, b"/*testns\0*testdb\0*testtb\0!lk\0\0\0\x01testid\0");
+
+		let dec = Lk::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -3,4 +3,6 @@ pub mod ev;
 pub mod fd;
 pub mod ft;
 pub mod ix;
+pub mod lk;
 pub mod lq;
+pub mod rv;
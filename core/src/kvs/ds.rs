@@ -14,6 +14,8 @@ use std::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use channel::{Receiver, Sender};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use futures::{lock::Mutex, Future};
 use reblessive::{tree::Stk, TreeStack};
 use tokio::sync::RwLock;
@@ -29,13 +31,15 @@ use crate::ctx::Context;
 #[cfg(feature = "jwks")]
 use crate::dbs::capabilities::NetTarget;
 use crate::dbs::{
-	node::Timestamp, Attach, Capabilities, Executor, Notification, Options, Response, Session,
-	Variables,
+	node::Timestamp, Attach, Capabilities, Executor, Notification, Options, RecordLock, Response,
+	Session, Variables,
 };
+use crate::doc;
 use crate::err::Error;
 #[cfg(feature = "jwks")]
 use crate::iam::jwks::JwksCache;
-use crate::iam::{Action, Auth, Error as IamError, Resource, Role};
+use crate::iam::{Action, Auth, Error as IamError, Level, Resource, ResourceKind, Role};
+use crate::idx::builds::Writes;
 use crate::idx::trees::store::IndexStores;
 use crate::key::root::hb::Hb;
 use crate::kvs::clock::SizedClock;
@@ -44,9 +48,13 @@ use crate::kvs::clock::SystemClock;
 use crate::kvs::lq_cf::LiveQueryTracker;
 use crate::kvs::lq_structs::{LqValue, TrackedResult, UnreachableLqType};
 use crate::kvs::lq_v2_fut::process_lq_notifications;
+use crate::kvs::IsolationLevel;
 use crate::kvs::{LockType, LockType::*, TransactionType, TransactionType::*};
 use crate::options::EngineOptions;
-use crate::sql::{self, statements::DefineUserStatement, Base, Query, Uuid, Value};
+use crate::sql::statements::{
+	DefineIndexStatement, DefineStatement, DefineUserStatement, ShowSince,
+};
+use crate::sql::{self, Base, Permission, Query, Uuid, Value};
 use crate::syn;
 use crate::vs::{conv, Oracle, Versionstamp};
 
@@ -78,6 +86,10 @@ pub struct Datastore {
 	transaction_timeout: Option<Duration>,
 	// Capabilities for this datastore
 	capabilities: Capabilities,
+	// The fallback permission for guests on tables without their own
+	guest_permissions: Option<Arc<Permission>>,
+	// The default timezone used when formatting datetimes
+	timezone: Option<Tz>,
 	pub(super) engine_options: EngineOptions,
 	// The versionstamp oracle for this datastore.
 	// Used only in some datastores, such as tikv.
@@ -344,6 +356,8 @@ impl Datastore {
 			transaction_timeout: None,
 			notification_channel: None,
 			capabilities: Capabilities::default(),
+			guest_permissions: None,
+			timezone: None,
 			engine_options: EngineOptions::default(),
 			versionstamp_oracle: Arc::new(Mutex::new(Oracle::systime_counter())),
 			clock,
@@ -404,6 +418,22 @@ impl Datastore {
 		self
 	}
 
+	/// Set the permission guests fall back to on tables defined without permissions
+	///
+	/// Tables with a `PERMISSIONS` clause of their own, and authenticated sessions, are unaffected.
+	pub fn with_guest_permissions(mut self, perm: Option<Permission>) -> Self {
+		self.guest_permissions = perm.map(Arc::new);
+		self
+	}
+
+	/// Set the default timezone used when formatting datetimes
+	///
+	/// Datetimes are always stored in UTC, and are formatted in UTC when no timezone is set.
+	pub fn with_timezone(mut self, tz: Option<Tz>) -> Self {
+		self.timezone = tz;
+		self
+	}
+
 	#[cfg(any(
 		feature = "kv-mem",
 		feature = "kv-surrealkv",
@@ -416,6 +446,81 @@ impl Datastore {
 		self
 	}
 
+	/// Set the isolation level of the transactions on this Datastore
+	///
+	/// Fails with [`Error::Unsupported`] when the storage engine cannot provide the level.
+	#[allow(unreachable_code)]
+	pub async fn with_isolation(mut self, level: IsolationLevel) -> Result<Self, Error> {
+		#![allow(unused_variables)]
+		let supported = match &mut self.inner {
+			// Write transactions take an exclusive lock, so they always run one after another
+			#[cfg(feature = "kv-mem")]
+			Inner::Mem(_) => level == IsolationLevel::Serializable,
+			#[cfg(feature = "kv-rocksdb")]
+			Inner::RocksDB(_) => level == IsolationLevel::Snapshot,
+			#[cfg(feature = "kv-indxdb")]
+			Inner::IndxDB(_) => level == IsolationLevel::Serializable,
+			#[cfg(feature = "kv-tikv")]
+			Inner::TiKV(_) => level == IsolationLevel::Snapshot,
+			#[cfg(feature = "kv-fdb")]
+			Inner::FoundationDB(_) => level == IsolationLevel::Serializable,
+			#[cfg(feature = "kv-surrealkv")]
+			Inner::SurrealKV(v) => {
+				v.set_isolation(level).await?;
+				true
+			}
+			#[allow(unreachable_patterns)]
+			_ => unreachable!(),
+		};
+		match supported {
+			true => Ok(self),
+			false => Err(Error::Unsupported {
+				engine: self.to_string(),
+				feature: level.to_string(),
+			}),
+		}
+	}
+
+	/// Sync commits to disk at most once per interval instead of leaving it to the engine
+	///
+	/// A zero interval syncs every commit. Otherwise, commits made since the last sync can be
+	/// lost if the machine crashes. Fails with [`Error::Unsupported`] for distributed engines,
+	/// which manage their own durability.
+	#[allow(unreachable_code)]
+	pub fn with_flush_interval(mut self, interval: Duration) -> Result<Self, Error> {
+		#![allow(unused_variables)]
+		let supported = match &mut self.inner {
+			// Nothing is written to disk, so there is nothing to sync
+			#[cfg(feature = "kv-mem")]
+			Inner::Mem(_) => true,
+			#[cfg(feature = "kv-rocksdb")]
+			Inner::RocksDB(v) => {
+				v.set_flush_interval(interval);
+				true
+			}
+			#[cfg(feature = "kv-indxdb")]
+			Inner::IndxDB(_) => false,
+			#[cfg(feature = "kv-tikv")]
+			Inner::TiKV(_) => false,
+			#[cfg(feature = "kv-fdb")]
+			Inner::FoundationDB(_) => false,
+			#[cfg(feature = "kv-surrealkv")]
+			Inner::SurrealKV(v) => {
+				v.set_flush_interval(interval);
+				true
+			}
+			#[allow(unreachable_patterns)]
+			_ => unreachable!(),
+		};
+		match supported {
+			true => Ok(self),
+			false => Err(Error::Unsupported {
+				engine: self.to_string(),
+				feature: "a flush interval".to_string(),
+			}),
+		}
+	}
+
 	/// Set the engine options for the datastore
 	pub fn with_engine_options(mut self, engine_options: EngineOptions) -> Self {
 		self.engine_options = engine_options;
@@ -1128,6 +1233,7 @@ impl Datastore {
 		let mut ctx = Context::from_ds(
 			self.query_timeout,
 			self.capabilities.clone(),
+			self.timezone,
 			self.index_stores.clone(),
 			#[cfg(any(
 				feature = "kv-mem",
@@ -1138,6 +1244,8 @@ impl Datastore {
 			))]
 			self.temporary_directory.clone(),
 		)?;
+		// Set the guest permissions
+		ctx.add_guest_permissions(self.guest_permissions.clone());
 		// Setup the notification channel
 		if let Some(channel) = &self.notification_channel {
 			ctx.add_notifications(Some(&channel.0));
@@ -1158,6 +1266,217 @@ impl Datastore {
 		}
 	}
 
+	/// Defines an index, without missing the records written to its table while it is built
+	///
+	/// Transactions which started before the index was defined can't see it, so they don't
+	/// update it. The records they write to the table while the index is being built are
+	/// tracked, and brought up to date in the index once it has been built, until no more
+	/// such writes are left.
+	#[instrument(level = "debug", skip_all)]
+	pub async fn build_index(
+		&self,
+		stm: DefineIndexStatement,
+		sess: &Session,
+	) -> Result<(), Error> {
+		let ns = sess.ns.as_deref().ok_or(Error::NsEmpty)?;
+		let db = sess.db.as_deref().ok_or(Error::DbEmpty)?;
+		// Track the writes before the index is defined, so none of them are missed
+		let writes = self.index_stores.builds().track(ns, db, &stm);
+		let ix = stm.clone();
+		for response in self.process(DefineStatement::Index(stm).into(), sess, None).await? {
+			response.result?;
+		}
+		loop {
+			let writes = writes.take();
+			if writes.is_empty() {
+				return Ok(());
+			}
+			self.catch_up_index(&ix, writes, sess).await?;
+		}
+	}
+
+	/// Brings an index up to date with the records written to its table while it was built
+	async fn catch_up_index(
+		&self,
+		ix: &DefineIndexStatement,
+		writes: Writes,
+		sess: &Session,
+	) -> Result<(), Error> {
+		let mut stack = TreeStack::new();
+		// Create a new query options
+		let opt = Options::default()
+			.with_id(self.id.0)
+			.with_ns(sess.ns())
+			.with_db(sess.db())
+			.with_auth(sess.au.clone())
+			.with_strict(self.strict)
+			.with_auth_enabled(self.auth_enabled);
+		// Allowed to run?
+		opt.is_allowed(Action::Edit, ResourceKind::Index, &Base::Db)?;
+		// Create a default context
+		let ctx = Context::from_ds(
+			self.query_timeout,
+			self.capabilities.clone(),
+			self.timezone,
+			self.index_stores.clone(),
+			#[cfg(any(
+				feature = "kv-mem",
+				feature = "kv-surrealkv",
+				feature = "kv-rocksdb",
+				feature = "kv-fdb",
+				feature = "kv-tikv",
+			))]
+			self.temporary_directory.clone(),
+		)?;
+		// Start an execution context
+		let ctx = sess.context(ctx);
+		// Start a new transaction
+		let txn = self.transaction(Write, Optimistic).await?.enclose();
+		let ctx = ctx.set_transaction(txn.clone());
+		// Update the index
+		let res =
+			stack.enter(|stk| doc::catch_up_index(stk, &ctx, &opt, ix, writes)).finish().await;
+		// Store any data
+		match res {
+			Ok(()) => txn.lock().await.commit().await?,
+			Err(_) => txn.lock().await.cancel().await?,
+		};
+		res
+	}
+
+	/// Reads the changes made to a table from its change feed
+	///
+	/// Returns [`Error::NoChangeFeed`] when neither the table nor its database has a change feed.
+	#[instrument(level = "debug", skip(self, sess))]
+	pub async fn show_changes(
+		&self,
+		sess: &Session,
+		tb: &str,
+		since: ShowSince,
+		limit: Option<u32>,
+	) -> Result<Value, Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// Check the session can view the tables of the database
+		self.check(sess, Action::View, ResourceKind::Table.on_db(&ns, &db))?;
+		// Create a new readonly transaction
+		let mut txn = self.transaction(Read, Optimistic).await?;
+		// Ensure the table has a change feed before reading it
+		let res = match txn.check_changefeed(&ns, &db, tb).await {
+			Ok(()) => cf::read(&mut txn, &ns, &db, Some(tb), since, limit).await,
+			Err(e) => Err(e),
+		};
+		txn.cancel().await?;
+		// Return the changes
+		Ok(res?.into_iter().map(cf::ChangeSet::into_value).collect::<Vec<_>>().into())
+	}
+
+	/// Claims the advisory lock on a record for `ttl`, unless a lock which has not expired is held on it
+	///
+	/// Returns the token of the lock held on the record, which is `claim` if it was claimed.
+	#[instrument(level = "debug", skip_all)]
+	pub async fn lock_record(
+		&self,
+		sess: &Session,
+		rid: &sql::Thing,
+		ttl: Duration,
+		claim: Uuid,
+	) -> Result<Uuid, Error> {
+		let (ns, db) = self.check_record_lock(sess)?;
+		let key = crate::key::table::lk::new(ns, db, &rid.tb, &rid.id);
+		let now = Utc::now();
+		let mut txn = self.transaction(Write, Optimistic).await?;
+		// Check for a lock which is still held
+		if let Some(lock) = txn.get(key.clone()).await? {
+			let lock = RecordLock::from(lock);
+			if lock.expires.0 > now {
+				txn.cancel().await?;
+				return Ok(lock.token);
+			}
+		}
+		let expires = chrono::Duration::from_std(ttl)
+			.ok()
+			.and_then(|ttl| now.checked_add_signed(ttl))
+			.unwrap_or(DateTime::<Utc>::MAX_UTC);
+		let lock = RecordLock {
+			token: claim,
+			expires: expires.into(),
+		};
+		match txn.set(key, lock).await {
+			Ok(()) => txn.commit().await?,
+			Err(e) => {
+				txn.cancel().await?;
+				return Err(e);
+			}
+		}
+		Ok(claim)
+	}
+
+	/// Releases the advisory lock on a record if it is held with `claim` or has expired
+	///
+	/// Returns the token of the lock which is still held on the record by someone else, if any.
+	#[instrument(level = "debug", skip_all)]
+	pub async fn unlock_record(
+		&self,
+		sess: &Session,
+		rid: &sql::Thing,
+		claim: Uuid,
+	) -> Result<Option<Uuid>, Error> {
+		let (ns, db) = self.check_record_lock(sess)?;
+		let key = crate::key::table::lk::new(ns, db, &rid.tb, &rid.id);
+		let mut txn = self.transaction(Write, Optimistic).await?;
+		let held = match txn.get(key.clone()).await? {
+			Some(lock) => RecordLock::from(lock),
+			None => {
+				txn.cancel().await?;
+				return Ok(None);
+			}
+		};
+		// A lock which has not expired can only be released by its holder
+		if held.expires.0 > Utc::now() && held.token != claim {
+			txn.cancel().await?;
+			return Ok(Some(held.token));
+		}
+		match txn.del(key).await {
+			Ok(()) => txn.commit().await?,
+			Err(e) => {
+				txn.cancel().await?;
+				return Err(e);
+			}
+		}
+		Ok(None)
+	}
+
+	/// Checks that a session can take advisory locks on the records of its database
+	fn check_record_lock<'a>(&self, sess: &'a Session) -> Result<(&'a str, &'a str), Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		let ns = sess.ns.as_deref().ok_or(Error::NsEmpty)?;
+		let db = sess.db.as_deref().ok_or(Error::DbEmpty)?;
+		// Anyone who can access the database can lock its records, including record users
+		let allowed = match sess.au.level() {
+			Level::No => !self.auth_enabled || self.capabilities.allows_guest_access(),
+			Level::Root => true,
+			Level::Namespace(n) => n == ns,
+			Level::Database(n, d) | Level::Record(n, d, _) => n == ns && d == db,
+		};
+		if !allowed {
+			return Err(IamError::NotAllowed {
+				actor: sess.au.id().to_string(),
+				action: "lock".to_string(),
+				resource: "record".to_string(),
+			}
+			.into());
+		}
+		Ok((ns, db))
+	}
+
 	/// Ensure a SQL [`Value`] is fully computed
 	///
 	/// ```rust,no_run
@@ -1213,6 +1532,8 @@ impl Datastore {
 		let mut ctx = Context::default();
 		// Set context capabilities
 		ctx.add_capabilities(self.capabilities.clone());
+		// Set the guest permissions
+		ctx.add_guest_permissions(self.guest_permissions.clone());
 		// Set the global query timeout
 		if let Some(timeout) = self.query_timeout {
 			ctx.add_timeout(timeout)?;
@@ -1288,6 +1609,8 @@ impl Datastore {
 		let mut ctx = Context::default();
 		// Set context capabilities
 		ctx.add_capabilities(self.capabilities.clone());
+		// Set the guest permissions
+		ctx.add_guest_permissions(self.guest_permissions.clone());
 		// Set the global query timeout
 		if let Some(timeout) = self.query_timeout {
 			ctx.add_timeout(timeout)?;
@@ -1372,6 +1695,30 @@ impl Datastore {
 		})
 	}
 
+	/// Performs a database export of the definitions only, as SQL
+	#[instrument(level = "debug", skip(self, sess, chn))]
+	pub async fn export_schema(
+		&self,
+		sess: &Session,
+		chn: Sender<Vec<u8>>,
+	) -> Result<impl Future<Output = Result<(), Error>>, Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// Create a new readonly transaction
+		let mut txn = self.transaction(Read, Optimistic).await?;
+		// Return an async export job
+		Ok(async move {
+			// Process the export
+			txn.export_schema(&ns, &db, chn).await?;
+			// Everything ok
+			Ok(())
+		})
+	}
+
 	/// Checks the required permissions level for this session
 	#[instrument(level = "debug", skip(self, sess))]
 	pub fn check(&self, sess: &Session, action: Action, resource: Resource) -> Result<(), Error> {
@@ -114,7 +114,7 @@ impl Datastore {
 					_fdbnet,
 				})
 			}
-			Err(e) => Err(Error::Ds(e.to_string())),
+			Err(e) => Err(Error::DsUnavailable(e.to_string())),
 		}
 	}
 	/// Start a new transaction
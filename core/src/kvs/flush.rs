@@ -0,0 +1,38 @@
+#![cfg(any(feature = "kv-rocksdb", feature = "kv-surrealkv"))]
+
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Decides which commits should be synced to disk when writes are flushed periodically
+#[non_exhaustive]
+pub(crate) struct FlushSchedule {
+	/// How long commits may go without being synced to disk
+	interval: Duration,
+	/// When a commit was last synced to disk
+	last: Mutex<Instant>,
+}
+
+impl FlushSchedule {
+	pub(crate) fn new(interval: Duration) -> Self {
+		Self {
+			interval,
+			last: Mutex::new(Instant::now()),
+		}
+	}
+	/// Checks whether the next commit should be synced to disk.
+	/// Syncing a commit also syncs every unsynced commit before it.
+	pub(crate) fn due(&self) -> bool {
+		if self.interval.is_zero() {
+			return true;
+		}
+		let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+		match last.elapsed() >= self.interval {
+			true => {
+				*last = Instant::now();
+				true
+			}
+			false => false,
+		}
+	}
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// The key part of a key-value pair. An alias for [`Vec<u8>`].
 pub type Key = Vec<u8>;
 
@@ -13,6 +15,26 @@ pub(crate) enum Check {
 	Panic,
 }
 
+/// The isolation level of the transactions on a datastore
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum IsolationLevel {
+	/// Transactions read from a consistent snapshot and only conflict on concurrent writes
+	#[default]
+	Snapshot,
+	/// Transactions also conflict when a value they read is changed concurrently
+	Serializable,
+}
+
+impl fmt::Display for IsolationLevel {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Snapshot => f.write_str("snapshot isolation"),
+			Self::Serializable => f.write_str("serializable isolation"),
+		}
+	}
+}
+
 /// This trait appends an element to a collection, and allows chaining
 pub(super) trait Add<T> {
 	fn add(self, v: T) -> Self;
@@ -15,6 +15,7 @@ mod cache;
 mod clock;
 mod ds;
 mod fdb;
+mod flush;
 mod indxdb;
 mod kv;
 mod mem;
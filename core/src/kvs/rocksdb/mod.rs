@@ -4,6 +4,7 @@ mod cnf;
 
 use crate::err::Error;
 use crate::key::error::KeyCategory;
+use crate::kvs::flush::FlushSchedule;
 use crate::kvs::Check;
 use crate::kvs::Key;
 use crate::kvs::Val;
@@ -16,11 +17,13 @@ use rocksdb::{
 use std::ops::Range;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct Datastore {
 	db: Pin<Arc<OptimisticTransactionDB>>,
+	flush: Option<Arc<FlushSchedule>>,
 }
 
 #[non_exhaustive]
@@ -114,10 +117,17 @@ impl Datastore {
 			DBCompressionType::Lz4hc,
 		]);
 		// Create the datastore
+		let db = OptimisticTransactionDB::open(&opts, path)
+			.map_err(|e| Error::DsUnavailable(e.to_string()))?;
 		Ok(Datastore {
-			db: Arc::pin(OptimisticTransactionDB::open(&opts, path)?),
+			db: Arc::pin(db),
+			flush: None,
 		})
 	}
+	/// Sync commits to disk at most once per interval, or on every commit if it is zero
+	pub(crate) fn set_flush_interval(&mut self, interval: Duration) {
+		self.flush = Some(Arc::new(FlushSchedule::new(interval)));
+	}
 	/// Start a new transaction
 	pub(crate) async fn transaction(&self, write: bool, _: bool) -> Result<Transaction, Error> {
 		// Set the transaction options
@@ -125,7 +135,7 @@ impl Datastore {
 		to.set_snapshot(true);
 		// Set the write options
 		let mut wo = WriteOptions::default();
-		wo.set_sync(false);
+		wo.set_sync(write && self.flush.as_ref().is_some_and(|flush| flush.due()));
 		// Create a new transaction
 		let inner = self.db.transaction_opt(&wo, &to);
 		// The database reference must always outlive
@@ -2,12 +2,17 @@
 
 use crate::err::Error;
 use crate::key::error::KeyCategory;
+use crate::kvs::flush::FlushSchedule;
 use crate::kvs::Check;
+use crate::kvs::IsolationLevel;
 use crate::kvs::Key;
 use crate::kvs::Val;
 use crate::vs::{try_to_u64_be, u64_to_versionstamp, Versionstamp};
 
 use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+use surrealkv::Durability;
 use surrealkv::Options;
 use surrealkv::Store;
 use surrealkv::Transaction as Tx;
@@ -15,6 +20,8 @@ use surrealkv::Transaction as Tx;
 #[non_exhaustive]
 pub struct Datastore {
 	db: Store,
+	opts: Options,
+	flush: Option<Arc<FlushSchedule>>,
 }
 
 #[non_exhaustive]
@@ -27,6 +34,8 @@ pub struct Transaction {
 	check: Check,
 	/// The underlying datastore transaction
 	inner: Tx,
+	/// When commits are synced to disk, if not left to the engine
+	flush: Option<Arc<FlushSchedule>>,
 }
 
 impl Drop for Transaction {
@@ -65,13 +74,38 @@ impl Datastore {
 		let mut opts = Options::new();
 		opts.dir = path.to_string().into();
 
-		match Store::new(opts) {
+		match Store::new(opts.clone()) {
 			Ok(db) => Ok(Datastore {
 				db,
+				opts,
+				flush: None,
 			}),
-			Err(e) => Err(Error::Ds(e.to_string())),
+			Err(e) => Err(Error::DsUnavailable(e.to_string())),
 		}
 	}
+	/// Reopen the database with a different transaction isolation level
+	pub(crate) async fn set_isolation(&mut self, level: IsolationLevel) -> Result<(), Error> {
+		let isolation_level = match level {
+			IsolationLevel::Snapshot => surrealkv::IsolationLevel::SnapshotIsolation,
+			IsolationLevel::Serializable => {
+				surrealkv::IsolationLevel::SerializableSnapshotIsolation
+			}
+		};
+		if self.opts.isolation_level == isolation_level {
+			return Ok(());
+		}
+		// The isolation level is fixed when the store is opened
+		self.db.close().await.map_err(|e| Error::Ds(e.to_string()))?;
+		let mut opts = self.opts.clone();
+		opts.isolation_level = isolation_level;
+		self.db = Store::new(opts.clone()).map_err(|e| Error::Ds(e.to_string()))?;
+		self.opts = opts;
+		Ok(())
+	}
+	/// Sync commits to disk at most once per interval, or on every commit if it is zero
+	pub(crate) fn set_flush_interval(&mut self, interval: Duration) {
+		self.flush = Some(Arc::new(FlushSchedule::new(interval)));
+	}
 	/// Start a new transaction
 	pub(crate) async fn transaction(&self, write: bool, _: bool) -> Result<Transaction, Error> {
 		// Specify the check level
@@ -86,6 +120,7 @@ impl Datastore {
 				check,
 				write,
 				inner,
+				flush: self.flush.clone(),
 			}),
 			Err(e) => Err(Error::Tx(e.to_string())),
 		}
@@ -131,6 +166,14 @@ impl Transaction {
 		// Mark the transaction as done.
 		self.done = true;
 
+		// Sync the commit to disk if it is due.
+		if let Some(flush) = &self.flush {
+			self.inner.set_durability(match flush.due() {
+				true => Durability::Immediate,
+				false => Durability::Eventual,
+			});
+		}
+
 		// Commit the transaction.
 		self.inner.commit().await.map_err(Into::into)
 	}
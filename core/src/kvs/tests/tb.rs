@@ -73,6 +73,7 @@ async fn table_definitions_can_be_deleted() {
 		comment: None,
 		if_not_exists: false,
 		kind: TableType::Any,
+		default_permissions: false,
 	};
 	tx.set(&key, &value).await.unwrap();
 
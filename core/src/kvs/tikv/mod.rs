@@ -65,7 +65,7 @@ impl Datastore {
 			Ok(db) => Ok(Datastore {
 				db,
 			}),
-			Err(e) => Err(Error::Ds(e.to_string())),
+			Err(e) => Err(Error::DsUnavailable(e.to_string())),
 		}
 	}
 	/// Start a new transaction
@@ -2048,6 +2048,7 @@ impl Transaction {
 					let val = DefineTableStatement {
 						name: tb.to_owned().into(),
 						permissions: Permissions::none(),
+						default_permissions: true,
 						..Default::default()
 					};
 					self.put(key.key_category(), key, &val).await?;
@@ -2131,6 +2132,30 @@ impl Transaction {
 		})
 	}
 
+	/// Ensure that a change feed is defined on a specific table or its database.
+	pub async fn check_changefeed(&mut self, ns: &str, db: &str, tb: &str) -> Result<(), Error> {
+		let dbc = match self.get_and_cache_db(ns, db).await {
+			Ok(v) => v.changefeed.is_some(),
+			Err(Error::DbNotFound {
+				..
+			}) => false,
+			Err(e) => return Err(e),
+		};
+		let tbc = match self.get_and_cache_tb(ns, db, tb).await {
+			Ok(v) => v.changefeed.is_some(),
+			Err(Error::TbNotFound {
+				..
+			}) => false,
+			Err(e) => return Err(e),
+		};
+		if !dbc && !tbc {
+			return Err(Error::NoChangeFeed {
+				value: tb.to_owned(),
+			});
+		}
+		Ok(())
+	}
+
 	/// Retrieve a specific function definition.
 	pub async fn get_and_cache_db_function(
 		&mut self,
@@ -2303,6 +2328,7 @@ impl Transaction {
 					let val = DefineTableStatement {
 						name: tb.to_owned().into(),
 						permissions: Permissions::none(),
+						default_permissions: true,
 						..Default::default()
 					};
 					self.put(key.key_category(), key, &val).await?;
@@ -2344,6 +2370,26 @@ impl Transaction {
 
 	/// Writes the full database contents as binary SQL.
 	pub async fn export(&mut self, ns: &str, db: &str, chn: Sender<Vec<u8>>) -> Result<(), Error> {
+		self.export_sql(ns, db, chn, true).await
+	}
+
+	/// Writes the definitions of a database as SQL, without any of its records
+	pub async fn export_schema(
+		&mut self,
+		ns: &str,
+		db: &str,
+		chn: Sender<Vec<u8>>,
+	) -> Result<(), Error> {
+		self.export_sql(ns, db, chn, false).await
+	}
+
+	async fn export_sql(
+		&mut self,
+		ns: &str,
+		db: &str,
+		chn: Sender<Vec<u8>>,
+		records: bool,
+	) -> Result<(), Error> {
 		// Output OPTIONS
 		{
 			chn.send(bytes!("-- ------------------------------")).await?;
@@ -2460,6 +2506,10 @@ impl Transaction {
 						chn.send(bytes!("")).await?;
 					}
 				}
+				// Skip the records when only exporting the schema
+				if !records {
+					return Ok(());
+				}
 				// Start transaction
 				chn.send(bytes!("-- ------------------------------")).await?;
 				chn.send(bytes!("-- TRANSACTION")).await?;
@@ -10,20 +10,26 @@ pub enum Method {
 	Authenticate,
 	Kill,
 	Live,
+	Lock,
+	Unlock,
 	Set,
 	Unset,
 	Select,
 	Insert,
 	Create,
 	Upsert,
+	UpsertMany,
 	Update,
 	Merge,
 	Patch,
 	Delete,
+	DeleteWhere,
 	Version,
 	Query,
 	Relate,
+	RelateMany,
 	Run,
+	ShowChanges,
 }
 
 impl Method {
@@ -41,20 +47,26 @@ impl Method {
 			"authenticate" => Self::Authenticate,
 			"kill" => Self::Kill,
 			"live" => Self::Live,
+			"lock" => Self::Lock,
+			"unlock" => Self::Unlock,
 			"let" | "set" => Self::Set,
 			"unset" => Self::Unset,
 			"select" => Self::Select,
 			"insert" => Self::Insert,
 			"create" => Self::Create,
 			"upsert" => Self::Upsert,
+			"upsert_many" => Self::UpsertMany,
 			"update" => Self::Update,
 			"merge" => Self::Merge,
 			"patch" => Self::Patch,
 			"delete" => Self::Delete,
+			"delete_where" => Self::DeleteWhere,
 			"version" => Self::Version,
 			"query" => Self::Query,
 			"relate" => Self::Relate,
+			"relate_many" => Self::RelateMany,
 			"run" => Self::Run,
+			"show_changes" => Self::ShowChanges,
 			_ => Self::Unknown,
 		}
 	}
@@ -73,20 +85,26 @@ impl Method {
 			Self::Authenticate => "authenticate",
 			Self::Kill => "kill",
 			Self::Live => "live",
+			Self::Lock => "lock",
+			Self::Unlock => "unlock",
 			Self::Set => "set",
 			Self::Unset => "unset",
 			Self::Select => "select",
 			Self::Insert => "insert",
 			Self::Create => "create",
 			Self::Upsert => "upsert",
+			Self::UpsertMany => "upsert_many",
 			Self::Update => "update",
 			Self::Merge => "merge",
 			Self::Patch => "patch",
 			Self::Delete => "delete",
+			Self::DeleteWhere => "delete_where",
 			Self::Version => "version",
 			Self::Query => "query",
 			Self::Relate => "relate",
+			Self::RelateMany => "relate_many",
 			Self::Run => "run",
+			Self::ShowChanges => "show_changes",
 		}
 	}
 }
@@ -108,10 +126,15 @@ impl Method {
 				| Method::Info | Method::Select
 				| Method::Insert | Method::Create
 				| Method::Update | Method::Upsert
+				| Method::UpsertMany
+				| Method::Lock | Method::Unlock
 				| Method::Merge | Method::Patch
-				| Method::Delete | Method::Version
+				| Method::Delete | Method::DeleteWhere
+				| Method::Version
 				| Method::Query | Method::Relate
-				| Method::Run | Method::Unknown
+				| Method::RelateMany
+				| Method::Run | Method::ShowChanges
+				| Method::Unknown
 		)
 	}
 }
@@ -6,7 +6,10 @@ use crate::{
 	dbs::{QueryType, Response, Session},
 	kvs::Datastore,
 	rpc::args::Take,
-	sql::{Array, Function, Model, Statement, Strand, Value},
+	sql::{
+		statements::{DeleteStatement, ShowSince},
+		Array, Cond, Function, Model, Output, Statement, Strand, Value, Values,
+	},
 };
 
 use super::{method::Method, response::Data, rpc_error::RpcError};
@@ -54,14 +57,28 @@ pub trait RpcContext {
 			Method::Insert => self.insert(params).await.map(Into::into).map_err(Into::into),
 			Method::Create => self.create(params).await.map(Into::into).map_err(Into::into),
 			Method::Upsert => self.upsert(params).await.map(Into::into).map_err(Into::into),
+			Method::UpsertMany => {
+				self.upsert_many(params).await.map(Into::into).map_err(Into::into)
+			}
+			Method::Lock => self.lock(params).await.map(Into::into).map_err(Into::into),
+			Method::Unlock => self.unlock(params).await.map(Into::into).map_err(Into::into),
 			Method::Update => self.update(params).await.map(Into::into).map_err(Into::into),
 			Method::Merge => self.merge(params).await.map(Into::into).map_err(Into::into),
 			Method::Patch => self.patch(params).await.map(Into::into).map_err(Into::into),
 			Method::Delete => self.delete(params).await.map(Into::into).map_err(Into::into),
+			Method::DeleteWhere => {
+				self.delete_where(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Version => self.version(params).await.map(Into::into).map_err(Into::into),
 			Method::Query => self.query(params).await.map(Into::into).map_err(Into::into),
 			Method::Relate => self.relate(params).await.map(Into::into).map_err(Into::into),
+			Method::RelateMany => {
+				self.relate_many(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Run => self.run(params).await.map(Into::into).map_err(Into::into),
+			Method::ShowChanges => {
+				self.show_changes(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Unknown => Err(RpcError::MethodNotFound),
 		}
 	}
@@ -74,14 +91,28 @@ pub trait RpcContext {
 			Method::Insert => self.insert(params).await.map(Into::into).map_err(Into::into),
 			Method::Create => self.create(params).await.map(Into::into).map_err(Into::into),
 			Method::Upsert => self.upsert(params).await.map(Into::into).map_err(Into::into),
+			Method::UpsertMany => {
+				self.upsert_many(params).await.map(Into::into).map_err(Into::into)
+			}
+			Method::Lock => self.lock(params).await.map(Into::into).map_err(Into::into),
+			Method::Unlock => self.unlock(params).await.map(Into::into).map_err(Into::into),
 			Method::Update => self.update(params).await.map(Into::into).map_err(Into::into),
 			Method::Merge => self.merge(params).await.map(Into::into).map_err(Into::into),
 			Method::Patch => self.patch(params).await.map(Into::into).map_err(Into::into),
 			Method::Delete => self.delete(params).await.map(Into::into).map_err(Into::into),
+			Method::DeleteWhere => {
+				self.delete_where(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Version => self.version(params).await.map(Into::into).map_err(Into::into),
 			Method::Query => self.query(params).await.map(Into::into).map_err(Into::into),
 			Method::Relate => self.relate(params).await.map(Into::into).map_err(Into::into),
+			Method::RelateMany => {
+				self.relate_many(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Run => self.run(params).await.map(Into::into).map_err(Into::into),
+			Method::ShowChanges => {
+				self.show_changes(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Unknown => Err(RpcError::MethodNotFound),
 			_ => Err(RpcError::MethodNotFound),
 		}
@@ -355,6 +386,71 @@ pub trait RpcContext {
 		Ok(res)
 	}
 
+	async fn lock(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok((Value::Thing(record), Value::Duration(ttl), Value::Uuid(claim))) =
+			params.needs_three()
+		else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Claim the lock on the record
+		let token = self.kvs().lock_record(self.session(), &record, ttl.0, claim).await?;
+		// Return the token of the lock held on the record
+		Ok(Value::Uuid(token))
+	}
+
+	async fn unlock(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok((Value::Thing(record), Value::Uuid(claim))) = params.needs_two() else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Release the lock on the record
+		let held = self.kvs().unlock_record(self.session(), &record, claim).await?;
+		// Return the token of the lock still held on the record
+		Ok(held.map(Value::Uuid).unwrap_or_default())
+	}
+
+	async fn upsert_many(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok(Value::Array(records)) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Upsert every record within a single transaction, returning the record before each
+		// upsert to tell whether it created the record, and reading the record after it
+		let mut sql = String::from("BEGIN;");
+		let mut var = self.vars().clone();
+		for (i, record) in records.into_iter().enumerate() {
+			let Value::Array(record) = record else {
+				return Err(RpcError::InvalidParams);
+			};
+			let Ok((id, data)) = record.needs_two() else {
+				return Err(RpcError::InvalidParams);
+			};
+			if !id.is_thing() {
+				return Err(RpcError::InvalidParams);
+			}
+			match data.is_none_or_null() {
+				true => sql.push_str(&format!("UPSERT ONLY $id{i} RETURN BEFORE;")),
+				false => {
+					sql.push_str(&format!("UPSERT ONLY $id{i} CONTENT $data{i} RETURN BEFORE;"))
+				}
+			}
+			sql.push_str(&format!("SELECT * FROM ONLY $id{i};"));
+			var.insert(format!("id{i}"), id);
+			var.insert(format!("data{i}"), data);
+		}
+		sql.push_str("COMMIT;");
+		// Execute the query on the database
+		let res = self.kvs().execute(&sql, self.session(), Some(var)).await?;
+		let mut res = transaction_results(res)?.into_iter();
+		let mut records = Vec::new();
+		while let (Some(before), Some(after)) = (res.next(), res.next()) {
+			records.push(Value::from(map! {
+				String::from("id") => after.rid(),
+				String::from("created") => Value::from(before.is_none()),
+				String::from("record") => after,
+			}));
+		}
+		Ok(Value::from(records))
+	}
+
 	// ------------------------------
 	// Methods for updating
 	// ------------------------------
@@ -488,6 +584,36 @@ pub trait RpcContext {
 		Ok(res)
 	}
 
+	async fn relate_many(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok(Value::Array(edges)) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Relate every edge within a single transaction
+		let mut sql = String::from("BEGIN;");
+		let mut var = self.vars().clone();
+		for (i, edge) in edges.into_iter().enumerate() {
+			let Value::Array(edge) = edge else {
+				return Err(RpcError::InvalidParams);
+			};
+			let Ok((from, kind, to, data)) = edge.needs_three_or_four() else {
+				return Err(RpcError::InvalidParams);
+			};
+			match data.is_none_or_null() {
+				true => sql.push_str(&format!("RELATE ONLY $from{i}->$kind{i}->$to{i};")),
+				false => sql
+					.push_str(&format!("RELATE ONLY $from{i}->$kind{i}->$to{i} CONTENT $data{i};")),
+			}
+			var.insert(format!("from{i}"), from);
+			var.insert(format!("kind{i}"), kind.could_be_table());
+			var.insert(format!("to{i}"), to);
+			var.insert(format!("data{i}"), data);
+		}
+		sql.push_str("COMMIT;");
+		// Execute the query on the database
+		let res = self.kvs().execute(&sql, self.session(), Some(var)).await?;
+		Ok(Value::from(transaction_results(res)?))
+	}
+
 	// ------------------------------
 	// Methods for deleting
 	// ------------------------------
@@ -516,6 +642,30 @@ pub trait RpcContext {
 		Ok(res)
 	}
 
+	async fn delete_where(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok((what, Value::Strand(cond))) = params.needs_two() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let mut stm = DeleteStatement::default();
+		stm.what = Values(vec![what.could_be_table()]);
+		// Parsing the condition on its own ensures it is a single expression
+		stm.cond = Some(Cond(crate::syn::value(&cond)?));
+		// Each deleted record is returned as a null, so only the count is sent back
+		stm.output = Some(Output::Null);
+		// Execute the query on the database
+		let mut res = self
+			.kvs()
+			.process(Statement::Delete(stm).into(), self.session(), Some(self.vars().clone()))
+			.await?;
+		// Count the deleted records
+		let res = match res.remove(0).result? {
+			Value::Array(deleted) => deleted.len(),
+			_ => 0,
+		};
+		// Return the result to the client
+		Ok(Value::from(res))
+	}
+
 	// ------------------------------
 	// Methods for getting info
 	// ------------------------------
@@ -593,6 +743,33 @@ pub trait RpcContext {
 		res.remove(0).result.map_err(Into::into)
 	}
 
+	// ------------------------------
+	// Methods for change feeds
+	// ------------------------------
+
+	async fn show_changes(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok((what, since, limit)) = params.needs_one_two_or_three() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let tb = match what {
+			Value::Table(v) => v,
+			Value::Strand(v) => v.0.into(),
+			_ => return Err(RpcError::InvalidParams),
+		};
+		let since = match since {
+			Value::None | Value::Null => ShowSince::default(),
+			v => v.try_into().map_err(|_| RpcError::InvalidParams)?,
+		};
+		let limit = match limit {
+			Value::None | Value::Null => None,
+			v => Some(v.try_into().map_err(|_| RpcError::InvalidParams)?),
+		};
+		// Read the changes, once the table is known to have a change feed
+		let res = self.kvs().show_changes(self.session(), &tb, since, limit).await?;
+		// Return the result to the client
+		Ok(res)
+	}
+
 	// ------------------------------
 	// Private methods
 	// ------------------------------
@@ -638,3 +815,30 @@ pub trait RpcContext {
 		}
 	}
 }
+
+/// Collects the results of a query run in a single transaction, or the error which rolled it back
+fn transaction_results(res: Vec<Response>) -> Result<Vec<Value>, RpcError> {
+	// Every statement of a failed transaction errors, so report the one which caused it
+	let not_executed = |e: &crate::err::Error| {
+		matches!(
+			e,
+			crate::err::Error::QueryNotExecuted | crate::err::Error::QueryNotExecutedDetail { .. }
+		)
+	};
+	let mut values = Vec::with_capacity(res.len());
+	let mut failure = None;
+	for res in res {
+		match res.result {
+			Ok(value) => values.push(value),
+			Err(e) => {
+				if failure.as_ref().map_or(true, |f| not_executed(f) && !not_executed(&e)) {
+					failure = Some(e);
+				}
+			}
+		}
+	}
+	match failure {
+		Some(e) => Err(e.into()),
+		None => Ok(values),
+	}
+}
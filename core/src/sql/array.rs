@@ -324,9 +324,10 @@ pub(crate) trait Difference<T> {
 }
 
 impl Difference<Array> for Array {
-	fn difference(self, mut other: Array) -> Array {
+	fn difference(self, other: Array) -> Array {
 		let mut out = Array::new();
-		for v in self.into_iter() {
+		let mut other = other.uniq();
+		for v in self.uniq().into_iter() {
 			if let Some(pos) = other.iter().position(|w| v == *w) {
 				other.remove(pos);
 			} else {
@@ -372,7 +373,7 @@ impl Intersect<Self> for Array {
 				out.push(v);
 			}
 		}
-		out
+		out.uniq()
 	}
 }
 
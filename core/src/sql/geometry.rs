@@ -2,6 +2,7 @@
 
 use crate::sql::array::Array;
 use crate::sql::fmt::Fmt;
+use crate::sql::object::Object;
 use crate::sql::value::Value;
 use geo::algorithm::contains::Contains;
 use geo::algorithm::intersects::Intersects;
@@ -76,6 +77,118 @@ impl Geometry {
 			Self::Collection(_) => "GeometryCollection",
 		}
 	}
+	/// Try to read a GeoJSON object as a Geometry
+	///
+	/// Like geometry literals, the object must only have a `type` key and either a
+	/// `coordinates` or a `geometries` key, and the coordinates must match the type.
+	pub(crate) fn try_from_object(object: &Object) -> Option<Geometry> {
+		if object.len() != 2 {
+			return None;
+		}
+		let Some(Value::Strand(kind)) = object.get("type") else {
+			return None;
+		};
+		if kind.as_str() == "GeometryCollection" {
+			let Some(Value::Array(geometries)) = object.get("geometries") else {
+				return None;
+			};
+			return geometries
+				.iter()
+				.map(|geometry| match geometry {
+					Value::Geometry(geometry) => Some(geometry.clone()),
+					Value::Object(object) => Self::try_from_object(object),
+					_ => None,
+				})
+				.collect::<Option<Vec<_>>>()
+				.map(Geometry::Collection);
+		}
+		let coordinates = object.get("coordinates")?;
+		match kind.as_str() {
+			"Point" => Self::array_to_point(coordinates).map(Geometry::Point),
+			"LineString" => Self::array_to_line(coordinates).map(Geometry::Line),
+			"Polygon" => Self::array_to_polygon(coordinates).map(Geometry::Polygon),
+			"MultiPoint" => Self::array_to_multipoint(coordinates).map(Geometry::MultiPoint),
+			"MultiLineString" => Self::array_to_multiline(coordinates).map(Geometry::MultiLine),
+			"MultiPolygon" => Self::array_to_multipolygon(coordinates).map(Geometry::MultiPolygon),
+			_ => None,
+		}
+	}
+	/// Convert an array of polygon coordinates into a MultiPolygon
+	pub(crate) fn array_to_multipolygon(v: &Value) -> Option<MultiPolygon<f64>> {
+		let mut res = Vec::new();
+		let Value::Array(v) = v else {
+			return None;
+		};
+		for x in v.iter() {
+			res.push(Self::array_to_polygon(x)?);
+		}
+		Some(MultiPolygon::new(res))
+	}
+	/// Convert an array of line coordinates into a MultiLineString
+	pub(crate) fn array_to_multiline(v: &Value) -> Option<MultiLineString<f64>> {
+		let mut res = Vec::new();
+		let Value::Array(v) = v else {
+			return None;
+		};
+		for x in v.iter() {
+			res.push(Self::array_to_line(x)?);
+		}
+		Some(MultiLineString::new(res))
+	}
+	/// Convert an array of point coordinates into a MultiPoint
+	pub(crate) fn array_to_multipoint(v: &Value) -> Option<MultiPoint<f64>> {
+		let mut res = Vec::new();
+		let Value::Array(v) = v else {
+			return None;
+		};
+		for x in v.iter() {
+			res.push(Self::array_to_point(x)?);
+		}
+		Some(MultiPoint::new(res))
+	}
+	/// Convert an array of ring coordinates into a Polygon
+	pub(crate) fn array_to_polygon(v: &Value) -> Option<Polygon<f64>> {
+		let mut res = Vec::new();
+		let Value::Array(v) = v else {
+			return None;
+		};
+		if v.is_empty() {
+			return None;
+		}
+		let first = Self::array_to_line(&v[0])?;
+		for x in &v[1..] {
+			res.push(Self::array_to_line(x)?);
+		}
+		Some(Polygon::new(first, res))
+	}
+	/// Convert an array of point coordinates into a LineString
+	pub(crate) fn array_to_line(v: &Value) -> Option<LineString<f64>> {
+		let mut res = Vec::new();
+		let Value::Array(v) = v else {
+			return None;
+		};
+		for x in v.iter() {
+			res.push(Self::array_to_point(x)?);
+		}
+		Some(LineString::from(res))
+	}
+	/// Convert a pair of numbers into a Point
+	pub(crate) fn array_to_point(v: &Value) -> Option<Point<f64>> {
+		let Value::Array(v) = v else {
+			return None;
+		};
+		if v.len() != 2 {
+			return None;
+		}
+		// FIXME: This truncates decimals and large integers into a f64.
+		let Value::Number(ref a) = v.0[0] else {
+			return None;
+		};
+		let Value::Number(ref b) = v.0[1] else {
+			return None;
+		};
+		Some(Point::from((a.clone().try_into().ok()?, b.clone().try_into().ok()?)))
+	}
 	/// Get the raw coordinates of this Geometry as an Array
 	pub fn as_coordinates(&self) -> Value {
 		fn point(v: &Point) -> Value {
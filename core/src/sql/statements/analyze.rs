@@ -6,9 +6,13 @@ use crate::iam::{Action, ResourceKind};
 use crate::idx::ft::FtIndex;
 use crate::idx::trees::mtree::MTreeIndex;
 use crate::idx::IndexKeyBase;
+use crate::key;
+use crate::kvs::Transaction;
 use crate::kvs::TransactionType;
+use crate::sql::array::Array;
 use crate::sql::ident::Ident;
 use crate::sql::index::Index;
+use crate::sql::object::Object;
 use crate::sql::value::Value;
 use crate::sql::Base;
 use derive::Store;
@@ -65,9 +69,13 @@ impl AnalyzeStatement {
 						.await?;
 						mt.statistics(&mut tx).await?.into()
 					}
+					Index::Idx | Index::Uniq => {
+						let mut tx = ctx.tx_lock().await;
+						IdxStatistics::compute(&mut tx, opt.ns()?, opt.db()?, tb, idx).await?.into()
+					}
 					_ => {
 						return Err(Error::FeatureNotYetImplemented {
-							feature: "Statistics on unique and non-unique indexes.".to_string(),
+							feature: "Statistics on HNSW indexes.".to_string(),
 						})
 					}
 				};
@@ -78,6 +86,74 @@ impl AnalyzeStatement {
 	}
 }
 
+/// The cardinality of a unique or non-unique index
+///
+/// Every entry of the index is read, so the figures are exact for the transaction they are
+/// read in. An index which is still being built in the background only counts the records
+/// indexed so far.
+struct IdxStatistics {
+	distinct_keys: u64,
+	total_entries: u64,
+}
+
+impl IdxStatistics {
+	/// How many entries are read from the index at a time
+	const BATCH_SIZE: u32 = 1000;
+
+	async fn compute(
+		tx: &mut Transaction,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		ix: &str,
+	) -> Result<Self, Error> {
+		let mut stats = Self {
+			distinct_keys: 0,
+			total_entries: 0,
+		};
+		let mut beg = key::index::Index::prefix_beg(ns, db, tb, ix);
+		let end = key::index::Index::prefix_end(ns, db, tb, ix);
+		let mut last: Option<Array> = None;
+		loop {
+			let batch = tx.scan(beg.clone()..end.clone(), Self::BATCH_SIZE).await?;
+			let Some((key, _)) = batch.last() else {
+				break;
+			};
+			// Continue from just after the last key of this batch
+			beg.clone_from(key);
+			beg.push(0x00);
+			for (key, _) in &batch {
+				let key = key::index::Index::decode(key)?;
+				stats.total_entries += 1;
+				// Entries are ordered by their values, so equal values are next to each other
+				if last.as_ref() != Some(key.fd.as_ref()) {
+					stats.distinct_keys += 1;
+					last = Some(key.fd.into_owned());
+				}
+			}
+		}
+		Ok(stats)
+	}
+
+	/// The share of entries which have a distinct value, from `0` to `1`
+	fn selectivity(&self) -> f64 {
+		match self.total_entries {
+			0 => 0.0,
+			total => self.distinct_keys as f64 / total as f64,
+		}
+	}
+}
+
+impl From<IdxStatistics> for Value {
+	fn from(stats: IdxStatistics) -> Self {
+		let mut res = Object::default();
+		res.insert("selectivity".to_owned(), Value::from(stats.selectivity()));
+		res.insert("distinct_keys".to_owned(), Value::from(stats.distinct_keys));
+		res.insert("total_entries".to_owned(), Value::from(stats.total_entries));
+		Value::from(res)
+	}
+}
+
 impl Display for AnalyzeStatement {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		match self {
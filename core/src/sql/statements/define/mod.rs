@@ -118,4 +118,23 @@ mod tests {
 		let enc: Vec<u8> = stm.into();
 		assert_eq!(12, enc.len());
 	}
+
+	#[test]
+	fn check_define_table_explicit_permissions() {
+		let stm = DefineTableStatement {
+			name: Ident::from("test"),
+			default_permissions: true,
+			..Default::default()
+		};
+		// Tables without a clause of their own are exported without one
+		assert_eq!(stm.to_string(), "DEFINE TABLE test TYPE ANY SCHEMALESS");
+		let mut enc: Vec<u8> = stm.into();
+		assert_eq!(enc[0], 4);
+		// Revision 3 is the same without the trailing `default_permissions` flag
+		enc[0] = 3;
+		enc.pop();
+		// Tables stored before revision 4 never fall back to the guest permissions
+		let dec = DefineTableStatement::from(enc);
+		assert!(!dec.default_permissions);
+	}
 }
@@ -47,6 +47,7 @@ pub use self::r#use::UseStatement;
 pub use self::relate::RelateStatement;
 pub use self::select::SelectStatement;
 pub use self::set::SetStatement;
+pub use self::show::ShowSince;
 pub use self::show::ShowStatement;
 pub use self::sleep::SleepStatement;
 pub use self::throw::ThrowStatement;
@@ -19,6 +19,46 @@ pub enum ShowSince {
 	Versionstamp(u64),
 }
 
+impl Default for ShowSince {
+	fn default() -> Self {
+		ShowSince::Versionstamp(0)
+	}
+}
+
+impl From<u64> for ShowSince {
+	fn from(v: u64) -> Self {
+		ShowSince::Versionstamp(v)
+	}
+}
+
+impl From<Datetime> for ShowSince {
+	fn from(v: Datetime) -> Self {
+		ShowSince::Timestamp(v)
+	}
+}
+
+impl From<ShowSince> for Value {
+	fn from(v: ShowSince) -> Self {
+		match v {
+			ShowSince::Timestamp(v) => v.into(),
+			ShowSince::Versionstamp(v) => v.into(),
+		}
+	}
+}
+
+impl TryFrom<Value> for ShowSince {
+	type Error = Error;
+	fn try_from(value: Value) -> Result<Self, Self::Error> {
+		match value {
+			Value::Datetime(v) => Ok(ShowSince::Timestamp(v)),
+			Value::Number(v) if v.is_int() && v.is_zero_or_positive() => {
+				Ok(ShowSince::Versionstamp(v.as_int() as u64))
+			}
+			_ => Err(Error::TryFrom(value.to_string(), "ShowSince")),
+		}
+	}
+}
+
 impl ShowSince {
 	pub fn versionstamp(vs: &Versionstamp) -> ShowSince {
 		ShowSince::Versionstamp(conv::versionstamp_to_u64(vs))
@@ -35,7 +75,7 @@ impl ShowSince {
 // ShowStatement is used to show changes in a table or database via
 // the SHOW CHANGES statement.
 #[revisioned(revision = 1)]
-#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct ShowStatement {
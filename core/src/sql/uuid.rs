@@ -5,9 +5,13 @@ use std::fmt::{self, Display, Formatter};
 use std::ops::Deref;
 use std::str;
 use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
 
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Uuid";
 
+/// The timestamp and counter of the last generated V7 UUID
+static V7_STATE: Mutex<(u64, u16)> = Mutex::new((0, 0));
+
 #[revisioned(revision = 1)]
 #[derive(
 	Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize, Hash,
@@ -70,15 +74,37 @@ impl Deref for Uuid {
 impl Uuid {
 	/// Generate a new UUID
 	pub fn new() -> Self {
-		Self(uuid::Uuid::now_v7())
+		Self::new_v7()
 	}
 	/// Generate a new V4 UUID
 	pub fn new_v4() -> Self {
 		Self(uuid::Uuid::new_v4())
 	}
 	/// Generate a new V7 UUID
+	///
+	/// The 12 bits following the millisecond timestamp hold a counter, which is incremented for
+	/// UUIDs generated within the same millisecond, so UUIDs always sort in generation order.
 	pub fn new_v7() -> Self {
-		Self(uuid::Uuid::now_v7())
+		let (secs, nanos) = uuid::Timestamp::now(uuid::NoContext).to_unix();
+		let now = secs * 1000 + u64::from(nanos) / 1_000_000;
+		let mut bytes: [u8; 10] = rand::random();
+		let (millis, counter) = {
+			let mut state = V7_STATE.lock().unwrap_or_else(PoisonError::into_inner);
+			let (last, counter) = *state;
+			*state = if now > last {
+				// Start from a random counter, leaving room to increment it
+				(now, u16::from_be_bytes([bytes[0], bytes[1]]) & 0x07FF)
+			} else if counter < 0x0FFF {
+				// Within the same millisecond, or the clock went backwards
+				(last, counter + 1)
+			} else {
+				// The counter is exhausted, so move on to the next millisecond
+				(last + 1, 0)
+			};
+			*state
+		};
+		bytes[..2].copy_from_slice(&counter.to_be_bytes());
+		Self(uuid::Builder::from_unix_timestamp_millis(millis, &bytes).into_uuid())
 	}
 	/// Convert the Uuid to a raw String
 	pub fn to_raw(&self) -> String {
@@ -52,6 +52,7 @@ pub struct SerializeDefineTableStatement {
 	comment: Option<Strand>,
 	if_not_exists: bool,
 	kind: TableType,
+	default_permissions: bool,
 }
 
 impl serde::ser::SerializeStruct for SerializeDefineTableStatement {
@@ -93,6 +94,10 @@ impl serde::ser::SerializeStruct for SerializeDefineTableStatement {
 			"if_not_exists" => {
 				self.if_not_exists = value.serialize(ser::primitive::bool::Serializer.wrap())?
 			}
+			"default_permissions" => {
+				self.default_permissions =
+					value.serialize(ser::primitive::bool::Serializer.wrap())?
+			}
 			key => {
 				return Err(Error::custom(format!(
 					"unexpected field `DefineTableStatement::{key}`"
@@ -114,6 +119,7 @@ impl serde::ser::SerializeStruct for SerializeDefineTableStatement {
 			comment: self.comment,
 			kind: self.kind,
 			if_not_exists: self.if_not_exists,
+			default_permissions: self.default_permissions,
 		})
 	}
 }
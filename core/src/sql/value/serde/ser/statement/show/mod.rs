@@ -86,23 +86,6 @@ impl serde::ser::SerializeStruct for SerializeShowStatement {
 mod tests {
 	use super::*;
 
-	impl Default for ShowSince {
-		fn default() -> Self {
-			ShowSince::Timestamp(Default::default())
-		}
-	}
-
-	#[allow(clippy::derivable_impls)]
-	impl Default for ShowStatement {
-		fn default() -> Self {
-			ShowStatement {
-				table: None,
-				since: Default::default(),
-				limit: None,
-			}
-		}
-	}
-
 	#[test]
 	fn default() {
 		let stmt = ShowStatement::default();
@@ -120,6 +103,16 @@ mod tests {
 		assert_eq!(value, stmt);
 	}
 
+	#[test]
+	fn with_timestamp() {
+		let stmt = ShowStatement {
+			since: ShowSince::Timestamp(Default::default()),
+			..Default::default()
+		};
+		let value: ShowStatement = stmt.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(value, stmt);
+	}
+
 	#[test]
 	fn with_limit() {
 		let stmt = ShowStatement {
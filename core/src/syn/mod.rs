@@ -2,7 +2,7 @@
 
 use crate::{
 	err::Error,
-	sql::{Datetime, Duration, Idiom, Query, Range, Subquery, Thing, Value},
+	sql::{Datetime, Duration, Idiom, Kind, Query, Range, Subquery, Thing, Value},
 };
 
 pub mod common;
@@ -168,3 +168,15 @@ pub fn thing(input: &str) -> Result<Thing, Error> {
 		.map_err(|e| e.render_on(input))
 		.map_err(Error::InvalidQuery)
 }
+
+/// Parse a kind, such as `int` or `array<string>`.
+pub fn kind(input: &str) -> Result<Kind, Error> {
+	debug!("parsing kind, input = {input}");
+	let mut parser = Parser::new(input.as_bytes());
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_full_kind(stk))
+		.finish()
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
@@ -87,6 +87,7 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("array::any") => PathKind::Function,
 		UniCase::ascii("array::append") => PathKind::Function,
 		UniCase::ascii("array::at") => PathKind::Function,
+		UniCase::ascii("array::binary_search") => PathKind::Function,
 		UniCase::ascii("array::boolean_and") => PathKind::Function,
 		UniCase::ascii("array::boolean_not") => PathKind::Function,
 		UniCase::ascii("array::boolean_or") => PathKind::Function,
@@ -97,10 +98,13 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("array::concat") => PathKind::Function,
 		UniCase::ascii("array::difference") => PathKind::Function,
 		UniCase::ascii("array::distinct") => PathKind::Function,
+		UniCase::ascii("array::fill") => PathKind::Function,
 		UniCase::ascii("array::filter_index") => PathKind::Function,
 		UniCase::ascii("array::find_index") => PathKind::Function,
+		UniCase::ascii("array::find_last_index") => PathKind::Function,
 		UniCase::ascii("array::first") => PathKind::Function,
 		UniCase::ascii("array::flatten") => PathKind::Function,
+		UniCase::ascii("array::frequencies") => PathKind::Function,
 		UniCase::ascii("array::group") => PathKind::Function,
 		UniCase::ascii("array::insert") => PathKind::Function,
 		UniCase::ascii("array::intersect") => PathKind::Function,
@@ -117,19 +121,29 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("array::prepend") => PathKind::Function,
 		UniCase::ascii("array::push") => PathKind::Function,
 		UniCase::ascii("array::remove") => PathKind::Function,
+		UniCase::ascii("array::repeat") => PathKind::Function,
 		UniCase::ascii("array::reverse") => PathKind::Function,
+		UniCase::ascii("array::rotate") => PathKind::Function,
+		UniCase::ascii("array::sample") => PathKind::Function,
 		UniCase::ascii("array::shuffle") => PathKind::Function,
 		UniCase::ascii("array::slice") => PathKind::Function,
 		UniCase::ascii("array::sort") => PathKind::Function,
+		UniCase::ascii("array::splice") => PathKind::Function,
 		UniCase::ascii("array::transpose") => PathKind::Function,
 		UniCase::ascii("array::union") => PathKind::Function,
 		UniCase::ascii("array::sort::asc") => PathKind::Function,
 		UniCase::ascii("array::sort::desc") => PathKind::Function,
 		//
+		UniCase::ascii("object::deep_get") => PathKind::Function,
+		UniCase::ascii("object::deep_set") => PathKind::Function,
 		UniCase::ascii("object::entries") => PathKind::Function,
+		UniCase::ascii("object::flatten") => PathKind::Function,
 		UniCase::ascii("object::from_entries") => PathKind::Function,
 		UniCase::ascii("object::keys") => PathKind::Function,
 		UniCase::ascii("object::len") => PathKind::Function,
+		UniCase::ascii("object::omit") => PathKind::Function,
+		UniCase::ascii("object::pick") => PathKind::Function,
+		UniCase::ascii("object::unflatten") => PathKind::Function,
 		UniCase::ascii("object::values") => PathKind::Function,
 		UniCase::ascii("object::matches") => PathKind::Function,
 		//
@@ -137,11 +151,16 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		//
 		UniCase::ascii("count") => PathKind::Function,
 		//
+		UniCase::ascii("crypto::adler32") => PathKind::Function,
+		UniCase::ascii("crypto::crc32") => PathKind::Function,
 		UniCase::ascii("crypto::md5") => PathKind::Function,
 		UniCase::ascii("crypto::sha1") => PathKind::Function,
 		UniCase::ascii("crypto::sha256") => PathKind::Function,
 		UniCase::ascii("crypto::sha512") => PathKind::Function,
+		UniCase::ascii("crypto::jwt::decode") => PathKind::Function,
+		UniCase::ascii("crypto::jwt::encode") => PathKind::Function,
 		//
+		UniCase::ascii("duration::components") => PathKind::Function,
 		UniCase::ascii("duration::days") => PathKind::Function,
 		UniCase::ascii("duration::hours") => PathKind::Function,
 		UniCase::ascii("duration::micros") => PathKind::Function,
@@ -151,6 +170,7 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("duration::secs") => PathKind::Function,
 		UniCase::ascii("duration::weeks") => PathKind::Function,
 		UniCase::ascii("duration::years") => PathKind::Function,
+		UniCase::ascii("duration::from_components") => PathKind::Function,
 		UniCase::ascii("duration::from::days") => PathKind::Function,
 		UniCase::ascii("duration::from::hours") => PathKind::Function,
 		UniCase::ascii("duration::from::micros") => PathKind::Function,
@@ -162,6 +182,8 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		//
 		UniCase::ascii("encoding::base64::decode") => PathKind::Function,
 		UniCase::ascii("encoding::base64::encode") => PathKind::Function,
+		UniCase::ascii("encoding::json::parse") => PathKind::Function,
+		UniCase::ascii("encoding::json::stringify") => PathKind::Function,
 		//
 		UniCase::ascii("geo::area") => PathKind::Function,
 		UniCase::ascii("geo::bearing") => PathKind::Function,
@@ -169,6 +191,8 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("geo::distance") => PathKind::Function,
 		UniCase::ascii("geo::hash::decode") => PathKind::Function,
 		UniCase::ascii("geo::hash::encode") => PathKind::Function,
+		UniCase::ascii("geo::length") => PathKind::Function,
+		UniCase::ascii("geo::simplify") => PathKind::Function,
 		//
 		UniCase::ascii("math::abs") => PathKind::Function,
 		UniCase::ascii("math::acos") => PathKind::Function,
@@ -178,12 +202,19 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("math::bottom") => PathKind::Function,
 		UniCase::ascii("math::ceil") => PathKind::Function,
 		UniCase::ascii("math::clamp") => PathKind::Function,
+		UniCase::ascii("math::combinations") => PathKind::Function,
 		UniCase::ascii("math::cos") => PathKind::Function,
 		UniCase::ascii("math::cot") => PathKind::Function,
+		UniCase::ascii("math::cumsum") => PathKind::Function,
 		UniCase::ascii("math::deg2rad") => PathKind::Function,
+		UniCase::ascii("math::diff") => PathKind::Function,
+		UniCase::ascii("math::factorial") => PathKind::Function,
 		UniCase::ascii("math::fixed") => PathKind::Function,
 		UniCase::ascii("math::floor") => PathKind::Function,
+		UniCase::ascii("math::gcd") => PathKind::Function,
+		UniCase::ascii("math::histogram") => PathKind::Function,
 		UniCase::ascii("math::interquartile") => PathKind::Function,
+		UniCase::ascii("math::lcm") => PathKind::Function,
 		UniCase::ascii("math::lerp") => PathKind::Function,
 		UniCase::ascii("math::lerpangle") => PathKind::Function,
 		UniCase::ascii("math::ln") => PathKind::Function,
@@ -197,10 +228,13 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("math::min") => PathKind::Function,
 		UniCase::ascii("math::mode") => PathKind::Function,
 		UniCase::ascii("math::nearestrank") => PathKind::Function,
+		UniCase::ascii("math::normalize") => PathKind::Function,
 		UniCase::ascii("math::percentile") => PathKind::Function,
+		UniCase::ascii("math::permutations") => PathKind::Function,
 		UniCase::ascii("math::pow") => PathKind::Function,
 		UniCase::ascii("math::product") => PathKind::Function,
 		UniCase::ascii("math::round") => PathKind::Function,
+		UniCase::ascii("math::scale") => PathKind::Function,
 		UniCase::ascii("math::rad2deg") => PathKind::Function,
 		UniCase::ascii("math::sign") => PathKind::Function,
 		UniCase::ascii("math::sin") => PathKind::Function,
@@ -219,6 +253,8 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		//
 		UniCase::ascii("not") => PathKind::Function,
 		//
+		UniCase::ascii("parse::bytes") => PathKind::Function,
+		UniCase::ascii("parse::duration") => PathKind::Function,
 		UniCase::ascii("parse::email::host") => PathKind::Function,
 		UniCase::ascii("parse::email::user") => PathKind::Function,
 		UniCase::ascii("parse::url::domain") => PathKind::Function,
@@ -236,6 +272,7 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("rand::guid") => PathKind::Function,
 		UniCase::ascii("rand::int") => PathKind::Function,
 		UniCase::ascii("rand::string") => PathKind::Function,
+		UniCase::ascii("rand::string::pattern") => PathKind::Function,
 		UniCase::ascii("rand::time") => PathKind::Function,
 		UniCase::ascii("rand::ulid") => PathKind::Function,
 		UniCase::ascii("rand::uuid::v4") => PathKind::Function,
@@ -253,10 +290,20 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		//
 		UniCase::ascii("string::concat") => PathKind::Function,
 		UniCase::ascii("string::contains") => PathKind::Function,
+		UniCase::ascii("string::count_matches") => PathKind::Function,
+		UniCase::ascii("string::diff") => PathKind::Function,
 		UniCase::ascii("string::endsWith") => PathKind::Function,
+		UniCase::ascii("string::extract_emails") => PathKind::Function,
+		UniCase::ascii("string::extract_numbers") => PathKind::Function,
+		UniCase::ascii("string::highlight") => PathKind::Function,
 		UniCase::ascii("string::join") => PathKind::Function,
 		UniCase::ascii("string::len") => PathKind::Function,
 		UniCase::ascii("string::lowercase") => PathKind::Function,
+		UniCase::ascii("string::mask") => PathKind::Function,
+		UniCase::ascii("string::ngrams") => PathKind::Function,
+		UniCase::ascii("string::pad_end") => PathKind::Function,
+		UniCase::ascii("string::pad_start") => PathKind::Function,
+		UniCase::ascii("string::redact_json") => PathKind::Function,
 		UniCase::ascii("string::repeat") => PathKind::Function,
 		UniCase::ascii("string::replace") => PathKind::Function,
 		UniCase::ascii("string::reverse") => PathKind::Function,
@@ -264,9 +311,11 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("string::slug") => PathKind::Function,
 		UniCase::ascii("string::split") => PathKind::Function,
 		UniCase::ascii("string::startsWith") => PathKind::Function,
+		UniCase::ascii("string::to_case") => PathKind::Function,
 		UniCase::ascii("string::trim") => PathKind::Function,
 		UniCase::ascii("string::uppercase") => PathKind::Function,
 		UniCase::ascii("string::words") => PathKind::Function,
+		UniCase::ascii("string::wrap") => PathKind::Function,
 		UniCase::ascii("string::distance::hamming") => PathKind::Function,
 		UniCase::ascii("string::distance::levenshtein") => PathKind::Function,
 		UniCase::ascii("string::html::encode") => PathKind::Function,
@@ -302,8 +351,12 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("string::similarity::smithwaterman") => PathKind::Function,
 		UniCase::ascii("string::matches") => PathKind::Function,
 		//
+		UniCase::ascii("time::add") => PathKind::Function,
+		UniCase::ascii("time::age") => PathKind::Function,
+		UniCase::ascii("time::business_days_between") => PathKind::Function,
 		UniCase::ascii("time::ceil") => PathKind::Function,
 		UniCase::ascii("time::day") => PathKind::Function,
+		UniCase::ascii("time::day_of_year") => PathKind::Function,
 		UniCase::ascii("time::floor") => PathKind::Function,
 		UniCase::ascii("time::format") => PathKind::Function,
 		UniCase::ascii("time::group") => PathKind::Function,
@@ -316,14 +369,19 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("time::micros") => PathKind::Function,
 		UniCase::ascii("time::millis") => PathKind::Function,
 		UniCase::ascii("time::now") => PathKind::Function,
+		UniCase::ascii("time::quarter") => PathKind::Function,
 		UniCase::ascii("time::round") => PathKind::Function,
 		UniCase::ascii("time::second") => PathKind::Function,
+		UniCase::ascii("time::subtract") => PathKind::Function,
 		UniCase::ascii("time::timezone") => PathKind::Function,
 		UniCase::ascii("time::unix") => PathKind::Function,
 		UniCase::ascii("time::wday") => PathKind::Function,
 		UniCase::ascii("time::week") => PathKind::Function,
 		UniCase::ascii("time::yday") => PathKind::Function,
 		UniCase::ascii("time::year") => PathKind::Function,
+		UniCase::ascii("time::is::business_day") => PathKind::Function,
+		UniCase::ascii("time::is::leap_year") => PathKind::Function,
+		UniCase::ascii("time::is::weekend") => PathKind::Function,
 		UniCase::ascii("time::from::nanos") => PathKind::Function,
 		UniCase::ascii("time::from::micros") => PathKind::Function,
 		UniCase::ascii("time::from::millis") => PathKind::Function,
@@ -331,6 +389,7 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("time::from::unix") => PathKind::Function,
 		//
 		UniCase::ascii("type::bool") => PathKind::Function,
+		UniCase::ascii("type::coerce") => PathKind::Function,
 		UniCase::ascii("type::datetime") => PathKind::Function,
 		UniCase::ascii("type::decimal") => PathKind::Function,
 		UniCase::ascii("type::duration") => PathKind::Function,
@@ -21,6 +21,13 @@ impl Parser<'_> {
 		Ok(kind)
 	}
 
+	/// Parse an inner kind which makes up the whole input, such as `int` or `array<string>`.
+	pub async fn parse_full_kind(&mut self, ctx: &mut Stk) -> ParseResult<Kind> {
+		let kind = self.parse_inner_kind(ctx).await?;
+		expected!(self, TokenKind::Eof);
+		Ok(kind)
+	}
+
 	/// Parse an inner kind, a kind without enclosing `<` `>`.
 	pub async fn parse_inner_kind(&mut self, ctx: &mut Stk) -> ParseResult<Kind> {
 		match self.peek_kind() {
@@ -1,6 +1,5 @@
 use std::collections::BTreeMap;
 
-use geo_types::{LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
 use reblessive::Stk;
 
 use crate::{
@@ -72,21 +71,36 @@ impl Parser<'_> {
 				// can still be wrong.
 				//
 				// we can unwrap strand since we just matched it to not be an err.
-				self.parse_geometry_after_type(ctx, start, key, type_value, Self::to_point, |x| {
-					Value::Geometry(Geometry::Point(x))
-				})
+				self.parse_geometry_after_type(
+					ctx,
+					start,
+					key,
+					type_value,
+					Geometry::array_to_point,
+					|x| Value::Geometry(Geometry::Point(x)),
+				)
 				.await
 			}
 			"LineString" => {
-				self.parse_geometry_after_type(ctx, start, key, type_value, Self::to_line, |x| {
-					Value::Geometry(Geometry::Line(x))
-				})
+				self.parse_geometry_after_type(
+					ctx,
+					start,
+					key,
+					type_value,
+					Geometry::array_to_line,
+					|x| Value::Geometry(Geometry::Line(x)),
+				)
 				.await
 			}
 			"Polygon" => {
-				self.parse_geometry_after_type(ctx, start, key, type_value, Self::to_polygon, |x| {
-					Value::Geometry(Geometry::Polygon(x))
-				})
+				self.parse_geometry_after_type(
+					ctx,
+					start,
+					key,
+					type_value,
+					Geometry::array_to_polygon,
+					|x| Value::Geometry(Geometry::Polygon(x)),
+				)
 				.await
 			}
 			"MultiPoint" => {
@@ -95,7 +109,7 @@ impl Parser<'_> {
 					start,
 					key,
 					type_value,
-					Self::to_multipoint,
+					Geometry::array_to_multipoint,
 					|x| Value::Geometry(Geometry::MultiPoint(x)),
 				)
 				.await
@@ -106,7 +120,7 @@ impl Parser<'_> {
 					start,
 					key,
 					type_value,
-					Self::to_multiline,
+					Geometry::array_to_multiline,
 					|x| Value::Geometry(Geometry::MultiLine(x)),
 				)
 				.await
@@ -117,7 +131,7 @@ impl Parser<'_> {
 					start,
 					key,
 					type_value,
-					Self::to_multipolygon,
+					Geometry::array_to_multipolygon,
 					|x| Value::Geometry(Geometry::MultiPolygon(x)),
 				)
 				.await
@@ -267,42 +281,42 @@ impl Parser<'_> {
 		match type_value.as_str() {
 			"Point" => {
 				if self.eat(t!("}")) {
-					if let Some(point) = Self::to_point(&value) {
+					if let Some(point) = Geometry::array_to_point(&value) {
 						return Ok(Value::Geometry(Geometry::Point(point)));
 					}
 				}
 			}
 			"LineString" => {
 				if self.eat(t!("}")) {
-					if let Some(point) = Self::to_line(&value) {
+					if let Some(point) = Geometry::array_to_line(&value) {
 						return Ok(Value::Geometry(Geometry::Line(point)));
 					}
 				}
 			}
 			"Polygon" => {
 				if self.eat(t!("}")) {
-					if let Some(point) = Self::to_polygon(&value) {
+					if let Some(point) = Geometry::array_to_polygon(&value) {
 						return Ok(Value::Geometry(Geometry::Polygon(point)));
 					}
 				}
 			}
 			"MultiPoint" => {
 				if self.eat(t!("}")) {
-					if let Some(point) = Self::to_multipolygon(&value) {
+					if let Some(point) = Geometry::array_to_multipolygon(&value) {
 						return Ok(Value::Geometry(Geometry::MultiPolygon(point)));
 					}
 				}
 			}
 			"MultiLineString" => {
 				if self.eat(t!("}")) {
-					if let Some(point) = Self::to_multiline(&value) {
+					if let Some(point) = Geometry::array_to_multiline(&value) {
 						return Ok(Value::Geometry(Geometry::MultiLine(point)));
 					}
 				}
 			}
 			"MultiPolygon" => {
 				if self.eat(t!("}")) {
-					if let Some(point) = Self::to_multipolygon(&value) {
+					if let Some(point) = Geometry::array_to_multipolygon(&value) {
 						return Ok(Value::Geometry(Geometry::MultiPolygon(point)));
 					}
 				}
@@ -506,82 +520,6 @@ impl Parser<'_> {
 		Ok(map(v))
 	}
 
-	fn to_multipolygon(v: &Value) -> Option<MultiPolygon<f64>> {
-		let mut res = Vec::new();
-		let Value::Array(v) = v else {
-			return None;
-		};
-		for x in v.iter() {
-			res.push(Self::to_polygon(x)?);
-		}
-		Some(MultiPolygon::new(res))
-	}
-
-	fn to_multiline(v: &Value) -> Option<MultiLineString<f64>> {
-		let mut res = Vec::new();
-		let Value::Array(v) = v else {
-			return None;
-		};
-		for x in v.iter() {
-			res.push(Self::to_line(x)?);
-		}
-		Some(MultiLineString::new(res))
-	}
-
-	fn to_multipoint(v: &Value) -> Option<MultiPoint<f64>> {
-		let mut res = Vec::new();
-		let Value::Array(v) = v else {
-			return None;
-		};
-		for x in v.iter() {
-			res.push(Self::to_point(x)?);
-		}
-		Some(MultiPoint::new(res))
-	}
-
-	fn to_polygon(v: &Value) -> Option<Polygon<f64>> {
-		let mut res = Vec::new();
-		let Value::Array(v) = v else {
-			return None;
-		};
-		if v.is_empty() {
-			return None;
-		}
-		let first = Self::to_line(&v[0])?;
-		for x in &v[1..] {
-			res.push(Self::to_line(x)?);
-		}
-		Some(Polygon::new(first, res))
-	}
-
-	fn to_line(v: &Value) -> Option<LineString<f64>> {
-		let mut res = Vec::new();
-		let Value::Array(v) = v else {
-			return None;
-		};
-		for x in v.iter() {
-			res.push(Self::to_point(x)?);
-		}
-		Some(LineString::from(res))
-	}
-
-	fn to_point(v: &Value) -> Option<Point<f64>> {
-		let Value::Array(v) = v else {
-			return None;
-		};
-		if v.len() != 2 {
-			return None;
-		}
-		// FIXME: This truncates decimals and large integers into a f64.
-		let Value::Number(ref a) = v.0[0] else {
-			return None;
-		};
-		let Value::Number(ref b) = v.0[1] else {
-			return None;
-		};
-		Some(Point::from((a.clone().try_into().ok()?, b.clone().try_into().ok()?)))
-	}
-
 	async fn parse_object_from_key(
 		&mut self,
 		ctx: &mut Stk,
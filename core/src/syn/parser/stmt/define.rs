@@ -573,6 +573,7 @@ impl Parser<'_> {
 			name,
 			permissions: Permissions::none(),
 			if_not_exists,
+			default_permissions: true,
 			..Default::default()
 		};
 
@@ -615,6 +616,7 @@ impl Parser<'_> {
 				t!("PERMISSIONS") => {
 					self.pop_peek();
 					res.permissions = ctx.run(|ctx| self.parse_permission(ctx, false)).await?;
+					res.default_permissions = false;
 				}
 				t!("CHANGEFEED") => {
 					self.pop_peek();
@@ -1234,6 +1234,7 @@ fn parse_define_table() {
 			comment: None,
 			if_not_exists: false,
 			kind: TableType::Any,
+			default_permissions: false,
 		}))
 	);
 }
@@ -0,0 +1,44 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use pprof::criterion::{Output, PProfProfiler};
+use surrealdb::engine::local::Mem;
+use surrealdb::opt::Config;
+use surrealdb::Surreal;
+
+const QUERY: &str = "
+	LET $person = { name: $name, age: $age, tags: ['a', 'b', 'c'], address: { city: 'London', postcode: 'SW1A 1AA' } };
+	RETURN IF $person.age >= 18 { 'adult' } ELSE IF $person.age >= 13 { 'teenager' } ELSE { 'child' };
+	RETURN array::len($person.tags) + string::len($person.name) * 2 - math::abs(-1);
+	RETURN $person.address.city + ', ' + $person.address.postcode;
+";
+
+macro_rules! query {
+	($c: expr, $name: ident, $config: expr) => {
+		$c.bench_function(stringify!($name), |b| {
+			let runtime = tokio::runtime::Runtime::new().unwrap();
+			let db = runtime.block_on(async {
+				let db = Surreal::new::<Mem>($config).await.unwrap();
+				db.use_ns("test").use_db("test").await.unwrap();
+				db
+			});
+			b.to_async(&runtime).iter(|| async {
+				let query = db.query(black_box(QUERY)).bind(("name", "Tobie")).bind(("age", 32));
+				black_box(query.await.unwrap());
+			})
+		});
+	};
+}
+
+fn bench_statement_cache(c: &mut Criterion) {
+	let mut c = c.benchmark_group("statement_cache");
+	c.throughput(Throughput::Elements(1));
+	query!(c, uncached, Config::new());
+	query!(c, cached, Config::new().statement_cache_size(100));
+	c.finish();
+}
+
+criterion_group!(
+	name = benches;
+	config = Criterion::default().with_profiler(PProfProfiler::new(1000, Output::Flamegraph(None)));
+	targets = bench_statement_cache
+);
+criterion_main!(benches);
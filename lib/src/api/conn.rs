@@ -8,9 +8,15 @@ use crate::api::Surreal;
 use crate::dbs::Notification;
 use crate::sql::from_value;
 use crate::sql::Query;
+use crate::sql::Statement;
 use crate::sql::Value;
+use flume::r#async::RecvStream;
 use flume::Receiver;
+use flume::SendError;
 use flume::Sender;
+use futures::Stream;
+use futures::StreamExt;
+use quick_cache::sync::Cache;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -20,6 +26,10 @@ use std::path::PathBuf;
 use std::pin::Pin;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+use std::task::Context;
+use std::task::Poll;
 
 #[derive(Debug)]
 #[allow(dead_code)] // used by the embedded and remote connections
@@ -28,12 +38,103 @@ pub(crate) struct Route {
 	pub(crate) response: Sender<Result<DbResponse>>,
 }
 
+/// Creates the channel routes are sent to the router on
+///
+/// A capacity of `0` creates an unbounded channel.
+pub(crate) fn route_channel(capacity: usize) -> (RouteSender, RouteReceiver) {
+	let (sender, receiver) = channel(capacity);
+	let (resized_tx, resized_rx) = flume::unbounded();
+	let sender = RouteSender {
+		current: RwLock::new((sender, capacity)),
+		resized: resized_tx,
+	};
+	let receiver = RouteReceiver {
+		current: receiver.into_stream(),
+		resized: resized_rx,
+	};
+	(sender, receiver)
+}
+
+fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+	match capacity {
+		0 => flume::unbounded(),
+		capacity => flume::bounded(capacity),
+	}
+}
+
+/// The sending half of the route channel
+#[derive(Debug)]
+pub(crate) struct RouteSender {
+	current: RwLock<(Sender<Option<Route>>, usize)>,
+	resized: Sender<Receiver<Option<Route>>>,
+}
+
+impl RouteSender {
+	fn sender(&self) -> Sender<Option<Route>> {
+		self.current.read().unwrap_or_else(PoisonError::into_inner).0.clone()
+	}
+
+	pub(crate) async fn send_async(
+		&self,
+		route: Option<Route>,
+	) -> std::result::Result<(), SendError<Option<Route>>> {
+		self.sender().send_async(route).await
+	}
+
+	pub(crate) fn send(
+		&self,
+		route: Option<Route>,
+	) -> std::result::Result<(), SendError<Option<Route>>> {
+		self.sender().send(route)
+	}
+
+	/// Replaces the channel with one of a different capacity
+	///
+	/// The router finishes draining routes already sent on the old channel before
+	/// receiving from the new one, so routes are still received in order.
+	pub(crate) fn resize(&self, capacity: usize) {
+		let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+		if current.1 == capacity {
+			return;
+		}
+		let (sender, receiver) = channel(capacity);
+		// The router has to know about the new channel before the old one closes
+		if self.resized.send(receiver).is_ok() {
+			*current = (sender, capacity);
+		}
+	}
+}
+
+/// The receiving half of the route channel, which follows the channel when it is resized
+pub(crate) struct RouteReceiver {
+	current: RecvStream<'static, Option<Route>>,
+	resized: Receiver<Receiver<Option<Route>>>,
+}
+
+impl Stream for RouteReceiver {
+	type Item = Option<Route>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		loop {
+			match self.current.poll_next_unpin(cx) {
+				// The old channel is closed and drained, so carry on with the new one
+				Poll::Ready(None) => match self.resized.try_recv() {
+					Ok(receiver) => self.current = receiver.into_stream(),
+					Err(_) => return Poll::Ready(None),
+				},
+				poll => return poll,
+			}
+		}
+	}
+}
+
 /// Message router
 #[derive(Debug)]
 pub struct Router {
-	pub(crate) sender: Sender<Option<Route>>,
+	pub(crate) sender: RouteSender,
 	pub(crate) last_id: AtomicI64,
 	pub(crate) features: HashSet<ExtraFeatures>,
+	pub(crate) statement_cache: StatementCache,
 }
 
 impl Router {
@@ -42,6 +143,34 @@ impl Router {
 	}
 }
 
+/// Parsed queries, keyed by their SurrealQL text
+#[derive(Debug)]
+pub(crate) struct StatementCache(Option<Cache<String, Vec<Statement>>>);
+
+impl StatementCache {
+	/// Creates a cache holding up to `size` queries, where 0 disables caching
+	pub(crate) fn new(size: usize) -> Self {
+		match size {
+			0 => Self(None),
+			size => Self(Some(Cache::new(size))),
+		}
+	}
+
+	/// Parses a query, reusing the statements of an earlier parse of the same text
+	pub(crate) fn parse(&self, sql: &str) -> Result<Vec<Statement>> {
+		let Some(cache) = &self.0 else {
+			return Ok(crate::syn::parse(sql)?.0 .0);
+		};
+		if let Some(statements) = cache.get(sql) {
+			return Ok(statements);
+		}
+		// Queries which fail to parse are not cached
+		let statements = crate::syn::parse(sql)?.0 .0;
+		cache.insert(sql.to_owned(), statements.clone());
+		Ok(statements)
+	}
+}
+
 impl Drop for Router {
 	fn drop(&mut self) {
 		let _res = self.sender.send(None);
@@ -56,14 +185,30 @@ pub enum Method {
 	Authenticate,
 	/// Performs a merge update operation
 	Merge,
+	/// Runs a batch of queries concurrently
+	Batch,
 	/// Creates a record in a table
 	Create,
+	/// Creates a record unless it already exists
+	CreateIfAbsent,
+	/// Starts building an index in the background
+	DefineIndexAsync,
 	/// Deletes a record from a table
 	Delete,
+	/// Deletes the records of a table matching a condition and counts them
+	DeleteWhere,
 	/// Exports a database
 	Export,
+	/// Estimates the cost of a query without running it
+	ExplainCost,
+	/// Exports the definitions of a database without its records
+	ExportSchema,
+	/// Traverses the graph from a record
+	Graph,
 	/// Checks the health of the server
 	Health,
+	/// Reads the cardinality of an index
+	IndexStats,
 	/// Imports a database
 	Import,
 	/// Invalidates a session
@@ -76,24 +221,46 @@ pub enum Method {
 	/// Starts a live query
 	#[doc(hidden)] // Not supported yet
 	Live,
+	/// Acquires an advisory lock on a record
+	Lock,
+	/// Reads the metrics of an embedded database
+	Metrics,
 	/// Performs a patch update operation
 	Patch,
+	/// Checks that the connection is responsive without touching storage
+	Ping,
 	/// Sends a raw query to the database
 	Query,
+	/// Sends a query whose results are returned without deserialising them
+	RawQuery,
+	/// Sends a query whose results are returned with execution statistics
+	QueryStats,
+	/// Creates several graph edges in one transaction
+	RelateMany,
 	/// Selects a record or records from a table
 	Select,
+	/// Compares a record with an expected state of it
+	SelectDiff,
+	/// Selects a record or records along with their metadata
+	SelectMeta,
 	/// Sets a parameter on the connection
 	Set,
+	/// Reads the change feed of a table
+	ShowChanges,
 	/// Signs into the server
 	Signin,
 	/// Signs up on the server
 	Signup,
+	/// Releases an advisory lock on a record
+	Unlock,
 	/// Removes a parameter from a connection
 	Unset,
 	/// Performs an update operation
 	Update,
 	/// Performs an upsert operation
 	Upsert,
+	/// Upserts several records in one transaction
+	UpsertMany,
 	/// Selects a namespace and database to use
 	Use,
 	/// Queries the version of the server
@@ -128,6 +295,8 @@ pub struct Param {
 	pub(crate) file: Option<PathBuf>,
 	pub(crate) bytes_sender: Option<channel::Sender<Result<Vec<u8>>>>,
 	pub(crate) notification_sender: Option<channel::Sender<Notification>>,
+	pub(crate) index_build_sender: Option<channel::Sender<Result<()>>>,
+	pub(crate) batch_sender: Option<channel::Sender<(usize, Result<Value>)>>,
 	pub(crate) ml_config: Option<MlConfig>,
 }
 
@@ -329,3 +498,42 @@ pub trait Connection: Sized + Send + Sync + 'static {
 		})
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::FutureExt;
+
+	fn route(id: i64) -> Option<Route> {
+		Some(Route {
+			request: (id, Method::Ping, Param::new(Vec::new())),
+			response: flume::bounded(1).0,
+		})
+	}
+
+	fn received(route: Option<Option<Route>>) -> i64 {
+		route.flatten().expect("a route").request.0
+	}
+
+	#[tokio::test]
+	async fn resized_route_channel_buffers_more_routes() {
+		let (sender, mut receiver) = route_channel(1);
+		assert!(sender.send_async(route(0)).now_or_never().is_some());
+		assert!(sender.send_async(route(1)).now_or_never().is_none());
+		// Resizing to the same capacity keeps the existing channel
+		sender.resize(1);
+		assert!(receiver.resized.is_empty());
+		assert!(sender.send_async(route(1)).now_or_never().is_none());
+		// A bigger channel buffers more routes before blocking
+		sender.resize(3);
+		for id in 1..=3 {
+			assert!(sender.send_async(route(id)).now_or_never().is_some());
+		}
+		assert!(sender.send_async(route(4)).now_or_never().is_none());
+		// Routes from the old channel are received first
+		for id in 0..=3 {
+			assert_eq!(received(receiver.next().await), id);
+		}
+		assert!(receiver.next().now_or_never().is_none());
+	}
+}
@@ -1,8 +1,10 @@
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 #[allow(unused_imports)] // used by the DB engines
 use crate::api::engine;
 use crate::api::engine::any::Any;
@@ -54,10 +56,8 @@ impl Connection for Any {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
-			let (route_tx, route_rx) = match capacity {
-				0 => flume::unbounded(),
-				capacity => flume::bounded(capacity),
-			};
+			let statement_cache = StatementCache::new(address.config.statement_cache_size);
+			let (route_tx, route_rx) = route_channel(capacity);
 
 			let (conn_tx, conn_rx) = flume::bounded::<Result<()>>(1);
 			let mut features = HashSet::new();
@@ -68,6 +68,17 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::Metrics);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
+						features.insert(ExtraFeatures::Batch);
+						features.insert(ExtraFeatures::IndexBuilds);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
 					}
@@ -83,6 +94,17 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::Metrics);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
+						features.insert(ExtraFeatures::Batch);
+						features.insert(ExtraFeatures::IndexBuilds);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
 					}
@@ -98,6 +120,17 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::Metrics);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
+						features.insert(ExtraFeatures::Batch);
+						features.insert(ExtraFeatures::IndexBuilds);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
 					}
@@ -114,6 +147,17 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::Metrics);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
+						features.insert(ExtraFeatures::Batch);
+						features.insert(ExtraFeatures::IndexBuilds);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
 					}
@@ -129,6 +173,17 @@ impl Connection for Any {
 					{
 						features.insert(ExtraFeatures::Backup);
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::Metrics);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
+						features.insert(ExtraFeatures::Batch);
+						features.insert(ExtraFeatures::IndexBuilds);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
 					}
@@ -148,7 +203,7 @@ impl Connection for Any {
 						#[allow(unused_mut)]
 						let mut builder = ClientBuilder::new().default_headers(headers);
 						#[cfg(any(feature = "native-tls", feature = "rustls"))]
-						if let Some(tls) = address.config.tls_config {
+						if let Some(tls) = address.config.tls_connector()? {
 							builder = match tls {
 								#[cfg(feature = "native-tls")]
 								Tls::Native(config) => builder.use_preconfigured_tls(config),
@@ -179,7 +234,7 @@ impl Connection for Any {
 						let mut endpoint = address;
 						endpoint.url = endpoint.url.join(engine::remote::ws::PATH)?;
 						#[cfg(any(feature = "native-tls", feature = "rustls"))]
-						let maybe_connector = endpoint.config.tls_config.clone().map(Connector::from);
+						let maybe_connector = endpoint.config.tls_connector()?.map(Connector::from);
 						#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
 						let maybe_connector = None;
 
@@ -219,6 +274,7 @@ impl Connection for Any {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					statement_cache,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -1,8 +1,10 @@
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 #[allow(unused_imports)] // used by the DB engines
 use crate::api::engine;
 use crate::api::engine::any::Any;
@@ -41,10 +43,8 @@ impl Connection for Any {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
-			let (route_tx, route_rx) = match capacity {
-				0 => flume::unbounded(),
-				capacity => flume::bounded(capacity),
-			};
+			let statement_cache = StatementCache::new(address.config.statement_cache_size);
+			let (route_tx, route_rx) = route_channel(capacity);
 
 			let (conn_tx, conn_rx) = flume::bounded::<Result<()>>(1);
 			let mut features = HashSet::new();
@@ -54,6 +54,14 @@ impl Connection for Any {
 					#[cfg(feature = "kv-fdb")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??;
 					}
@@ -68,6 +76,14 @@ impl Connection for Any {
 					#[cfg(feature = "kv-indxdb")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??;
 					}
@@ -82,6 +98,14 @@ impl Connection for Any {
 					#[cfg(feature = "kv-mem")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??;
 					}
@@ -96,6 +120,14 @@ impl Connection for Any {
 					#[cfg(feature = "kv-rocksdb")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??;
 					}
@@ -111,6 +143,14 @@ impl Connection for Any {
 					#[cfg(feature = "kv-surrealkv")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??;
 					}
@@ -126,6 +166,14 @@ impl Connection for Any {
 					#[cfg(feature = "kv-tikv")]
 					{
 						features.insert(ExtraFeatures::LiveQueries);
+						features.insert(ExtraFeatures::CreateIfAbsent);
+						features.insert(ExtraFeatures::ExportSchema);
+						features.insert(ExtraFeatures::Graph);
+						features.insert(ExtraFeatures::QueryStats);
+						features.insert(ExtraFeatures::ExplainCost);
+						features.insert(ExtraFeatures::IndexStats);
+						features.insert(ExtraFeatures::RecordMeta);
+						features.insert(ExtraFeatures::SelectDiff);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??;
 					}
@@ -174,6 +222,7 @@ impl Connection for Any {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					statement_cache,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
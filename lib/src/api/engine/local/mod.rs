@@ -33,23 +33,29 @@ use crate::api::conn::MlConfig;
 use crate::api::conn::Param;
 use crate::api::engine::create_statement;
 use crate::api::engine::delete_statement;
+use crate::api::engine::delete_where_statement;
 use crate::api::engine::insert_statement;
 use crate::api::engine::merge_statement;
 use crate::api::engine::patch_statement;
+use crate::api::engine::relate_many_query;
 use crate::api::engine::select_statement;
+use crate::api::engine::show_changes_statement;
+use crate::api::engine::transaction_result;
 use crate::api::engine::update_statement;
+use crate::api::engine::upsert_many_query;
+use crate::api::engine::upsert_many_result;
 use crate::api::engine::upsert_statement;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::api::err::Error;
+use crate::api::opt::ExperimentalFeature;
 use crate::api::Connect;
 use crate::api::Response as QueryResponse;
 use crate::api::Result;
 use crate::api::Surreal;
 use crate::dbs::Notification;
+use crate::dbs::RecordVersion;
 use crate::dbs::Response;
 use crate::dbs::Session;
-#[cfg(feature = "ml")]
-#[cfg(not(target_arch = "wasm32"))]
 use crate::iam::check::check_ns_db;
 #[cfg(feature = "ml")]
 #[cfg(not(target_arch = "wasm32"))]
@@ -58,32 +64,41 @@ use crate::iam::Action;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::iam::ResourceKind;
 use crate::kvs::Datastore;
-#[cfg(feature = "ml")]
-#[cfg(not(target_arch = "wasm32"))]
+use crate::kvs::Transaction;
 use crate::kvs::{LockType, TransactionType};
 use crate::method::Stats;
 #[cfg(feature = "ml")]
 #[cfg(not(target_arch = "wasm32"))]
 use crate::ml::storage::surml_file::SurMlFile;
 use crate::opt::IntoEndpoint;
+use crate::sql::from_value;
+use crate::sql::index::Index;
+use crate::sql::statements::AnalyzeStatement;
+use crate::sql::statements::DefineIndexStatement;
 #[cfg(feature = "ml")]
 #[cfg(not(target_arch = "wasm32"))]
 use crate::sql::statements::DefineModelStatement;
-#[cfg(feature = "ml")]
-#[cfg(not(target_arch = "wasm32"))]
 use crate::sql::statements::DefineStatement;
 use crate::sql::statements::KillStatement;
+use crate::sql::statements::SelectStatement;
+use crate::sql::to_value;
+use crate::sql::Explain;
+use crate::sql::Limit;
 use crate::sql::Query;
 use crate::sql::Statement;
+use crate::sql::Thing;
 use crate::sql::Uuid;
 use crate::sql::Value;
 use channel::Sender;
+use chrono::Utc;
 #[cfg(feature = "ml")]
 #[cfg(not(target_arch = "wasm32"))]
 use futures::StreamExt;
 use indexmap::IndexMap;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::marker::PhantomData;
 use std::mem;
 #[cfg(not(target_arch = "wasm32"))]
@@ -98,8 +113,13 @@ use tokio::io;
 use tokio::io::AsyncReadExt;
 #[cfg(not(target_arch = "wasm32"))]
 use tokio::io::AsyncWriteExt;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
 
 const DEFAULT_TICK_INTERVAL: Duration = Duration::from_secs(10);
+const DEFAULT_FULL_SCAN_THRESHOLD: u64 = 1000;
 
 /// In-memory database
 ///
@@ -396,6 +416,7 @@ fn process(responses: Vec<Response>) -> QueryResponse {
 	for (index, response) in responses.into_iter().enumerate() {
 		let stats = Stats {
 			execution_time: Some(response.time),
+			records_examined: Some(response.examined as u64),
 		};
 		match response.result {
 			Ok(value) => map.insert(index, (stats, Ok(value))),
@@ -430,6 +451,755 @@ async fn take(one: bool, responses: Vec<Response>) -> Result<Value> {
 	}
 }
 
+fn created_record(created: bool, record: Value) -> Value {
+	let mut object = BTreeMap::new();
+	object.insert("created".to_owned(), Value::from(created));
+	object.insert("record".to_owned(), record);
+	Value::from(object)
+}
+
+/// Pairs each selected record with its version and last-modified time
+///
+/// Both are stored with each record on a table with a change feed whenever the record is
+/// written, so only the selected records are looked up. Either is `NONE` when the table has no
+/// change feed or the record has not been written since it got one.
+async fn with_record_meta(
+	kvs: &Datastore,
+	session: &Session,
+	records: Vec<Value>,
+) -> Result<Value> {
+	let (ns, db) = check_ns_db(session)?;
+	let mut tx = kvs.transaction(TransactionType::Read, LockType::Optimistic).await?;
+	let mut out = Vec::with_capacity(records.len());
+	for record in records {
+		let id = record.pick(&["id".into()]);
+		let meta = match &id {
+			Value::Thing(thing) => {
+				match tx.get(crate::key::table::rv::new(&ns, &db, &thing.tb, &thing.id)).await {
+					Ok(val) => val.map(RecordVersion::from),
+					Err(error) => {
+						tx.cancel().await?;
+						return Err(error.into());
+					}
+				}
+			}
+			_ => None,
+		};
+		let mut object = BTreeMap::new();
+		object.insert("id".to_owned(), id);
+		match meta {
+			Some(meta) => {
+				object.insert("version".to_owned(), Value::from(meta.version));
+				object.insert("last_modified".to_owned(), Value::Datetime(meta.modified));
+			}
+			None => {
+				object.insert("version".to_owned(), Value::None);
+				object.insert("last_modified".to_owned(), Value::None);
+			}
+		}
+		object.insert("data".to_owned(), record);
+		out.push(Value::from(object));
+	}
+	tx.cancel().await?;
+	Ok(out.into())
+}
+
+/// Builds the RFC 6902 JSON Patch which turns one value into another
+///
+/// Objects are compared field by field and arrays item by item, and any other values which
+/// differ are replaced as a whole. Surplus array items are removed from the last one backwards,
+/// so that every operation refers to the value left by the ones before it.
+fn json_patch(from: &Value, to: &Value, path: &str, ops: &mut Vec<Value>) {
+	let op = |op: &str, path: String, value: Option<&Value>| {
+		let mut out = BTreeMap::new();
+		out.insert("op".to_owned(), Value::from(op));
+		out.insert("path".to_owned(), Value::from(path));
+		if let Some(value) = value {
+			out.insert("value".to_owned(), value.clone());
+		}
+		Value::from(out)
+	};
+	// Keys are escaped as JSON Pointer reference tokens
+	let field = |key: &str| format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"));
+	match (from, to) {
+		(from, to) if from == to => {}
+		(Value::Object(from), Value::Object(to)) => {
+			for key in from.keys().filter(|key| !to.contains_key(*key)) {
+				ops.push(op("remove", field(key), None));
+			}
+			for (key, value) in to.iter() {
+				match from.get(key) {
+					Some(old) => json_patch(old, value, &field(key), ops),
+					None => ops.push(op("add", field(key), Some(value))),
+				}
+			}
+		}
+		(Value::Array(from), Value::Array(to)) => {
+			let common = from.len().min(to.len());
+			for i in 0..common {
+				json_patch(&from[i], &to[i], &format!("{path}/{i}"), ops);
+			}
+			for (i, value) in to.iter().enumerate().skip(common) {
+				ops.push(op("add", format!("{path}/{i}"), Some(value)));
+			}
+			for i in (common..from.len()).rev() {
+				ops.push(op("remove", format!("{path}/{i}"), None));
+			}
+		}
+		(_, to) => ops.push(op("replace", path.to_owned(), Some(to))),
+	}
+}
+
+/// Caps the rows a `SELECT` statement returns at one more than the configured maximum
+///
+/// This stops the statement as soon as it is known to return too many rows, rather than after
+/// scanning the whole table. Statements with a `LIMIT` of their own are left alone.
+fn limit_select(statement: &mut SelectStatement, max: usize) {
+	if statement.limit.is_none() {
+		statement.limit = Some(Limit(max.saturating_add(1).into()));
+	}
+}
+
+/// Caps the rows of the `SELECT` statements of a query, returning which of its results they
+/// produce
+fn limit_selects(query: &mut Query, max: usize) -> Vec<bool> {
+	let statements = &mut query.0 .0;
+	statements
+		.iter_mut()
+		.filter(|statement| {
+			!matches!(
+				statement,
+				Statement::Option(_)
+					| Statement::Begin(_)
+					| Statement::Cancel(_)
+					| Statement::Commit(_)
+			)
+		})
+		.map(|statement| match statement {
+			Statement::Select(select) => {
+				limit_select(select, max);
+				true
+			}
+			_ => false,
+		})
+		.collect()
+}
+
+/// Whether a result contains more rows than the configured maximum
+fn too_many_rows(value: &Value, max: usize) -> bool {
+	matches!(value, Value::Array(rows) if rows.len() > max)
+}
+
+/// Rejects queries which contain more statements than the configured maximum
+fn limit_statements(request: &(i64, Method, Param), max: Option<usize>) -> Result<()> {
+	let (Some(max), (_, Method::Query | Method::RawQuery | Method::QueryStats, param)) =
+		(max, request)
+	else {
+		return Ok(());
+	};
+	// Comments and empty statements are already dropped by the parser
+	let count = param.query.as_ref().map_or(0, |(query, _)| query.0 .0.len());
+	if count > max {
+		return Err(Error::TooManyStatements {
+			count,
+			max,
+		}
+		.into());
+	}
+	Ok(())
+}
+
+/// Rejects queries which bind more parameters than the configured maximum
+fn limit_bound_params(request: &(i64, Method, Param), max: Option<usize>) -> Result<()> {
+	let (Some(max), (_, Method::Query | Method::RawQuery | Method::QueryStats, param)) =
+		(max, request)
+	else {
+		return Ok(());
+	};
+	let count = param.query.as_ref().map_or(0, |(_, bindings)| bindings.len());
+	if count > max {
+		return Err(Error::TooManyParameters {
+			count,
+			max,
+		}
+		.into());
+	}
+	Ok(())
+}
+
+/// Rejects requests from sessions which have not signed in, when guests are not allowed
+fn deny_guests(request: &(i64, Method, Param), session: &Session, deny: bool) -> Result<()> {
+	match request.1 {
+		Method::Health
+		| Method::Version
+		| Method::Ping
+		| Method::Use
+		| Method::Signin
+		| Method::Signup
+		| Method::Authenticate
+		| Method::Invalidate => Ok(()),
+		_ if deny && session.au.is_anon() => Err(Error::NotAuthenticated.into()),
+		_ => Ok(()),
+	}
+}
+
+/// Rejects requests which use an experimental feature that has not been enabled
+fn deny_experimental(
+	request: &(i64, Method, Param),
+	enabled: &HashSet<ExperimentalFeature>,
+) -> Result<()> {
+	let (
+		_,
+		Method::Query | Method::RawQuery | Method::QueryStats | Method::DefineIndexAsync,
+		param,
+	) = request
+	else {
+		return Ok(());
+	};
+	let Some((query, _)) = &param.query else {
+		return Ok(());
+	};
+	for statement in query.0 .0.iter() {
+		let feature = match statement {
+			Statement::Define(DefineStatement::Index(DefineIndexStatement {
+				index: Index::Hnsw(_),
+				..
+			})) => ExperimentalFeature::HnswIndex,
+			_ => continue,
+		};
+		if !enabled.contains(&feature) {
+			return Err(Error::ExperimentalFeatureDisabled(feature).into());
+		}
+	}
+	Ok(())
+}
+
+/// Rejects queries which would scan all of a table holding more records than the threshold
+///
+/// Each `SELECT` statement is explained first. Statements which can't be explained on their
+/// own, such as those using a variable set earlier in the same query, are left for the query
+/// itself to report.
+async fn deny_full_scans(
+	request: &(i64, Method, Param),
+	kvs: &Datastore,
+	session: &Session,
+	vars: &BTreeMap<String, Value>,
+	threshold: Option<u64>,
+) -> Result<()> {
+	let (Some(threshold), (_, Method::Query | Method::RawQuery | Method::QueryStats, param)) =
+		(threshold, request)
+	else {
+		return Ok(());
+	};
+	let Some((query, bindings)) = &param.query else {
+		return Ok(());
+	};
+	let mut tables = BTreeSet::new();
+	for step in plan_selects(kvs, session, vars, query, bindings).await? {
+		if step.pick(&["operation".into()]) == Value::from("Iterate Table") {
+			if let Value::Strand(table) = step.pick(&["detail".into(), "table".into()]) {
+				tables.insert(table.0);
+			}
+		}
+	}
+	for table in tables {
+		// Only count as many records as it takes to exceed the threshold
+		if count_records(kvs, session, &table, threshold.saturating_add(1)).await? > threshold {
+			return Err(Error::FullScanDenied {
+				table,
+				threshold,
+			}
+			.into());
+		}
+	}
+	Ok(())
+}
+
+/// How many times a record which disappears after failing to create it is created again
+const CREATE_IF_ABSENT_RETRIES: usize = 3;
+
+/// Estimates the cost of a query from the plan of each `SELECT` statement
+///
+/// Nothing is executed. Reading a single record costs one, and scanning a table costs the
+/// number of records it holds. An index lookup costs one plus the number of entries it
+/// matches, which are counted on unique and non-unique indexes. Nearest neighbour lookups
+/// match as many records as they ask for, while full-text lookups and record ranges are
+/// assumed to match the whole table.
+async fn explain_cost(
+	kvs: &Datastore,
+	session: &Session,
+	vars: &BTreeMap<String, Value>,
+	query: &Query,
+	bindings: &BTreeMap<String, Value>,
+) -> Result<Value> {
+	let steps = plan_selects(kvs, session, vars, query, bindings).await?;
+	let mut tx = kvs.transaction(TransactionType::Read, LockType::Optimistic).await?;
+	let estimate = estimate_steps(&mut tx, session, steps).await;
+	tx.cancel().await?;
+	let (cost, rows) = estimate?;
+	Ok(Value::from(crate::map! {
+		String::from("cost") => Value::from(cost),
+		String::from("rows") => Value::from(rows),
+	}))
+}
+
+/// Adds up the cost and the number of records selected by the steps of a plan
+async fn estimate_steps(
+	tx: &mut Transaction,
+	session: &Session,
+	steps: Vec<Value>,
+) -> Result<(f64, u64)> {
+	let (ns, db) = check_ns_db(session)?;
+	let mut sizes = HashMap::new();
+	let mut indexes = HashMap::new();
+	let (mut cost, mut rows) = (0.0, 0);
+	for step in steps {
+		let operation = step.pick(&["operation".into()]).as_raw_string();
+		let table = step.pick(&["detail".into(), "table".into()]).as_raw_string();
+		let matched = match operation.as_str() {
+			"Iterate Table" | "Iterate Range" => {
+				table_size(tx, &ns, &db, &table, &mut sizes).await?
+			}
+			"Iterate Index" => {
+				if !indexes.contains_key(&table) {
+					indexes.insert(table.clone(), tx.all_tb_indexes(&ns, &db, &table).await?);
+				}
+				let plan = step.pick(&["detail".into(), "plan".into()]);
+				let lookups = match plan.pick(&["joins".into()]) {
+					Value::Array(joins) => joins.0,
+					_ => vec![plan],
+				};
+				let mut matched = 0;
+				for lookup in lookups {
+					let name = lookup.pick(&["index".into()]).as_raw_string();
+					let index = indexes[&table].iter().find(|index| index.name.0 == name);
+					matched += match index {
+						Some(index) => match count_matches(tx, &ns, &db, index, &lookup).await? {
+							Some(count) => count,
+							None => table_size(tx, &ns, &db, &table, &mut sizes).await?,
+						},
+						None => 0,
+					};
+					// Looking up the index itself
+					cost += 1.0;
+				}
+				matched
+			}
+			operation if operation.starts_with("Iterate ") => 1,
+			_ => continue,
+		};
+		cost += matched as f64;
+		rows += matched;
+	}
+	Ok((cost, rows))
+}
+
+/// Counts the records of a table, once per table
+async fn table_size(
+	tx: &mut Transaction,
+	ns: &str,
+	db: &str,
+	table: &str,
+	sizes: &mut HashMap<String, u64>,
+) -> Result<u64> {
+	if let Some(size) = sizes.get(table) {
+		return Ok(*size);
+	}
+	let beg = crate::key::thing::prefix(ns, db, table);
+	let end = crate::key::thing::suffix(ns, db, table);
+	let size = count_keys(tx, beg, end).await?;
+	sizes.insert(table.to_owned(), size);
+	Ok(size)
+}
+
+/// Counts the entries of an index matched by a lookup from the plan of a query
+///
+/// Returns `None` when the entries can't be counted without running the lookup.
+async fn count_matches(
+	tx: &mut Transaction,
+	ns: &str,
+	db: &str,
+	index: &DefineIndexStatement,
+	lookup: &Value,
+) -> Result<Option<u64>> {
+	let operator = lookup.pick(&["operator".into()]).as_raw_string();
+	let value = lookup.pick(&["value".into()]);
+	let bound = |bound: &str| match lookup.pick(&[bound.into(), "value".into()]) {
+		Value::None => None,
+		value => Some((value, lookup.pick(&[bound.into(), "inclusive".into()]).is_truthy())),
+	};
+	let (from, to) = match operator.as_str() {
+		"=" | "==" => (Some((value.clone(), true)), Some((value, true))),
+		"union" => {
+			let Value::Array(values) = value else {
+				return Ok(Some(0));
+			};
+			let mut count = 0;
+			for value in values {
+				let value = Some((value, true));
+				count += count_range(tx, ns, db, index, &value, &value).await?.unwrap_or(0);
+			}
+			return Ok(Some(count));
+		}
+		"<" => (None, Some((value, false))),
+		"<=" => (None, Some((value, true))),
+		">" => (Some((value, false)), None),
+		">=" => (Some((value, true)), None),
+		// Nearest neighbour lookups ask for a number of records, as in `<|10|>` or `<|10,40|>`
+		operator if operator.starts_with("<|") => {
+			let k = operator.trim_matches(|c| c == '<' || c == '|' || c == '>');
+			let k = k.split(',').next().and_then(|k| k.parse().ok());
+			return Ok(k);
+		}
+		_ if lookup.pick(&["from".into()]).is_some() => (bound("from"), bound("to")),
+		_ => return Ok(None),
+	};
+	count_range(tx, ns, db, index, &from, &to).await
+}
+
+/// Counts the entries of a unique or non-unique index between two bounds
+///
+/// Each bound is a value and whether it is included, or `None` if the range is unbounded.
+async fn count_range(
+	tx: &mut Transaction,
+	ns: &str,
+	db: &str,
+	index: &DefineIndexStatement,
+	from: &Option<(Value, bool)>,
+	to: &Option<(Value, bool)>,
+) -> Result<Option<u64>> {
+	use crate::key::index::Index as Key;
+	let (tb, ix) = (index.what.as_str(), index.name.as_str());
+	// Values are stored as arrays, with a value for each field of the index
+	let fd = |value: &Value| match value {
+		Value::Array(values) => values.clone(),
+		value => value.clone().into(),
+	};
+	let (beg, end) = match index.index {
+		// Non-unique entries are followed by the id of their record
+		Index::Idx => {
+			let beg = match from {
+				None => Key::prefix_beg(ns, db, tb, ix),
+				Some((value, true)) => Key::prefix_ids_beg(ns, db, tb, ix, &fd(value)),
+				Some((value, false)) => Key::prefix_ids_end(ns, db, tb, ix, &fd(value)),
+			};
+			let end = match to {
+				None => Key::prefix_end(ns, db, tb, ix),
+				Some((value, true)) => Key::prefix_ids_end(ns, db, tb, ix, &fd(value)),
+				Some((value, false)) => Key::prefix_ids_beg(ns, db, tb, ix, &fd(value)),
+			};
+			(beg, end)
+		}
+		// Unique entries are keyed by their value alone
+		Index::Uniq => {
+			let key = |value: &Value, after: bool| {
+				let fd = fd(value);
+				let mut key: Vec<u8> = Key::new(ns, db, tb, ix, &fd, None).into();
+				if after {
+					key.push(0x00);
+				}
+				key
+			};
+			let beg = match from {
+				None => Key::prefix_beg(ns, db, tb, ix),
+				Some((value, inclusive)) => key(value, !inclusive),
+			};
+			let end = match to {
+				None => Key::prefix_end(ns, db, tb, ix),
+				Some((value, inclusive)) => key(value, *inclusive),
+			};
+			(beg, end)
+		}
+		_ => return Ok(None),
+	};
+	Ok(Some(count_keys(tx, beg, end).await?))
+}
+
+/// Counts the keys in a range, reading them in batches
+async fn count_keys(tx: &mut Transaction, mut beg: Vec<u8>, end: Vec<u8>) -> Result<u64> {
+	const BATCH_SIZE: u32 = 1_000;
+	let mut count = 0;
+	while beg < end {
+		let batch = tx.scan(beg.clone()..end.clone(), BATCH_SIZE).await?;
+		count += batch.len() as u64;
+		match batch.last() {
+			Some((key, _)) if batch.len() == BATCH_SIZE as usize => {
+				// Continue from just after the last key of this batch
+				beg.clone_from(key);
+				beg.push(0x00);
+			}
+			_ => break,
+		}
+	}
+	Ok(count)
+}
+
+/// Plans each `SELECT` statement of a query, returning the steps of all of the plans
+///
+/// Explaining a statement plans it without iterating over anything. Statements which can't be
+/// explained on their own, such as those using a variable set earlier in the same query, are
+/// left out.
+async fn plan_selects(
+	kvs: &Datastore,
+	session: &Session,
+	vars: &BTreeMap<String, Value>,
+	query: &Query,
+	bindings: &BTreeMap<String, Value>,
+) -> Result<Vec<Value>> {
+	let mut vars = vars.clone();
+	vars.extend(bindings.iter().map(|(key, value)| (key.clone(), value.clone())));
+	let mut steps = Vec::new();
+	for statement in query.iter() {
+		let Statement::Select(select) = statement else {
+			continue;
+		};
+		let mut select = select.clone();
+		select.explain = Some(Explain::default());
+		let mut explain = Query::default();
+		explain.0 .0 = vec![Statement::Select(select)];
+		let response = kvs.process(explain, session, Some(vars.clone())).await?;
+		if let Ok(Value::Array(plan)) = take(false, response).await {
+			steps.extend(plan);
+		}
+	}
+	Ok(steps)
+}
+
+/// Counts the records of a table, stopping once the limit is reached
+async fn count_records(kvs: &Datastore, session: &Session, table: &str, limit: u64) -> Result<u64> {
+	let sql = "RETURN count(SELECT VALUE id FROM type::table($table) LIMIT $limit)";
+	let params = crate::map! {
+		String::from("table") => Value::from(table),
+		String::from("limit") => Value::from(limit),
+	};
+	let response = kvs.execute(sql, session, Some(params)).await?;
+	match take(true, response).await? {
+		Value::Number(count) => Ok(count.as_int() as u64),
+		_ => Ok(0),
+	}
+}
+
+/// Walks the graph outwards from a record, one hop at a time
+///
+/// Each record is only traversed the first time it is reached, so the walk ends once the
+/// depth is reached or no new records turn up, even if the graph has cycles.
+async fn select_graph(
+	kvs: &Datastore,
+	session: &Session,
+	vars: &BTreeMap<String, Value>,
+	start: Thing,
+	path: &str,
+	depth: i64,
+) -> Result<Value> {
+	let sql = format!("SELECT id, in, out FROM array::flatten($frontier{path})");
+	let mut seen = HashSet::from([start.clone()]);
+	let mut nodes = vec![Value::Thing(start.clone())];
+	let mut edges = Vec::new();
+	let mut edge_ids = HashSet::new();
+	let mut frontier = vec![Value::Thing(start)];
+	for _ in 0..depth {
+		if frontier.is_empty() {
+			break;
+		}
+		let mut vars = vars.clone();
+		vars.insert("frontier".to_owned(), mem::take(&mut frontier).into());
+		let response = kvs.execute(&sql, session, Some(vars)).await?;
+		let Value::Array(found) = take(false, response).await? else {
+			continue;
+		};
+		for edge in found {
+			let Value::Thing(id) = edge.pick(&["id".into()]) else {
+				continue;
+			};
+			if !edge_ids.insert(id) {
+				continue;
+			}
+			for end in ["in", "out"] {
+				if let Value::Thing(node) = edge.pick(&[end.into()]) {
+					if seen.insert(node.clone()) {
+						nodes.push(Value::Thing(node.clone()));
+						frontier.push(Value::Thing(node));
+					}
+				}
+			}
+			edges.push(edge);
+		}
+	}
+	Ok(Value::from(crate::map! {
+		String::from("nodes") => Value::from(nodes),
+		String::from("edges") => Value::from(edges),
+	}))
+}
+
+/// Opens the datastore at the endpoint, trying again while it can't be reached
+///
+/// Only failures to open or reach the underlying storage are tried again, waiting twice as
+/// long after each attempt. Any other error is returned straight away.
+async fn open_datastore(
+	endpoint: &str,
+	retry: Option<(u32, Duration)>,
+) -> std::result::Result<Datastore, crate::error::Db> {
+	let (attempts, mut backoff) = retry.unwrap_or((1, Duration::ZERO));
+	let mut attempt = 1;
+	loop {
+		match Datastore::new(endpoint).await {
+			Err(crate::error::Db::DsUnavailable(_)) if attempt < attempts => {
+				sleep(backoff).await;
+				backoff = backoff.saturating_mul(2);
+				attempt += 1;
+			}
+			result => return result,
+		}
+	}
+}
+
+/// Expires a signed in session once it has been idle for too long
+#[cfg(not(target_arch = "wasm32"))]
+struct SessionExpiry {
+	expiry: Duration,
+	last_active: std::time::Instant,
+	expired: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SessionExpiry {
+	fn new(expiry: Duration) -> Self {
+		Self {
+			expiry,
+			last_active: std::time::Instant::now(),
+			expired: false,
+		}
+	}
+
+	/// Rejects requests from a session which has expired, until it signs in again
+	fn check(&mut self, method: Method, session: &mut Session) -> Result<()> {
+		match method {
+			Method::Health
+			| Method::Version
+			| Method::Signin
+			| Method::Signup
+			| Method::Authenticate
+			| Method::Invalidate => return Ok(()),
+			_ => {}
+		}
+		if !session.au.is_anon() && self.last_active.elapsed() > self.expiry {
+			crate::iam::clear::clear(session)?;
+			self.expired = true;
+		}
+		if self.expired {
+			return Err(Error::SessionExpired.into());
+		}
+		self.last_active = std::time::Instant::now();
+		Ok(())
+	}
+
+	/// Revives the session once it has signed in, authenticated or signed out again
+	fn record(&mut self, method: Method, response: &Result<DbResponse>) {
+		if let (
+			Method::Signin | Method::Signup | Method::Authenticate | Method::Invalidate,
+			Ok(_),
+		) = (method, response)
+		{
+			self.expired = false;
+			self.last_active = std::time::Instant::now();
+		}
+	}
+}
+
+/// Describes a request for the query log, leaving out any credentials it carries
+#[cfg(not(target_arch = "wasm32"))]
+fn describe_request((_, method, param): &(i64, Method, Param)) -> String {
+	match (method, &param.query) {
+		(Method::Query | Method::RawQuery | Method::QueryStats, Some((query, _))) => {
+			query.to_string()
+		}
+		(Method::Signin | Method::Signup | Method::Authenticate, _) => method.as_str().to_owned(),
+		_ => {
+			let params: Vec<String> = param.other.iter().map(ToString::to_string).collect();
+			format!("{}({})", method.as_str(), params.join(", "))
+		}
+	}
+}
+
+/// Returns the error of a failed request, or of the first failed statement of a query
+#[cfg(not(target_arch = "wasm32"))]
+fn response_error(response: &Result<DbResponse>) -> Option<String> {
+	match response {
+		Ok(DbResponse::Query(response)) => response
+			.results
+			.values()
+			.find_map(|(_, result)| result.as_ref().err())
+			.map(ToString::to_string),
+		Ok(DbResponse::Other(_)) => None,
+		Err(error) => Some(error.to_string()),
+	}
+}
+
+/// Passes the errors produced by a request to the error hook
+#[cfg(not(target_arch = "wasm32"))]
+struct ErrorObserver<'a> {
+	hook: &'a crate::opt::ErrorHook,
+	context: crate::opt::QueryContext,
+	// The statements of a query which produce a result, in the order of those results
+	statements: Vec<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<'a> ErrorObserver<'a> {
+	fn new(
+		hook: Option<&'a crate::opt::ErrorHook>,
+		request: &(i64, Method, Param),
+		session: &Session,
+	) -> Option<Self> {
+		let hook = hook?;
+		let (_, method, param) = request;
+		let statements = match (method, &param.query) {
+			(Method::Query | Method::RawQuery | Method::QueryStats, Some((query, _))) => query
+				.iter()
+				.filter(|statement| {
+					!matches!(
+						statement,
+						Statement::Option(_)
+							| Statement::Begin(_) | Statement::Cancel(_)
+							| Statement::Commit(_)
+					)
+				})
+				.map(ToString::to_string)
+				.collect(),
+			_ => Vec::new(),
+		};
+		Some(Self {
+			hook,
+			context: crate::opt::QueryContext {
+				method: method.as_str(),
+				statement: describe_request(request),
+				user: (!session.au.is_anon()).then(|| session.au.id().to_owned()),
+				namespace: session.ns.clone(),
+				database: session.db.clone(),
+			},
+			statements,
+		})
+	}
+
+	fn observe(&self, response: &Result<DbResponse>) {
+		match response {
+			Ok(DbResponse::Query(response)) => {
+				for (index, (_, result)) in &response.results {
+					if let Err(error) = result {
+						let mut context = self.context.clone();
+						if let Some(statement) = self.statements.get(*index) {
+							statement.clone_into(&mut context.statement);
+						}
+						(self.hook.0)(error, &context);
+					}
+				}
+			}
+			Ok(DbResponse::Other(_)) => {}
+			Err(error) => (self.hook.0)(error, &self.context),
+		}
+	}
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 async fn export(
 	kvs: &Datastore,
@@ -512,6 +1282,7 @@ async fn router(
 	session: &mut Session,
 	vars: &mut BTreeMap<String, Value>,
 	live_queries: &mut HashMap<Uuid, Sender<Notification>>,
+	max_result_rows: Option<usize>,
 ) -> Result<DbResponse> {
 	let mut params = param.other;
 
@@ -568,6 +1339,37 @@ async fn router(
 			let value = take(true, response).await?;
 			Ok(DbResponse::Other(value))
 		}
+		Method::CreateIfAbsent => {
+			let what = params.first().cloned().unwrap_or_default();
+			let statement = create_statement(&mut params);
+			let mut retries = 0;
+			loop {
+				// The create either writes the record or fails because it's already there
+				let mut query = Query::default();
+				query.0 .0 = vec![Statement::Create(statement.clone())];
+				let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+				let exists = match take(true, response).await {
+					Ok(record) => return Ok(DbResponse::Other(created_record(true, record))),
+					Err(
+						error @ crate::Error::Db(crate::error::Db::RecordExists {
+							..
+						}),
+					) => error,
+					Err(error) => return Err(error),
+				};
+				let mut query = Query::default();
+				let (_, statement) = select_statement(&mut [what.clone()]);
+				query.0 .0 = vec![Statement::Select(statement)];
+				let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+				match take(true, response).await? {
+					// The record was deleted between the two statements so try creating it again
+					Value::None | Value::Null if retries < CREATE_IF_ABSENT_RETRIES => retries += 1,
+					// The record is there but can't be selected, such as when permissions hide it
+					Value::None | Value::Null => return Err(exists),
+					record => return Ok(DbResponse::Other(created_record(false, record))),
+				}
+			}
+		}
 		Method::Upsert => {
 			let mut query = Query::default();
 			let (one, statement) = upsert_statement(&mut params);
@@ -610,10 +1412,45 @@ async fn router(
 		}
 		Method::Select => {
 			let mut query = Query::default();
-			let (one, statement) = select_statement(&mut params);
+			let (one, mut statement) = select_statement(&mut params);
+			if let Some(max) = max_result_rows {
+				limit_select(&mut statement, max);
+			}
 			query.0 .0 = vec![Statement::Select(statement)];
 			let response = kvs.process(query, &*session, Some(vars.clone())).await?;
 			let value = take(one, response).await?;
+			match max_result_rows {
+				Some(max) if too_many_rows(&value, max) => Err(Error::ResultTooLarge(max).into()),
+				_ => Ok(DbResponse::Other(value)),
+			}
+		}
+		Method::SelectDiff => {
+			let expected = params.pop().unwrap_or_default();
+			let mut query = Query::default();
+			let (one, statement) = select_statement(&mut params);
+			query.0 .0 = vec![Statement::Select(statement)];
+			let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+			let current = match take(one, response).await? {
+				// A record which does not exist has no fields
+				Value::None => Value::Object(Default::default()),
+				// Compare the record as it reads back through serde, the same as the expected state
+				value => to_value(from_value::<serde_json::Value>(value)?)?,
+			};
+			let mut ops = Vec::new();
+			json_patch(&expected, &current, "", &mut ops);
+			Ok(DbResponse::Other(ops.into()))
+		}
+		Method::SelectMeta => {
+			let mut query = Query::default();
+			let (_, statement) = select_statement(&mut params);
+			query.0 .0 = vec![Statement::Select(statement)];
+			let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+			let records = match take(false, response).await? {
+				Value::Array(array) => array.0,
+				Value::None | Value::Null => Vec::new(),
+				value => vec![value],
+			};
+			let value = with_record_meta(kvs, session, records).await?;
 			Ok(DbResponse::Other(value))
 		}
 		Method::Delete => {
@@ -624,18 +1461,101 @@ async fn router(
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
-		Method::Query => {
-			let response = match param.query {
-				Some((query, mut bindings)) => {
+		Method::DeleteWhere => {
+			let mut query = Query::default();
+			let statement = delete_where_statement(&mut params)?;
+			query.0 .0 = vec![Statement::Delete(statement)];
+			let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+			// Only the number of deleted records is sent back
+			let count = match take(false, response).await? {
+				Value::Array(deleted) => deleted.len(),
+				_ => 0,
+			};
+			Ok(DbResponse::Other(count.into()))
+		}
+		Method::RelateMany => {
+			let query = relate_many_query(&mut params);
+			let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+			let value = transaction_result(process(response))?;
+			Ok(DbResponse::Other(value))
+		}
+		Method::UpsertMany => {
+			let query = upsert_many_query(&mut params);
+			let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+			let value = upsert_many_result(process(response))?;
+			Ok(DbResponse::Other(value))
+		}
+		Method::ShowChanges => {
+			let statement = show_changes_statement(&mut params)?;
+			let table = statement.table.unwrap_or_default();
+			// The datastore ensures the table has a change feed
+			let value = kvs.show_changes(session, &table, statement.since, statement.limit).await?;
+			Ok(DbResponse::Other(value))
+		}
+		Method::Query | Method::RawQuery | Method::QueryStats => {
+			let (response, selects) = match param.query {
+				Some((mut query, mut bindings)) => {
+					let selects = max_result_rows.map(|max| limit_selects(&mut query, max));
 					let mut vars = vars.clone();
 					vars.append(&mut bindings);
-					kvs.process(query, &*session, Some(vars)).await?
+					(kvs.process(query, &*session, Some(vars)).await?, selects)
 				}
 				None => unreachable!(),
 			};
-			let response = process(response);
+			let mut response = process(response);
+			if let (Some(max), Some(selects)) = (max_result_rows, selects) {
+				for (index, (_, result)) in response.results.iter_mut() {
+					let select = selects.get(*index).copied().unwrap_or_default();
+					if select && matches!(result, Ok(value) if too_many_rows(value, max)) {
+						*result = Err(Error::ResultTooLarge(max).into());
+					}
+				}
+			}
 			Ok(DbResponse::Query(response))
 		}
+		Method::ExportSchema => {
+			// The schema is small enough to be buffered in full
+			let (tx, rx) = crate::channel::unbounded();
+			kvs.export_schema(session, tx).await?.await?;
+			let mut schema = Vec::new();
+			while let Ok(bytes) = rx.try_recv() {
+				schema.extend(bytes);
+			}
+			Ok(DbResponse::Other(String::from_utf8_lossy(&schema).into_owned().into()))
+		}
+		Method::ExplainCost => {
+			let value = match &param.query {
+				Some((query, bindings)) => {
+					explain_cost(kvs, session, vars, query, bindings).await?
+				}
+				None => unreachable!(),
+			};
+			Ok(DbResponse::Other(value))
+		}
+		Method::IndexStats => {
+			let statement = match &mut params[..] {
+				[Value::Table(table), Value::Strand(index)] => AnalyzeStatement::Idx(
+					mem::take(&mut table.0).into(),
+					mem::take(&mut index.0).into(),
+				),
+				_ => unreachable!(),
+			};
+			let mut query = Query::default();
+			query.0 .0 = vec![Statement::Analyze(statement)];
+			let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+			let value = take(false, response).await?;
+			Ok(DbResponse::Other(value))
+		}
+		Method::Graph => {
+			let (start, path, depth) = match &mut params[..] {
+				[Value::Thing(start), Value::Strand(path), Value::Number(depth)] => {
+					(start.clone(), mem::take(&mut path.0), depth.to_int())
+				}
+				_ => unreachable!(),
+			};
+			let value = select_graph(kvs, session, vars, start, &path, depth).await?;
+			Ok(DbResponse::Other(value))
+		}
 		#[cfg(target_arch = "wasm32")]
 		Method::Export | Method::Import => unreachable!(),
 		#[cfg(not(target_arch = "wasm32"))]
@@ -785,7 +1705,13 @@ async fn router(
 			}
 			Ok(DbResponse::Other(Value::None))
 		}
-		Method::Health => Ok(DbResponse::Other(Value::None)),
+		Method::Health | Method::Ping => Ok(DbResponse::Other(Value::None)),
+		// Batches are run in the background by the native router itself
+		Method::Batch => Err(Error::BatchNotSupported.into()),
+		// Metrics are maintained by the native router itself
+		Method::Metrics => Err(Error::MetricsNotSupported.into()),
+		// Background index builds are started by the native router itself
+		Method::DefineIndexAsync => Err(Error::IndexBuildsNotSupported.into()),
 		Method::Version => Ok(DbResponse::Other(crate::env::VERSION.into())),
 		Method::Set => {
 			let (key, value) = match &mut params[..2] {
@@ -808,6 +1734,24 @@ async fn router(
 			}
 			Ok(DbResponse::Other(Value::None))
 		}
+		Method::Lock => {
+			let (record, ttl, claim) = match &params[..] {
+				[Value::Thing(record), Value::Duration(ttl), Value::Uuid(claim)] => {
+					(record, ttl.0, *claim)
+				}
+				_ => unreachable!(),
+			};
+			let token = kvs.lock_record(&*session, record, ttl, claim).await?;
+			Ok(DbResponse::Other(token.into()))
+		}
+		Method::Unlock => {
+			let (record, claim) = match &params[..] {
+				[Value::Thing(record), Value::Uuid(claim)] => (record, *claim),
+				_ => unreachable!(),
+			};
+			let held = kvs.unlock_record(&*session, record, claim).await?;
+			Ok(DbResponse::Other(held.map(Value::Uuid).unwrap_or_default()))
+		}
 		Method::Live => {
 			if let Some(sender) = param.notification_sender {
 				if let [Value::Uuid(id)] = &params[..1] {
@@ -1,15 +1,22 @@
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
+use crate::api::conn::RouteReceiver;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 use crate::api::engine::local::Db;
+use crate::api::err::Error;
+use crate::api::method::MetricsSnapshot;
+use crate::api::opt::QueryLogEntry;
 use crate::api::opt::{Endpoint, EndpointKind};
 use crate::api::ExtraFeatures;
 use crate::api::OnceLockExt;
 use crate::api::Result;
 use crate::api::Surreal;
+use crate::dbs::Notification;
 use crate::dbs::Session;
 use crate::engine::tasks::start_tasks;
 use crate::iam::Level;
@@ -17,9 +24,15 @@ use crate::kvs::Datastore;
 use crate::opt::auth::Root;
 use crate::opt::WaitFor;
 use crate::options::EngineOptions;
+use crate::sql::statements::DefineStatement;
+use crate::sql::to_value;
+use crate::sql::Query;
+use crate::sql::Statement;
+use crate::sql::Uuid;
+use crate::sql::Value;
+use chrono::Utc;
 use flume::Receiver;
 use flume::Sender;
-use futures::future::Either;
 use futures::stream::poll_fn;
 use futures::StreamExt;
 use futures_concurrency::stream::Merge as _;
@@ -27,12 +40,16 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::future::Future;
+use std::mem;
 use std::pin::Pin;
 use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use std::task::Poll;
+use std::time::Instant;
 use tokio::sync::watch;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
 
 impl crate::api::Connection for Db {}
 
@@ -48,10 +65,8 @@ impl Connection for Db {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
-			let (route_tx, route_rx) = match capacity {
-				0 => flume::unbounded(),
-				capacity => flume::bounded(capacity),
-			};
+			let statement_cache = StatementCache::new(address.config.statement_cache_size);
+			let (route_tx, route_rx) = route_channel(capacity);
 
 			let (conn_tx, conn_rx) = flume::bounded(1);
 
@@ -61,13 +76,25 @@ impl Connection for Db {
 
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::Backup);
+			features.insert(ExtraFeatures::Batch);
+			features.insert(ExtraFeatures::IndexBuilds);
 			features.insert(ExtraFeatures::LiveQueries);
+			features.insert(ExtraFeatures::Metrics);
+			features.insert(ExtraFeatures::CreateIfAbsent);
+			features.insert(ExtraFeatures::ExportSchema);
+			features.insert(ExtraFeatures::Graph);
+			features.insert(ExtraFeatures::QueryStats);
+			features.insert(ExtraFeatures::ExplainCost);
+			features.insert(ExtraFeatures::IndexStats);
+			features.insert(ExtraFeatures::RecordMeta);
+			features.insert(ExtraFeatures::SelectDiff);
 
 			Ok(Surreal::new_from_router_waiter(
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					statement_cache,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -91,11 +118,198 @@ impl Connection for Db {
 	}
 }
 
-pub(crate) fn router(
-	address: Endpoint,
-	conn_tx: Sender<Result<()>>,
-	route_rx: Receiver<Option<Route>>,
-) {
+/// Runs a query, returning the result of its last statement or its first error
+async fn run(
+	kvs: &Datastore,
+	query: Query,
+	session: &Session,
+	vars: BTreeMap<String, Value>,
+) -> Result<Value> {
+	let mut value = Value::None;
+	for response in kvs.process(query, session, Some(vars)).await? {
+		value = response.result?;
+	}
+	Ok(value)
+}
+
+fn start_batch(
+	mut param: Param,
+	kvs: &Arc<Datastore>,
+	session: &Session,
+	vars: &BTreeMap<String, Value>,
+	slots: &Option<Arc<Semaphore>>,
+) -> Result<DbResponse> {
+	let (Some(sender), [Value::Array(queries), Value::Number(parallelism)]) =
+		(param.batch_sender, &mut param.other[..])
+	else {
+		unreachable!();
+	};
+	let queries = mem::take(queries);
+	let parallelism = parallelism.to_usize().max(1);
+	let kvs = kvs.clone();
+	let session = session.clone();
+	let vars = vars.clone();
+	let slots = slots.clone();
+	tokio::spawn(async move {
+		let mut results = futures::stream::iter(queries.into_iter().enumerate())
+			.map(|(index, query)| {
+				let (kvs, session, vars, slots) = (&kvs, &session, vars.clone(), &slots);
+				async move {
+					let _slot = acquire(slots).await;
+					let result = match crate::syn::parse(&query.as_raw_string()) {
+						Ok(query) => run(kvs, query, session, vars).await,
+						Err(error) => Err(error.into()),
+					};
+					(index, result)
+				}
+			})
+			.buffer_unordered(parallelism);
+		while let Some(result) = results.next().await {
+			if sender.send(result).await.is_err() {
+				break;
+			}
+		}
+	});
+	Ok(DbResponse::Other(Value::None))
+}
+
+/// Waits for a free query slot, if the number of concurrent queries is limited
+async fn acquire(slots: &Option<Arc<Semaphore>>) -> Option<OwnedSemaphorePermit> {
+	match slots {
+		// The semaphore is never closed
+		Some(slots) => slots.clone().acquire_owned().await.ok(),
+		None => None,
+	}
+}
+
+fn start_index_build(
+	param: Param,
+	kvs: &Arc<Datastore>,
+	session: &Session,
+	slots: &Option<Arc<Semaphore>>,
+) -> Result<DbResponse> {
+	let (Some((mut query, _)), Some(sender)) = (param.query, param.index_build_sender) else {
+		unreachable!();
+	};
+	let index = match &mut query.0 .0[..] {
+		[Statement::Define(DefineStatement::Index(index))] => mem::take(index),
+		_ => {
+			return Err(Error::InvalidParams(
+				"Expected a single `DEFINE INDEX` statement".to_owned(),
+			)
+			.into())
+		}
+	};
+	let kvs = kvs.clone();
+	let session = session.clone();
+	let slots = slots.clone();
+	tokio::spawn(async move {
+		let _slot = acquire(&slots).await;
+		// Writes made to the table while the index is built are caught up on by the datastore
+		let result = kvs.build_index(index, &session).await.map_err(Into::into);
+		let _ = sender.send(result).await;
+	});
+	Ok(DbResponse::Other(Value::None))
+}
+
+/// What the router of a connection reacts to
+enum Event {
+	Route(Option<Route>),
+	Finished(u64, Result<DbResponse>),
+	Notification(Notification),
+}
+
+/// Whether a request leaves the session, variables and live queries of the connection
+/// untouched, so it can be executed in the background
+///
+/// Requests which change the session or start or kill live queries always run on the event loop,
+/// so every request sent after them sees their changes. Requests running in the background use
+/// the session and variables as they were when the request was received, and are answered as
+/// soon as they finish.
+fn runs_in_background((_, method, param): &(i64, Method, Param)) -> bool {
+	match method {
+		Method::Query | Method::RawQuery | Method::QueryStats => {
+			param.query.as_ref().map_or(true, |(query, _)| {
+				!query
+					.0
+					 .0
+					.iter()
+					.any(|statement| matches!(statement, Statement::Live(_) | Statement::Kill(_)))
+			})
+		}
+		Method::Create
+		| Method::CreateIfAbsent
+		| Method::Upsert
+		| Method::UpsertMany
+		| Method::Update
+		| Method::Insert
+		| Method::Patch
+		| Method::Merge
+		| Method::Select
+		| Method::SelectDiff
+		| Method::SelectMeta
+		| Method::Delete
+		| Method::DeleteWhere
+		| Method::RelateMany
+		| Method::ShowChanges
+		| Method::ExplainCost
+		| Method::IndexStats
+		| Method::Graph
+		| Method::Lock
+		| Method::Unlock => true,
+		_ => false,
+	}
+}
+
+/// Executes a request which is within the limits checked before it is parsed
+async fn execute(
+	request: (i64, Method, Param),
+	kvs: &Arc<Datastore>,
+	session: &mut Session,
+	vars: &mut BTreeMap<String, Value>,
+	live_queries: &mut HashMap<Uuid, channel::Sender<Notification>>,
+	full_scan_threshold: Option<u64>,
+	max_result_rows: Option<usize>,
+) -> Result<DbResponse> {
+	super::deny_full_scans(&request, kvs, session, vars, full_scan_threshold).await?;
+	super::router(request, kvs, session, vars, live_queries, max_result_rows).await
+}
+
+/// A request which is executing, waiting to be answered
+struct Pending<'a> {
+	method: Method,
+	errors: Option<super::ErrorObserver<'a>>,
+	log_entry: Option<QueryLogEntry>,
+	started: Instant,
+	sender: Sender<Result<DbResponse>>,
+}
+
+impl Pending<'_> {
+	/// Records the response of the request and sends it back
+	async fn finish(
+		self,
+		response: Result<DbResponse>,
+		metrics: &mut MetricsSnapshot,
+		session_expiry: &mut Option<super::SessionExpiry>,
+		query_log: &Option<Sender<QueryLogEntry>>,
+	) {
+		if let Some(errors) = &self.errors {
+			errors.observe(&response);
+		}
+		if let (Some(log_tx), Some(mut entry)) = (query_log, self.log_entry) {
+			entry.duration = self.started.elapsed();
+			entry.error = super::response_error(&response);
+			let _ = log_tx.send(entry);
+		}
+		metrics.record(&response, self.started.elapsed());
+		if let Some(expiry) = session_expiry {
+			expiry.record(self.method, &response);
+		}
+		let _ = self.sender.into_send_async(response).await;
+	}
+}
+
+pub(crate) fn router(address: Endpoint, conn_tx: Sender<Result<()>>, route_rx: RouteReceiver) {
 	tokio::spawn(async move {
 		let configured_root = match address.config.auth {
 			Level::Root => Some(Root {
@@ -105,12 +319,33 @@ pub(crate) fn router(
 			_ => None,
 		};
 
+		let timezone = match address.config.resolve_timezone() {
+			Ok(timezone) => timezone,
+			Err(error) => {
+				let _ = conn_tx.into_send_async(Err(error)).await;
+				return;
+			}
+		};
+
 		let endpoint = match EndpointKind::from(address.url.scheme()) {
 			EndpointKind::TiKv => address.url.as_str(),
 			_ => &address.path,
 		};
 
-		let kvs = match Datastore::new(endpoint).await {
+		let kvs = match super::open_datastore(endpoint, address.config.connect_retry).await {
+			Ok(kvs) => match address.config.isolation {
+				Some(level) => kvs.with_isolation(level).await,
+				None => Ok(kvs),
+			},
+			Err(error) => Err(error),
+		};
+
+		let kvs = match (kvs, address.config.flush_interval) {
+			(Ok(kvs), Some(interval)) => kvs.with_flush_interval(interval),
+			(kvs, _) => kvs,
+		};
+
+		let kvs = match kvs {
 			Ok(kvs) => {
 				if let Err(error) = kvs.bootstrap().await {
 					let _ = conn_tx.into_send_async(Err(error.into())).await;
@@ -142,7 +377,9 @@ pub(crate) fn router(
 			.with_strict_mode(address.config.strict)
 			.with_query_timeout(address.config.query_timeout)
 			.with_transaction_timeout(address.config.transaction_timeout)
-			.with_capabilities(address.config.capabilities);
+			.with_timezone(timezone)
+			.with_capabilities(address.config.resolve_capabilities())
+			.with_guest_permissions(address.config.guest_permissions.clone());
 
 		#[cfg(any(
 			feature = "kv-mem",
@@ -160,6 +397,33 @@ pub(crate) fn router(
 		let mut vars = BTreeMap::new();
 		let mut live_queries = HashMap::new();
 		let mut session = Session::default().with_rt(true);
+		let mut metrics = MetricsSnapshot::default();
+		let mut session_expiry = address.config.session_expiry.map(super::SessionExpiry::new);
+		let deny_guests = address.config.allow_guest == Some(false);
+		let max_result_rows = address.config.max_result_rows;
+		let max_statements = address.config.max_statements_per_query;
+		let max_bound_params = address.config.max_bound_params;
+		let experimental = address.config.experimental;
+		let query_slots = match address.config.max_concurrent_queries {
+			0 => None,
+			n => Some(Arc::new(Semaphore::new(n))),
+		};
+		let full_scan_threshold = address.config.deny_full_scans.then(|| {
+			address.config.full_scan_threshold.unwrap_or(super::DEFAULT_FULL_SCAN_THRESHOLD)
+		});
+
+		let error_hook = address.config.error_hook;
+
+		// Log entries are handed to the sink on another thread so it can't stall this loop
+		let query_log = address.config.query_log.map(|sink| {
+			let (log_tx, log_rx) = flume::unbounded::<QueryLogEntry>();
+			tokio::task::spawn_blocking(move || {
+				while let Ok(entry) = log_rx.recv() {
+					(sink.0)(entry);
+				}
+			});
+			log_tx
+		});
 
 		let opt = {
 			let mut engine_options = EngineOptions::default();
@@ -177,31 +441,144 @@ pub(crate) fn router(
 			None => Poll::Ready(None),
 		});
 
-		let streams = (route_rx.stream().map(Either::Left), notification_stream.map(Either::Right));
+		// Requests running in the background are answered here once they finish
+		let (finished_tx, finished_rx) = flume::unbounded();
+		let mut running = HashMap::new();
+		let mut next_pending = 0_u64;
+
+		let streams = (
+			route_rx.map(Event::Route),
+			finished_rx.into_stream().map(|(id, response)| Event::Finished(id, response)),
+			notification_stream.map(Event::Notification),
+		);
 		let mut merged = streams.merge();
 
-		while let Some(either) = merged.next().await {
-			match either {
-				Either::Left(None) => break, // Received a shutdown signal
-				Either::Left(Some(route)) => {
-					match super::router(
-						route.request,
-						&kvs,
-						&mut session,
-						&mut vars,
-						&mut live_queries,
-					)
-					.await
-					{
-						Ok(value) => {
-							let _ = route.response.into_send_async(Ok(value)).await;
+		while let Some(event) = merged.next().await {
+			match event {
+				Event::Route(None) => break, // Received a shutdown signal
+				Event::Route(Some(route)) => {
+					// Metrics are answered here as they are maintained by this router
+					if let (_, Method::Metrics, _) = route.request {
+						let response = to_value(metrics).map(DbResponse::Other).map_err(Into::into);
+						let _ = route.response.into_send_async(response).await;
+						continue;
+					}
+					let method = route.request.1;
+					let errors =
+						super::ErrorObserver::new(error_hook.as_ref(), &route.request, &session);
+					let observe = |response: &Result<DbResponse>| {
+						if let Some(errors) = &errors {
+							errors.observe(response);
 						}
-						Err(error) => {
-							let _ = route.response.into_send_async(Err(error)).await;
+					};
+					if let Some(expiry) = &mut session_expiry {
+						if let Err(error) = expiry.check(method, &mut session) {
+							let response = Err(error);
+							observe(&response);
+							let _ = route.response.into_send_async(response).await;
+							continue;
 						}
 					}
+					let denied = super::deny_guests(&route.request, &session, deny_guests)
+						.and_then(|()| super::deny_experimental(&route.request, &experimental));
+					if let Err(error) = denied {
+						let response = Err(error);
+						observe(&response);
+						let _ = route.response.into_send_async(response).await;
+						continue;
+					}
+					// Batches run in the background so their results can be streamed back
+					if let (_, Method::Batch, param) = route.request {
+						let response = start_batch(param, &kvs, &session, &vars, &query_slots);
+						observe(&response);
+						let _ = route.response.into_send_async(response).await;
+						continue;
+					}
+					// Index builds run in the background so they don't block other requests
+					if let (_, Method::DefineIndexAsync, param) = route.request {
+						let response = start_index_build(param, &kvs, &session, &query_slots);
+						observe(&response);
+						let _ = route.response.into_send_async(response).await;
+						continue;
+					}
+					let log_entry = query_log.as_ref().map(|_| QueryLogEntry {
+						statement: super::describe_request(&route.request),
+						user: (!session.au.is_anon()).then(|| session.au.id().to_owned()),
+						namespace: session.ns.clone(),
+						database: session.db.clone(),
+						started_at: Utc::now().into(),
+						duration: Default::default(),
+						error: None,
+					});
+					let pending = Pending {
+						method,
+						errors,
+						log_entry,
+						started: Instant::now(),
+						sender: route.response,
+					};
+					let request = route.request;
+					let limits = super::limit_statements(&request, max_statements)
+						.and_then(|()| super::limit_bound_params(&request, max_bound_params));
+					if let Err(error) = limits {
+						pending
+							.finish(Err(error), &mut metrics, &mut session_expiry, &query_log)
+							.await;
+						continue;
+					}
+					match &query_slots {
+						// Queries run in the background so this loop never waits for a slot
+						Some(slots) if runs_in_background(&request) => {
+							let id = next_pending;
+							next_pending += 1;
+							running.insert(id, pending);
+							let slots = slots.clone();
+							let kvs = kvs.clone();
+							let mut session = session.clone();
+							let mut vars = vars.clone();
+							let finished_tx = finished_tx.clone();
+							tokio::spawn(async move {
+								// The semaphore is never closed
+								let _slot = slots.acquire_owned().await.ok();
+								// Live queries are never started in the background
+								let response = execute(
+									request,
+									&kvs,
+									&mut session,
+									&mut vars,
+									&mut HashMap::new(),
+									full_scan_threshold,
+									max_result_rows,
+								)
+								.await;
+								let _ = finished_tx.send((id, response));
+							});
+						}
+						_ => {
+							let response = execute(
+								request,
+								&kvs,
+								&mut session,
+								&mut vars,
+								&mut live_queries,
+								full_scan_threshold,
+								max_result_rows,
+							)
+							.await;
+							pending
+								.finish(response, &mut metrics, &mut session_expiry, &query_log)
+								.await;
+						}
+					}
+				}
+				Event::Finished(id, response) => {
+					if let Some(pending) = running.remove(&id) {
+						pending
+							.finish(response, &mut metrics, &mut session_expiry, &query_log)
+							.await;
+					}
 				}
-				Either::Right(notification) => {
+				Event::Notification(notification) => {
 					let id = notification.id;
 					if let Some(sender) = live_queries.get(&id) {
 						if sender.send(notification).await.is_err() {
@@ -1,9 +1,12 @@
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
+use crate::api::conn::RouteReceiver;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 use crate::api::engine::local::Db;
 use crate::api::engine::local::DEFAULT_TICK_INTERVAL;
 use crate::api::opt::Endpoint;
@@ -51,10 +54,8 @@ impl Connection for Db {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
-			let (route_tx, route_rx) = match capacity {
-				0 => flume::unbounded(),
-				capacity => flume::bounded(capacity),
-			};
+			let statement_cache = StatementCache::new(address.config.statement_cache_size);
+			let (route_tx, route_rx) = route_channel(capacity);
 
 			let (conn_tx, conn_rx) = flume::bounded(1);
 
@@ -64,12 +65,21 @@ impl Connection for Db {
 
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::LiveQueries);
+			features.insert(ExtraFeatures::CreateIfAbsent);
+			features.insert(ExtraFeatures::ExportSchema);
+			features.insert(ExtraFeatures::Graph);
+			features.insert(ExtraFeatures::QueryStats);
+			features.insert(ExtraFeatures::ExplainCost);
+			features.insert(ExtraFeatures::IndexStats);
+			features.insert(ExtraFeatures::RecordMeta);
+			features.insert(ExtraFeatures::SelectDiff);
 
 			Ok(Surreal::new_from_router_waiter(
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					statement_cache,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -93,11 +103,7 @@ impl Connection for Db {
 	}
 }
 
-pub(crate) fn router(
-	address: Endpoint,
-	conn_tx: Sender<Result<()>>,
-	route_rx: Receiver<Option<Route>>,
-) {
+pub(crate) fn router(address: Endpoint, conn_tx: Sender<Result<()>>, route_rx: RouteReceiver) {
 	spawn_local(async move {
 		let configured_root = match address.config.auth {
 			Level::Root => Some(Root {
@@ -107,7 +113,28 @@ pub(crate) fn router(
 			_ => None,
 		};
 
-		let kvs = match Datastore::new(&address.path).await {
+		let timezone = match address.config.resolve_timezone() {
+			Ok(timezone) => timezone,
+			Err(error) => {
+				let _ = conn_tx.into_send_async(Err(error)).await;
+				return;
+			}
+		};
+
+		let kvs = match super::open_datastore(&address.path, address.config.connect_retry).await {
+			Ok(kvs) => match address.config.isolation {
+				Some(level) => kvs.with_isolation(level).await,
+				None => Ok(kvs),
+			},
+			Err(error) => Err(error),
+		};
+
+		let kvs = match (kvs, address.config.flush_interval) {
+			(Ok(kvs), Some(interval)) => kvs.with_flush_interval(interval),
+			(kvs, _) => kvs,
+		};
+
+		let kvs = match kvs {
 			Ok(kvs) => {
 				if let Err(error) = kvs.bootstrap().await {
 					let _ = conn_tx.into_send_async(Err(error.into())).await;
@@ -139,12 +166,22 @@ pub(crate) fn router(
 			.with_strict_mode(address.config.strict)
 			.with_query_timeout(address.config.query_timeout)
 			.with_transaction_timeout(address.config.transaction_timeout)
-			.with_capabilities(address.config.capabilities);
+			.with_timezone(timezone)
+			.with_capabilities(address.config.resolve_capabilities())
+			.with_guest_permissions(address.config.guest_permissions.clone());
 
 		let kvs = Arc::new(kvs);
 		let mut vars = BTreeMap::new();
 		let mut live_queries = HashMap::new();
 		let mut session = Session::default().with_rt(true);
+		let deny_guests = address.config.allow_guest == Some(false);
+		let max_result_rows = address.config.max_result_rows;
+		let max_statements = address.config.max_statements_per_query;
+		let max_bound_params = address.config.max_bound_params;
+		let experimental = address.config.experimental;
+		let full_scan_threshold = address.config.deny_full_scans.then(|| {
+			address.config.full_scan_threshold.unwrap_or(super::DEFAULT_FULL_SCAN_THRESHOLD)
+		});
 
 		let mut opt = EngineOptions::default();
 		opt.tick_interval = address.config.tick_interval.unwrap_or(DEFAULT_TICK_INTERVAL);
@@ -156,22 +193,45 @@ pub(crate) fn router(
 			None => Poll::Ready(None),
 		});
 
-		let streams = (route_rx.stream().map(Either::Left), notification_stream.map(Either::Right));
+		let streams = (route_rx.map(Either::Left), notification_stream.map(Either::Right));
 		let mut merged = streams.merge();
 
 		while let Some(either) = merged.next().await {
 			match either {
 				Either::Left(None) => break, // Received a shutdown signal
 				Either::Left(Some(route)) => {
-					match super::router(
-						route.request,
-						&kvs,
-						&mut session,
-						&mut vars,
-						&mut live_queries,
-					)
-					.await
-					{
+					let limits = super::deny_guests(&route.request, &session, deny_guests)
+						.and_then(|()| super::deny_experimental(&route.request, &experimental))
+						.and_then(|()| super::limit_statements(&route.request, max_statements))
+						.and_then(|()| super::limit_bound_params(&route.request, max_bound_params));
+					let limits = match limits {
+						Ok(()) => {
+							super::deny_full_scans(
+								&route.request,
+								&kvs,
+								&session,
+								&vars,
+								full_scan_threshold,
+							)
+							.await
+						}
+						Err(error) => Err(error),
+					};
+					let response = match limits {
+						Ok(()) => {
+							super::router(
+								route.request,
+								&kvs,
+								&mut session,
+								&mut vars,
+								&mut live_queries,
+								max_result_rows,
+							)
+							.await
+						}
+						Err(error) => Err(error),
+					};
+					match response {
 						Ok(value) => {
 							let _ = route.response.into_send_async(Ok(value)).await;
 						}
@@ -15,18 +15,29 @@ pub mod remote;
 #[doc(hidden)]
 pub mod tasks;
 
+use crate::api::Response;
+use crate::sql::statements::BeginStatement;
+use crate::sql::statements::CommitStatement;
 use crate::sql::statements::CreateStatement;
 use crate::sql::statements::DeleteStatement;
 use crate::sql::statements::InsertStatement;
+use crate::sql::statements::RelateStatement;
 use crate::sql::statements::SelectStatement;
+use crate::sql::statements::ShowSince;
+use crate::sql::statements::ShowStatement;
 use crate::sql::statements::UpdateStatement;
 use crate::sql::statements::UpsertStatement;
+use crate::sql::Cond;
 use crate::sql::Data;
 use crate::sql::Field;
 use crate::sql::Output;
+use crate::sql::Query;
+use crate::sql::Statement;
+use crate::sql::Table;
 use crate::sql::Value;
 use crate::sql::Values;
 use futures::Stream;
+use std::collections::BTreeMap;
 use std::mem;
 use std::pin::Pin;
 use std::task::Context;
@@ -160,6 +171,27 @@ fn select_statement(params: &mut [Value]) -> (bool, SelectStatement) {
 	(one, stmt)
 }
 
+#[allow(dead_code)] // used by the the embedded database
+fn show_changes_statement(params: &mut [Value]) -> crate::Result<ShowStatement> {
+	let (table, since, limit) = match params {
+		[Value::Table(table), since, limit] => {
+			(mem::take(table), mem::take(since), mem::take(limit))
+		}
+		_ => unreachable!(),
+	};
+	let mut stmt = ShowStatement::default();
+	stmt.table = Some(table);
+	stmt.since = match since {
+		Value::None | Value::Null => ShowSince::default(),
+		since => since.try_into()?,
+	};
+	stmt.limit = match limit {
+		Value::None | Value::Null => None,
+		limit => Some(limit.try_into()?),
+	};
+	Ok(stmt)
+}
+
 #[allow(dead_code)] // used by the the embedded database and `http`
 fn delete_statement(params: &mut [Value]) -> (bool, DeleteStatement) {
 	let (one, what, _) = split_params(params);
@@ -169,6 +201,146 @@ fn delete_statement(params: &mut [Value]) -> (bool, DeleteStatement) {
 	(one, stmt)
 }
 
+#[allow(dead_code)] // used by the the embedded database
+fn delete_where_statement(params: &mut [Value]) -> crate::Result<DeleteStatement> {
+	let (table, condition) = match params {
+		[Value::Table(table), Value::Strand(condition)] => {
+			(mem::take(table), mem::take(&mut condition.0))
+		}
+		_ => unreachable!(),
+	};
+	let mut stmt = DeleteStatement::default();
+	stmt.what.0 = vec![Value::Table(table)];
+	// Parsing the condition on its own ensures it is a single expression
+	let mut cond = Cond::default();
+	cond.0 = crate::syn::value(&condition)?;
+	stmt.cond = Some(cond);
+	// Each deleted record is returned as a null, which is all it takes to count them
+	stmt.output = Some(Output::Null);
+	Ok(stmt)
+}
+
+#[allow(dead_code)] // used by the the embedded database
+fn relate_many_query(params: &mut [Value]) -> Query {
+	let edges = match params {
+		[Value::Array(edges)] => mem::take(&mut edges.0),
+		_ => unreachable!(),
+	};
+	let mut statements = vec![Statement::Begin(BeginStatement::default())];
+	for mut edge in edges {
+		let Value::Array(edge) = &mut edge else {
+			unreachable!();
+		};
+		let (from, kind, with, data) = match &mut edge[..] {
+			[from, Value::Strand(kind), with, data] => {
+				(mem::take(from), mem::take(&mut kind.0), mem::take(with), mem::take(data))
+			}
+			_ => unreachable!(),
+		};
+		let mut stmt = RelateStatement::default();
+		stmt.only = true;
+		stmt.from = from;
+		stmt.kind = Value::Table(Table::from(kind));
+		stmt.with = with;
+		stmt.data = match data {
+			Value::None | Value::Null => None,
+			value => Some(Data::ContentExpression(value)),
+		};
+		stmt.output = Some(Output::After);
+		statements.push(Statement::Relate(stmt));
+	}
+	statements.push(Statement::Commit(CommitStatement::default()));
+	let mut query = Query::default();
+	query.0 .0 = statements;
+	query
+}
+
+#[allow(dead_code)] // used by the the embedded database
+fn upsert_many_query(params: &mut [Value]) -> Query {
+	let records = match params {
+		[Value::Array(records)] => mem::take(&mut records.0),
+		_ => unreachable!(),
+	};
+	let mut statements = vec![Statement::Begin(BeginStatement::default())];
+	for mut record in records {
+		let Value::Array(record) = &mut record else {
+			unreachable!();
+		};
+		let (id, data) = match &mut record[..] {
+			[id, data] => (mem::take(id), mem::take(data)),
+			_ => unreachable!(),
+		};
+		// The record before the upsert tells whether the upsert created it
+		let mut upsert = UpsertStatement::default();
+		upsert.only = true;
+		upsert.what.0 = vec![id.clone()];
+		upsert.data = match data {
+			Value::None | Value::Null => None,
+			value => Some(Data::ContentExpression(value)),
+		};
+		upsert.output = Some(Output::Before);
+		statements.push(Statement::Upsert(upsert));
+		let mut select = SelectStatement::default();
+		select.only = true;
+		select.what.0 = vec![id];
+		select.expr.0 = vec![Field::All];
+		statements.push(Statement::Select(select));
+	}
+	statements.push(Statement::Commit(CommitStatement::default()));
+	let mut query = Query::default();
+	query.0 .0 = statements;
+	query
+}
+
+/// Pairs the record returned from before each upsert of an `upsert_many` query with the record read after it
+#[allow(dead_code)] // used by the the embedded database
+fn upsert_many_result(response: Response) -> crate::Result<Value> {
+	let Value::Array(results) = transaction_result(response)? else {
+		unreachable!();
+	};
+	let mut results = results.into_iter();
+	let mut records = Vec::new();
+	while let (Some(before), Some(after)) = (results.next(), results.next()) {
+		let mut record = BTreeMap::new();
+		record.insert("id".to_owned(), after.rid());
+		record.insert("created".to_owned(), before.is_none().into());
+		record.insert("record".to_owned(), after);
+		records.push(Value::from(record));
+	}
+	Ok(records.into())
+}
+
+/// Collects the results of a query run in a single transaction, or the error which rolled it back
+#[allow(dead_code)] // used by the the embedded database
+fn transaction_result(response: Response) -> crate::Result<Value> {
+	// Every statement of a failed transaction errors, but only one of them caused the failure
+	let not_executed = |error: &crate::Error| {
+		matches!(
+			error,
+			crate::Error::Db(
+				crate::error::Db::QueryNotExecuted
+					| crate::error::Db::QueryNotExecutedDetail { .. }
+			)
+		)
+	};
+	let mut values = Vec::new();
+	let mut failure: Option<crate::Error> = None;
+	for (_, (_, result)) in response.results {
+		match result {
+			Ok(value) => values.push(value),
+			Err(error) => {
+				if failure.as_ref().map_or(true, |f| not_executed(f) && !not_executed(&error)) {
+					failure = Some(error);
+				}
+			}
+		}
+	}
+	match failure {
+		Some(error) => Err(error),
+		None => Ok(values.into()),
+	}
+}
+
 struct IntervalStream {
 	inner: Interval,
 }
@@ -35,6 +35,7 @@ use crate::method::Stats;
 use crate::opt::IntoEndpoint;
 use crate::sql::from_value;
 use crate::sql::serde::deserialize;
+use crate::sql::serde::serialize;
 use crate::sql::Value;
 #[cfg(not(target_arch = "wasm32"))]
 use futures::TryStreamExt;
@@ -42,7 +43,6 @@ use indexmap::IndexMap;
 use reqwest::header::HeaderMap;
 use reqwest::header::HeaderValue;
 use reqwest::header::ACCEPT;
-#[cfg(not(target_arch = "wasm32"))]
 use reqwest::header::CONTENT_TYPE;
 use reqwest::RequestBuilder;
 use serde::Deserialize;
@@ -59,7 +59,9 @@ use tokio::io;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use url::Url;
 
+const RPC_PATH: &str = "rpc";
 const SQL_PATH: &str = "sql";
+const STATUS_PATH: &str = "status";
 
 /// The HTTP scheme used to connect to `http://` endpoints
 #[derive(Debug)]
@@ -200,6 +202,7 @@ async fn query(request: RequestBuilder) -> Result<QueryResponse> {
 	for (index, (execution_time, status, value)) in responses.into_iter().enumerate() {
 		let stats = Stats {
 			execution_time: duration_from_str(&execution_time),
+			records_examined: None,
 		};
 		match status {
 			Status::Ok => {
@@ -218,6 +221,29 @@ async fn query(request: RequestBuilder) -> Result<QueryResponse> {
 	})
 }
 
+async fn rpc(request: RequestBuilder) -> Result<Value> {
+	let response = request.send().await?.error_for_status()?;
+	let bytes = response.bytes().await?;
+	let response: Value = deserialize(&bytes).map_err(|error| Error::ResponseFromBinary {
+		binary: bytes.to_vec(),
+		error,
+	})?;
+	match response {
+		Value::Object(mut response) => match response.remove("error") {
+			Some(Value::Object(mut error)) => {
+				let message = error.remove("message").unwrap_or_default();
+				Err(Error::Query(message.as_raw_string()).into())
+			}
+			_ => Ok(response.remove("result").unwrap_or_default()),
+		},
+		value => Err(Error::FromValue {
+			value,
+			error: String::from("invalid RPC response"),
+		}
+		.into()),
+	}
+}
+
 async fn take(one: bool, request: RequestBuilder) -> Result<Value> {
 	if let Some((_stats, result)) = query(request).await?.results.swap_remove(&0) {
 		let value = result?;
@@ -522,7 +548,31 @@ async fn router(
 			let value = take(one, request).await?;
 			Ok(DbResponse::Other(value))
 		}
-		Method::Query => {
+		// Locks are only taken over RPC
+		// The RPC endpoint also checks that the table has a change feed
+		// Bulk writes report the error which rolled back their transaction over RPC
+		// Deleting by condition sends back only a count over RPC
+		Method::Lock
+		| Method::Unlock
+		| Method::ShowChanges
+		| Method::RelateMany
+		| Method::UpsertMany
+		| Method::DeleteWhere => {
+			let path = base_url.join(RPC_PATH)?;
+			let body = Value::from(crate::map! {
+				String::from("method") => Value::from(method.as_str()),
+				String::from("params") => Value::from(mem::take(&mut params)),
+			});
+			let request = client
+				.post(path)
+				.headers(headers.clone())
+				.header(CONTENT_TYPE, "application/surrealdb")
+				.auth(auth)
+				.body(serialize(&body).map_err(|error| crate::Error::Db(error.into()))?);
+			let value = rpc(request).await?;
+			Ok(DbResponse::Other(value))
+		}
+		Method::Query | Method::RawQuery => {
 			let path = base_url.join(SQL_PATH)?;
 			let mut request = client.post(path).headers(headers.clone()).query(&vars).auth(auth);
 			match param.query {
@@ -572,6 +622,23 @@ async fn router(
 			let value = import(request, file).await?;
 			Ok(DbResponse::Other(value))
 		}
+		Method::Batch => Err(Error::BatchNotSupported.into()),
+		Method::Metrics => Err(Error::MetricsNotSupported.into()),
+		Method::DefineIndexAsync => Err(Error::IndexBuildsNotSupported.into()),
+		Method::SelectMeta => Err(Error::RecordMetaNotSupported.into()),
+		Method::CreateIfAbsent => Err(Error::CreateIfAbsentNotSupported.into()),
+		Method::ExportSchema => Err(Error::ExportSchemaNotSupported.into()),
+		Method::IndexStats => Err(Error::IndexStatsNotSupported.into()),
+		Method::ExplainCost => Err(Error::ExplainCostNotSupported.into()),
+		Method::SelectDiff => Err(Error::SelectDiffNotSupported.into()),
+		Method::Graph => Err(Error::GraphNotSupported.into()),
+		Method::QueryStats => Err(Error::QueryStatsNotSupported.into()),
+		Method::Ping => {
+			let path = base_url.join(STATUS_PATH)?;
+			let request = client.get(path);
+			let value = health(request).await?;
+			Ok(DbResponse::Other(value))
+		}
 		Method::Health => {
 			let path = base_url.join(Method::Health.as_str())?;
 			let request = client.get(path);
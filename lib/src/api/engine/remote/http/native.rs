@@ -1,10 +1,13 @@
 use super::Client;
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
+use crate::api::conn::RouteReceiver;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 use crate::api::opt::Endpoint;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 use crate::api::opt::Tls;
@@ -41,13 +44,14 @@ impl Connection for Client {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
+			let statement_cache = StatementCache::new(address.config.statement_cache_size);
 			let headers = super::default_headers();
 
 			#[allow(unused_mut)]
 			let mut builder = ClientBuilder::new().default_headers(headers);
 
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
-			if let Some(tls) = address.config.tls_config {
+			if let Some(tls) = address.config.tls_connector()? {
 				builder = match tls {
 					#[cfg(feature = "native-tls")]
 					Tls::Native(config) => builder.use_preconfigured_tls(config),
@@ -62,10 +66,7 @@ impl Connection for Client {
 
 			super::health(client.get(base_url.join(Method::Health.as_str())?)).await?;
 
-			let (route_tx, route_rx) = match capacity {
-				0 => flume::unbounded(),
-				capacity => flume::bounded(capacity),
-			};
+			let (route_tx, route_rx) = route_channel(capacity);
 
 			router(base_url, client, route_rx);
 
@@ -77,6 +78,7 @@ impl Connection for Client {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					statement_cache,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -100,12 +102,12 @@ impl Connection for Client {
 	}
 }
 
-pub(crate) fn router(base_url: Url, client: reqwest::Client, route_rx: Receiver<Option<Route>>) {
+pub(crate) fn router(base_url: Url, client: reqwest::Client, route_rx: RouteReceiver) {
 	tokio::spawn(async move {
 		let mut headers = HeaderMap::new();
 		let mut vars = IndexMap::new();
 		let mut auth = None;
-		let mut stream = route_rx.into_stream();
+		let mut stream = route_rx;
 
 		while let Some(Some(route)) = stream.next().await {
 			let result = super::router(
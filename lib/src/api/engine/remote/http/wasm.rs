@@ -1,10 +1,13 @@
 use super::Client;
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
+use crate::api::conn::RouteReceiver;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 use crate::api::opt::Endpoint;
 use crate::api::OnceLockExt;
 use crate::api::Result;
@@ -41,10 +44,8 @@ impl Connection for Client {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
-			let (route_tx, route_rx) = match capacity {
-				0 => flume::unbounded(),
-				capacity => flume::bounded(capacity),
-			};
+			let statement_cache = StatementCache::new(address.config.statement_cache_size);
+			let (route_tx, route_rx) = route_channel(capacity);
 
 			let (conn_tx, conn_rx) = flume::bounded(1);
 
@@ -57,6 +58,7 @@ impl Connection for Client {
 					features: HashSet::new(),
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					statement_cache,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -90,11 +92,7 @@ async fn client(base_url: &Url) -> Result<reqwest::Client> {
 	Ok(client)
 }
 
-pub(crate) fn router(
-	address: Endpoint,
-	conn_tx: Sender<Result<()>>,
-	route_rx: Receiver<Option<Route>>,
-) {
+pub(crate) fn router(address: Endpoint, conn_tx: Sender<Result<()>>, route_rx: RouteReceiver) {
 	spawn_local(async move {
 		let base_url = address.url;
 
@@ -112,7 +110,7 @@ pub(crate) fn router(
 		let mut headers = HeaderMap::new();
 		let mut vars = IndexMap::new();
 		let mut auth = None;
-		let mut stream = route_rx.into_stream();
+		let mut stream = route_rx;
 
 		while let Some(Some(route)) = stream.next().await {
 			match super::router(
@@ -123,6 +123,7 @@ impl DbResponse {
 				for (index, response) in responses.into_iter().enumerate() {
 					let stats = Stats {
 						execution_time: duration_from_str(&response.time),
+						records_examined: None,
 					};
 					match response.status {
 						Status::Ok => {
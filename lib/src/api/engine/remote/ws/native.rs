@@ -1,11 +1,14 @@
 use super::PATH;
 use super::{deserialize, serialize};
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
+use crate::api::conn::RouteReceiver;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 use crate::api::engine::remote::ws::Client;
 use crate::api::engine::remote::ws::Response;
 use crate::api::engine::remote::ws::PING_INTERVAL;
@@ -124,9 +127,10 @@ impl Connection for Client {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
+			let statement_cache = StatementCache::new(address.config.statement_cache_size);
 			address.url = address.url.join(PATH)?;
 			#[cfg(any(feature = "native-tls", feature = "rustls"))]
-			let maybe_connector = address.config.tls_config.clone().map(Connector::from);
+			let maybe_connector = address.config.tls_connector()?.map(Connector::from);
 			#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
 			let maybe_connector = None;
 
@@ -139,10 +143,7 @@ impl Connection for Client {
 
 			let socket = connect(&address, Some(config), maybe_connector.clone()).await?;
 
-			let (route_tx, route_rx) = match capacity {
-				0 => flume::unbounded(),
-				capacity => flume::bounded(capacity),
-			};
+			let (route_tx, route_rx) = route_channel(capacity);
 
 			router(address, maybe_connector, capacity, config, socket, route_rx);
 
@@ -154,6 +155,7 @@ impl Connection for Client {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					statement_cache,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -185,7 +187,7 @@ pub(crate) fn router(
 	capacity: usize,
 	config: WebSocketConfig,
 	mut socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
-	route_rx: Receiver<Option<Route>>,
+	mut route_rx: RouteReceiver,
 ) {
 	tokio::spawn(async move {
 		let ping = {
@@ -219,7 +221,7 @@ pub(crate) fn router(
 
 				let streams = (
 					socket_stream.map(Either::Response),
-					route_rx.stream().map(Either::Request),
+					(&mut route_rx).map(Either::Request),
 					pinger.map(|_| Either::Ping),
 				);
 
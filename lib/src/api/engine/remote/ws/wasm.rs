@@ -1,11 +1,14 @@
 use super::PATH;
 use super::{deserialize, serialize};
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
+use crate::api::conn::RouteReceiver;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 use crate::api::engine::remote::ws::Client;
 use crate::api::engine::remote::ws::Response;
 use crate::api::engine::remote::ws::PING_INTERVAL;
@@ -74,12 +77,10 @@ impl Connection for Client {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
+			let statement_cache = StatementCache::new(address.config.statement_cache_size);
 			address.url = address.url.join(PATH)?;
 
-			let (route_tx, route_rx) = match capacity {
-				0 => flume::unbounded(),
-				capacity => flume::bounded(capacity),
-			};
+			let (route_tx, route_rx) = route_channel(capacity);
 
 			let (conn_tx, conn_rx) = flume::bounded(1);
 
@@ -95,6 +96,7 @@ impl Connection for Client {
 					features,
 					sender: route_tx,
 					last_id: AtomicI64::new(0),
+					statement_cache,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -123,7 +125,7 @@ pub(crate) fn router(
 	endpoint: Endpoint,
 	capacity: usize,
 	conn_tx: Sender<Result<()>>,
-	route_rx: Receiver<Option<Route>>,
+	mut route_rx: RouteReceiver,
 ) {
 	spawn_local(async move {
 		let connect = match endpoint.supports_revision {
@@ -183,7 +185,7 @@ pub(crate) fn router(
 
 			let streams = (
 				socket_stream.map(Either::Response),
-				route_rx.stream().map(Either::Request),
+				(&mut route_rx).map(Either::Request),
 				pinger.map(|_| Either::Ping),
 				events.map(Either::Event),
 			);
@@ -1,3 +1,4 @@
+use crate::api::opt::ExperimentalFeature;
 use crate::api::Response;
 use crate::sql::Array;
 use crate::sql::Edges;
@@ -8,6 +9,7 @@ use crate::sql::Value;
 use serde::Serialize;
 use std::io;
 use std::path::PathBuf;
+use std::time::Duration;
 use surrealdb_core::dbs::capabilities::{ParseFuncTargetError, ParseNetTargetError};
 use thiserror::Error;
 
@@ -148,11 +150,110 @@ pub enum Error {
 	#[error("Tried to take only a single result from a query that contains multiple")]
 	LossyTake(Response),
 
+	/// The query returned more rows than the configured maximum
+	#[error("The query returned more than the maximum of {0} rows")]
+	ResultTooLarge(usize),
+
+	/// The query contained more statements than the configured maximum
+	#[error("The query contains {count} statements, but the maximum is {max}")]
+	TooManyStatements {
+		count: usize,
+		max: usize,
+	},
+
+	/// The query bound more parameters than the configured maximum
+	#[error("The query binds {count} parameters, but the maximum is {max}")]
+	TooManyParameters {
+		count: usize,
+		max: usize,
+	},
+
+	/// The session has not signed in, and guests are not allowed
+	#[error("The session is not authenticated, sign in or authenticate first")]
+	NotAuthenticated,
+
+	/// The session was idle for longer than the configured expiry
+	#[error("The session has expired, sign in or authenticate again")]
+	SessionExpired,
+
+	/// The query would scan a whole table which is too large to scan
+	#[error("The query would scan all of table `{table}`, which holds more than {threshold} records, without an index")]
+	FullScanDenied {
+		table: String,
+		threshold: u64,
+	},
+
+	/// Tried to select exactly one record but none matched
+	#[error("Expected to select exactly one record but none were found")]
+	NotFound,
+
+	/// Tried to lock a record which is already locked
+	#[error("The record `{0}` is locked")]
+	Locked(Thing),
+
+	/// Tried to select exactly one record but more than one matched
+	#[error("Expected to select exactly one record but {0} were found")]
+	MultipleResults(usize),
+
+	/// Tried to take a single value from a query that returned more or fewer than one
+	#[error("Expected the query to return exactly one value but it returned {0}")]
+	NotSingleValue(usize),
+
 	/// The protocol or storage engine being used does not support backups on the architecture
 	/// it's running on
 	#[error("The protocol or storage engine does not support backups on this architecture")]
 	BackupsNotSupported,
 
+	/// The protocol or storage engine does not keep metrics
+	#[error("The protocol or storage engine does not support metrics")]
+	MetricsNotSupported,
+
+	/// An experimental feature was used without being enabled
+	#[error("The experimental feature `{0}` is not enabled")]
+	ExperimentalFeatureDisabled(ExperimentalFeature),
+
+	/// The protocol or storage engine cannot run batches of queries in the background
+	#[error("The protocol or storage engine does not support running batches in the background")]
+	BatchNotSupported,
+
+	/// The protocol or storage engine cannot build indexes in the background
+	#[error("The protocol or storage engine does not support building indexes in the background")]
+	IndexBuildsNotSupported,
+
+	/// The protocol or storage engine cannot read record metadata
+	#[error("The protocol or storage engine does not support reading record metadata")]
+	RecordMetaNotSupported,
+
+	/// The protocol or storage engine cannot create records only if they are absent
+	#[error(
+		"The protocol or storage engine does not support creating records only if they are absent"
+	)]
+	CreateIfAbsentNotSupported,
+
+	/// The protocol or storage engine cannot export a database schema
+	#[error("The protocol or storage engine does not support exporting the database schema")]
+	ExportSchemaNotSupported,
+
+	/// The protocol or storage engine cannot estimate the cost of a query
+	#[error("The protocol or storage engine does not support estimating the cost of a query")]
+	ExplainCostNotSupported,
+
+	/// The protocol or storage engine cannot compare a record with an expected state
+	#[error("The protocol or storage engine does not support comparing records")]
+	SelectDiffNotSupported,
+
+	/// The protocol or storage engine cannot read the statistics of an index
+	#[error("The protocol or storage engine does not support reading index statistics")]
+	IndexStatsNotSupported,
+
+	/// The protocol or storage engine cannot traverse graphs
+	#[error("The protocol or storage engine does not support traversing graphs")]
+	GraphNotSupported,
+
+	/// The protocol or storage engine cannot report query execution statistics
+	#[error("The protocol or storage engine does not support reporting query statistics")]
+	QueryStatsNotSupported,
+
 	/// The version of the server is not compatible with the versions supported by this SDK
 	#[error("server version `{server_version}` does not match the range supported by the client `{supported_versions}`")]
 	VersionMismatch {
@@ -188,6 +289,10 @@ pub enum Error {
 	#[error("Query statement {0} is not a live query")]
 	NotLiveQuery(usize),
 
+	/// No live query notification arrived before the timeout
+	#[error("No live query notification arrived within {0:?}")]
+	Timeout(Duration),
+
 	/// Tried to access a query statement falling outside the bounds of the statements supplied
 	#[error("Query statement {0} is out of bounds")]
 	QueryIndexOutOfBounds(usize),
@@ -208,6 +313,36 @@ pub enum Error {
 	#[error("Insert queries on edges not supported: {0}")]
 	InsertOnEdges(Edges),
 
+	/// The supplied TLS configuration could not be used
+	#[error("Invalid TLS configuration: {0}")]
+	InvalidTlsConfig(String),
+
+	/// The language has no analyzer preset
+	#[error("Unknown analyzer language: {0}")]
+	UnknownLanguage(String),
+
+	/// The configured timezone is not a known IANA timezone name
+	#[error("Invalid timezone: {0}")]
+	InvalidTimezone(String),
+
+	/// The text of a CSV file could not be read
+	#[error("Failed to read CSV: {0}")]
+	CsvRead(io::Error),
+
+	/// A row of a CSV file could not be imported
+	#[error("Malformed CSV row on line {line}: {message}")]
+	MalformedCsvRow {
+		line: usize,
+		message: String,
+	},
+
+	/// A table schema could not be derived from a Rust type
+	#[error("Failed to derive a table schema from `{type_name}`: {message}")]
+	TableSchema {
+		type_name: &'static str,
+		message: String,
+	},
+
 	#[error("{0}")]
 	InvalidNetTarget(#[from] ParseNetTargetError),
 
@@ -0,0 +1,132 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::channel;
+use crate::sql::Value;
+use crate::Surreal;
+use futures::Stream;
+use futures::StreamExt;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+/// The number of queries a batch runs at the same time unless configured otherwise
+const DEFAULT_PARALLELISM: usize = 8;
+
+/// A batch future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Batch<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) queries: Vec<String>,
+	pub(super) parallelism: usize,
+}
+
+impl<'r, C> Batch<'r, C>
+where
+	C: Connection,
+{
+	pub(super) fn new(client: Cow<'r, Surreal<C>>, queries: Vec<String>) -> Self {
+		Self {
+			client,
+			queries,
+			parallelism: DEFAULT_PARALLELISM,
+		}
+	}
+
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> Batch<'static, C> {
+		Batch {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+
+	/// Sets the maximum number of queries to run at the same time
+	pub fn parallelism(mut self, parallelism: usize) -> Self {
+		self.parallelism = parallelism.max(1);
+		self
+	}
+}
+
+impl<'r, Client> IntoFuture for Batch<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<BatchStream>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			// Embedded databases run the batch in the background
+			if router.features.contains(&ExtraFeatures::Batch) {
+				let (sender, receiver) = channel::bounded(self.parallelism);
+				let param = Param {
+					batch_sender: Some(sender),
+					..Param::new(vec![self.queries.into(), self.parallelism.into()])
+				};
+				let mut conn = Client::new(Method::Batch);
+				conn.execute_unit(router, param).await?;
+				return Ok(BatchStream {
+					inner: Box::pin(receiver),
+				});
+			}
+			// Other engines run each query as a separate request
+			let client = self.client.into_owned();
+			let results = futures::stream::iter(self.queries.into_iter().enumerate())
+				.map(move |(index, query)| {
+					let client = client.clone();
+					async move {
+						let result = match client.query(query).await {
+							Ok(response) => last_value(response),
+							Err(error) => Err(error),
+						};
+						(index, result)
+					}
+				})
+				.buffer_unordered(self.parallelism);
+			Ok(BatchStream {
+				inner: Box::pin(results),
+			})
+		})
+	}
+}
+
+/// Takes the result of the last statement, or the first error, from a query response
+fn last_value(response: crate::Response) -> Result<Value> {
+	let mut response = response.check()?;
+	match response.num_statements() {
+		0 => Ok(Value::None),
+		n => response.take(n - 1),
+	}
+}
+
+/// A stream of batch results
+///
+/// Each item carries the index of its query in the batch. Items are yielded as the queries
+/// finish, so they may arrive out of order.
+#[must_use = "streams do nothing unless you poll them"]
+pub struct BatchStream {
+	inner: Pin<Box<dyn Stream<Item = (usize, Result<Value>)> + Send + Sync>>,
+}
+
+impl std::fmt::Debug for BatchStream {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("BatchStream").finish_non_exhaustive()
+	}
+}
+
+impl Stream for BatchStream {
+	type Item = (usize, Result<Value>);
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.inner.as_mut().poll_next(cx)
+	}
+}
@@ -0,0 +1,114 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::statements::ShowSince;
+use crate::sql::Value;
+use crate::Action;
+use crate::Change;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A change feed future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Changes<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) since: ShowSince,
+	pub(super) limit: u32,
+}
+
+impl<C> Changes<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> Changes<'static, C> {
+		Changes {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for Changes<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Vec<Change>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		let Changes {
+			client,
+			table,
+			since,
+			limit,
+		} = self;
+		Box::pin(async move {
+			let param = vec![Value::Table(table.into()), since.into(), limit.into()];
+			let mut conn = Client::new(Method::ShowChanges);
+			let value = conn.execute_value(client.router.extract()?, Param::new(param)).await?;
+			into_changes(value)
+		})
+	}
+}
+
+/// Flattens the change sets returned by `SHOW CHANGES` into individual record changes
+fn into_changes(value: Value) -> Result<Vec<Change>> {
+	let invalid = |value: Value| Error::FromValue {
+		value,
+		error: String::from("invalid change feed entry"),
+	};
+	let Value::Array(sets) = value else {
+		return Err(invalid(value).into());
+	};
+	let mut out = Vec::new();
+	for set in sets {
+		let Value::Object(mut set) = set else {
+			return Err(invalid(set).into());
+		};
+		let versionstamp = set.remove("versionstamp").unwrap_or_default();
+		let versionstamp =
+			u128::try_from(versionstamp.clone()).map_err(|_| invalid(versionstamp))?;
+		let Some(Value::Array(changes)) = set.remove("changes") else {
+			return Err(invalid(set.into()).into());
+		};
+		for change in changes {
+			let Value::Object(mut change) = change else {
+				return Err(invalid(change).into());
+			};
+			let (action, data) = if let Some(data) = change.remove("create") {
+				(Action::Create, data)
+			} else if let Some(data) = change.remove("current") {
+				(Action::Update, data)
+			} else if let Some(data) = change.remove("update") {
+				(Action::Update, data)
+			} else if let Some(data) = change.remove("delete") {
+				(Action::Delete, data)
+			} else {
+				// Table definitions are not record changes
+				continue;
+			};
+			let Value::Thing(record) = data.pick(&["id".into()]) else {
+				return Err(invalid(data).into());
+			};
+			out.push(Change {
+				versionstamp,
+				action,
+				record,
+				data: match action {
+					Action::Delete => Value::None,
+					_ => data,
+				},
+			});
+		}
+	}
+	Ok(out)
+}
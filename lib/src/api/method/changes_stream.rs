@@ -0,0 +1,181 @@
+use crate::api::method::live::Stream;
+use crate::api::opt::Resource;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::statements::ShowSince;
+use crate::sql::Value;
+use crate::Change;
+use crate::Surreal;
+use futures::StreamExt;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+/// The number of change sets read from the change feed at a time
+const PAGE_SIZE: u32 = 100;
+
+type Reading = Pin<Box<dyn Future<Output = Result<Vec<Change>>> + Send + Sync + 'static>>;
+
+/// A future which starts tailing the change feed of a table
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ChangesStream<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) since: ShowSince,
+}
+
+impl<C> ChangesStream<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> ChangesStream<'static, C> {
+		ChangesStream {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for ChangesStream<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<ChangeFeedStream<Client>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let client = self.client.into_owned();
+			// Subscribe before reading so no change falls between the two
+			let live =
+				client.select(Resource::from(self.table.as_str())).into_owned().live().await?;
+			let mut stream = ChangeFeedStream {
+				client,
+				table: self.table,
+				since: self.since,
+				pending: VecDeque::new(),
+				reading: None,
+				unread: false,
+				live: Some(live),
+			};
+			// Reading the history straight away reports tables without a change feed here
+			let changes = read(&stream.client, &stream.table, stream.since.clone()).await?;
+			stream.push(changes);
+			Ok(stream)
+		})
+	}
+}
+
+fn read<C>(client: &Surreal<C>, table: &str, since: ShowSince) -> Reading
+where
+	C: Connection,
+{
+	client.changes(table, since, PAGE_SIZE).into_owned().into_future()
+}
+
+/// A stream of the changes made to a table, read from its change feed
+///
+/// The changes made since the starting point are yielded first, followed by changes as they
+/// are committed, all in the order in which they were committed. The change feed is only read
+/// while the stream is polled, so a slow consumer holds back further reads rather than
+/// buffering changes. Failed reads are yielded as errors and tried again on the next poll.
+/// Dropping the stream kills the live query which signals new changes.
+#[must_use = "streams do nothing unless you poll them"]
+pub struct ChangeFeedStream<C: Connection> {
+	client: Surreal<C>,
+	table: String,
+	since: ShowSince,
+	pending: VecDeque<Change>,
+	reading: Option<Reading>,
+	unread: bool,
+	live: Option<Stream<'static, C, Value>>,
+}
+
+// Nothing is pinned in place, the pending read is boxed
+impl<C: Connection> Unpin for ChangeFeedStream<C> {}
+
+impl<C> std::fmt::Debug for ChangeFeedStream<C>
+where
+	C: Connection,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("ChangeFeedStream").field("table", &self.table).finish()
+	}
+}
+
+impl<C> ChangeFeedStream<C>
+where
+	C: Connection,
+{
+	fn push(&mut self, changes: Vec<Change>) {
+		// A full page may be followed by more changes, so keep reading until nothing is left
+		self.unread = match changes.last() {
+			Some(last) => {
+				// Only the first 8 of the 10 bytes of a versionstamp are used to read from it
+				self.since = ShowSince::Versionstamp((last.versionstamp >> 16) as u64 + 1);
+				true
+			}
+			None => false,
+		};
+		self.pending.extend(changes);
+	}
+}
+
+impl<C> futures::Stream for ChangeFeedStream<C>
+where
+	C: Connection,
+{
+	type Item = Result<Change>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = &mut *self;
+		loop {
+			if let Some(change) = this.pending.pop_front() {
+				return Poll::Ready(Some(Ok(change)));
+			}
+			if let Some(reading) = &mut this.reading {
+				let result = futures::ready!(reading.as_mut().poll(cx));
+				this.reading = None;
+				match result {
+					Ok(changes) => this.push(changes),
+					Err(error) => {
+						this.unread = true;
+						return Poll::Ready(Some(Err(error)));
+					}
+				}
+				continue;
+			}
+			if this.unread {
+				this.reading = Some(read(&this.client, &this.table, this.since.clone()));
+				continue;
+			}
+			let Some(live) = &mut this.live else {
+				return Poll::Ready(None);
+			};
+			// Notifications only signal that there are new changes to read, so any which
+			// arrived together are covered by the same read
+			loop {
+				match live.poll_next_unpin(cx) {
+					Poll::Ready(Some(_)) => this.unread = true,
+					Poll::Ready(None) => {
+						this.live = None;
+						break;
+					}
+					Poll::Pending => break,
+				}
+			}
+			if !this.unread {
+				return match this.live {
+					Some(_) => Poll::Pending,
+					None => Poll::Ready(None),
+				};
+			}
+		}
+	}
+}
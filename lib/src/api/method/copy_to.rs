@@ -0,0 +1,163 @@
+use crate::api::opt::Conflict;
+use crate::api::opt::CopyOptions;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::error::Db as DbError;
+use crate::sql::statements::DefineStatement;
+use crate::sql::statements::InsertStatement;
+use crate::sql::Data;
+use crate::sql::Statement;
+use crate::sql::Thing;
+use crate::sql::Value;
+use crate::Surreal;
+use futures::StreamExt;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A datastore copy future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CopyTo<'r, C: Connection, D: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) target: Cow<'r, Surreal<D>>,
+	pub(super) options: CopyOptions,
+}
+
+impl<C, D> CopyTo<'_, C, D>
+where
+	C: Connection,
+	D: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> CopyTo<'static, C, D> {
+		CopyTo {
+			client: Cow::Owned(self.client.into_owned()),
+			target: Cow::Owned(self.target.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client, Target> IntoFuture for CopyTo<'r, Client, Target>
+where
+	Client: Connection,
+	Target: Connection,
+{
+	type Output = Result<()>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut backup = self.client.export(()).await?;
+			// Options such as `OPTION IMPORT` apply to every batch sent to the target
+			let mut options = Vec::new();
+			while let Some(bytes) = backup.next().await {
+				let bytes = bytes?;
+				// Each chunk of an export holds a single statement or comment
+				let sql = String::from_utf8_lossy(&bytes);
+				let sql = sql.trim();
+				if sql.is_empty() || sql.starts_with("--") {
+					continue;
+				}
+				let mut statements = Vec::new();
+				for statement in crate::syn::parse(sql)?.0 .0 {
+					match statement {
+						Statement::Option(_) => options.push(statement),
+						// Batches are applied as they arrive rather than in one transaction
+						Statement::Begin(_) | Statement::Commit(_) => {}
+						Statement::Define(mut define) => {
+							if table_of(&define).is_some_and(|tb| !self.options.includes(tb)) {
+								continue;
+							}
+							if self.options.conflict == Conflict::Skip {
+								skip_existing(&mut define);
+							}
+							statements.push(Statement::Define(define));
+						}
+						Statement::Insert(mut insert) => {
+							let ids = record_ids(&insert);
+							if ids.first().is_some_and(|id| !self.options.includes(&id.tb)) {
+								continue;
+							}
+							match self.options.conflict {
+								Conflict::Skip => insert.ignore = true,
+								Conflict::Error => ensure_absent(&self.target, ids).await?,
+							}
+							statements.push(Statement::Insert(insert));
+						}
+						statement => statements.push(statement),
+					}
+				}
+				if statements.is_empty() {
+					continue;
+				}
+				let query: Vec<_> = options.iter().cloned().chain(statements).collect();
+				self.target.query(query).await?.check()?;
+			}
+			Ok(())
+		})
+	}
+}
+
+/// The table a definition belongs to, if any
+fn table_of(define: &DefineStatement) -> Option<&str> {
+	match define {
+		DefineStatement::Table(tb) => Some(tb.name.as_str()),
+		DefineStatement::Field(fd) => Some(fd.what.as_str()),
+		DefineStatement::Index(ix) => Some(ix.what.as_str()),
+		DefineStatement::Event(ev) => Some(ev.what.as_str()),
+		_ => None,
+	}
+}
+
+/// Leaves a definition alone if it already exists in the target
+fn skip_existing(define: &mut DefineStatement) {
+	match define {
+		DefineStatement::Namespace(ns) => ns.if_not_exists = true,
+		DefineStatement::Database(db) => db.if_not_exists = true,
+		DefineStatement::Function(fc) => fc.if_not_exists = true,
+		DefineStatement::Analyzer(az) => az.if_not_exists = true,
+		DefineStatement::Param(pa) => pa.if_not_exists = true,
+		DefineStatement::Table(tb) => tb.if_not_exists = true,
+		DefineStatement::Event(ev) => ev.if_not_exists = true,
+		DefineStatement::Field(fd) => fd.if_not_exists = true,
+		DefineStatement::Index(ix) => ix.if_not_exists = true,
+		DefineStatement::User(us) => us.if_not_exists = true,
+		DefineStatement::Model(ml) => ml.if_not_exists = true,
+		DefineStatement::Access(ac) => ac.if_not_exists = true,
+		_ => {}
+	}
+}
+
+/// The ids of the records an exported insert statement writes
+fn record_ids(insert: &InsertStatement) -> Vec<Thing> {
+	let Data::SingleExpression(Value::Array(records)) = &insert.data else {
+		return Vec::new();
+	};
+	records
+		.iter()
+		.filter_map(|record| match record {
+			Value::Object(record) => match record.get("id") {
+				Some(Value::Thing(id)) => Some(id.clone()),
+				_ => None,
+			},
+			_ => None,
+		})
+		.collect()
+}
+
+/// Fails if any of the records already exist in the target
+async fn ensure_absent<C: Connection>(target: &Surreal<C>, ids: Vec<Thing>) -> Result<()> {
+	let ids: Vec<Value> = ids.into_iter().map(Value::from).collect();
+	let mut response =
+		target.query("SELECT VALUE id FROM $ids LIMIT 1").bind(("ids", Value::from(ids))).await?;
+	match response.take::<Option<Thing>>(0)? {
+		Some(id) => Err(DbError::RecordExists {
+			thing: id.to_string(),
+		}
+		.into()),
+		None => Ok(()),
+	}
+}
@@ -0,0 +1,95 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::Resource;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::to_value;
+use crate::sql::Value;
+use crate::CreatedRecord;
+use crate::Surreal;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+/// A future that creates a record unless it already exists
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CreateIfNotExists<'r, C: Connection, R> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) resource: Result<Resource>,
+	pub(super) content: Result<Value>,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C, R> CreateIfNotExists<'_, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> CreateIfNotExists<'static, C, R> {
+		CreateIfNotExists {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+
+	/// Sets the content of the record if it gets created
+	///
+	/// The content is ignored when the record already exists.
+	pub fn content<D>(mut self, data: D) -> Self
+	where
+		D: Serialize,
+	{
+		self.content = to_value(data).map_err(Into::into);
+		self
+	}
+}
+
+impl<'r, Client, R> IntoFuture for CreateIfNotExists<'r, Client, R>
+where
+	Client: Connection,
+	R: DeserializeOwned + 'r,
+{
+	type Output = Result<CreatedRecord<R>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::CreateIfAbsent) {
+				return Err(Error::CreateIfAbsentNotSupported.into());
+			}
+			let params = match self.content? {
+				Value::None | Value::Null => vec![self.resource?.into()],
+				content => vec![self.resource?.into(), content],
+			};
+			let mut conn = Client::new(Method::CreateIfAbsent);
+			let value = conn.execute_value(router, Param::new(params)).await?;
+			into_created(value)
+		})
+	}
+}
+
+fn into_created<R: DeserializeOwned>(value: Value) -> Result<CreatedRecord<R>> {
+	let Value::Object(mut object) = value else {
+		return Err(Error::FromValue {
+			value,
+			error: String::from("invalid create response"),
+		}
+		.into());
+	};
+	let created = object.remove("created").is_some_and(|created| created.is_true());
+	let record = from_value(object.remove("record").unwrap_or_default())?;
+	Ok(CreatedRecord {
+		created,
+		record,
+	})
+}
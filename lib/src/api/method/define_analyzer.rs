@@ -0,0 +1,92 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql;
+use crate::sql::statements::DefineAnalyzerStatement;
+use crate::sql::statements::DefineStatement;
+use crate::sql::Filter;
+use crate::sql::Language;
+use crate::sql::Statement;
+use crate::sql::Tokenizer;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A future that defines a full-text analyzer for a language
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DefineAnalyzerPreset<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) name: String,
+	pub(super) language: String,
+}
+
+impl<C> DefineAnalyzerPreset<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> DefineAnalyzerPreset<'static, C> {
+		DefineAnalyzerPreset {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for DefineAnalyzerPreset<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<()>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let Some(language) = language(&self.language) else {
+				return Err(Error::UnknownLanguage(self.language).into());
+			};
+			let mut statement = DefineAnalyzerStatement::default();
+			statement.name = self.name.into();
+			statement.tokenizers = Some(vec![Tokenizer::Blank, Tokenizer::Class, Tokenizer::Punct]);
+			statement.filters = Some(vec![Filter::Lowercase, Filter::Snowball(language)]);
+			let mut query = sql::Query::default();
+			query.0 .0 = vec![Statement::Define(DefineStatement::Analyzer(statement))];
+			let router = self.client.router.extract()?;
+			let mut conn = Client::new(Method::Query);
+			conn.execute_query(router, Param::query(query, BTreeMap::new())).await?.check()?;
+			Ok(())
+		})
+	}
+}
+
+/// Looks up a stemming language by its name or its ISO 639 code
+fn language(name: &str) -> Option<Language> {
+	let language = match name.to_ascii_lowercase().as_str() {
+		"arabic" | "ara" | "ar" => Language::Arabic,
+		"danish" | "dan" | "da" => Language::Danish,
+		"dutch" | "nld" | "nl" => Language::Dutch,
+		"english" | "eng" | "en" => Language::English,
+		"french" | "fra" | "fr" => Language::French,
+		"german" | "deu" | "de" => Language::German,
+		"greek" | "ell" | "el" => Language::Greek,
+		"hungarian" | "hun" | "hu" => Language::Hungarian,
+		"italian" | "ita" | "it" => Language::Italian,
+		"norwegian" | "nor" | "no" => Language::Norwegian,
+		"portuguese" | "por" | "pt" => Language::Portuguese,
+		"romanian" | "ron" | "ro" => Language::Romanian,
+		"russian" | "rus" | "ru" => Language::Russian,
+		"spanish" | "spa" | "es" => Language::Spanish,
+		"swedish" | "swe" | "sv" => Language::Swedish,
+		"tamil" | "tam" | "ta" => Language::Tamil,
+		"turkish" | "tur" | "tr" => Language::Turkish,
+		_ => return None,
+	};
+	Some(language)
+}
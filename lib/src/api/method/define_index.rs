@@ -0,0 +1,105 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::channel;
+use crate::sql;
+use crate::sql::statements::DefineStatement;
+use crate::sql::Statement;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A future that starts building an index in the background
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DefineIndexAsync<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) query: Result<Vec<Statement>>,
+}
+
+impl<C> DefineIndexAsync<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> DefineIndexAsync<'static, C> {
+		DefineIndexAsync {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for DefineIndexAsync<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<IndexBuild>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::IndexBuilds) {
+				return Err(Error::IndexBuildsNotSupported.into());
+			}
+			let statements = self.query?;
+			if !matches!(&statements[..], [Statement::Define(DefineStatement::Index(_))]) {
+				return Err(Error::InvalidParams(
+					"Expected a single `DEFINE INDEX` statement".to_owned(),
+				)
+				.into());
+			}
+			let mut query = sql::Query::default();
+			query.0 .0 = statements;
+			let (sender, receiver) = channel::bounded(1);
+			let param = Param {
+				index_build_sender: Some(sender),
+				..Param::query(query, BTreeMap::new())
+			};
+			let mut conn = Client::new(Method::DefineIndexAsync);
+			conn.execute_unit(router, param).await?;
+			Ok(IndexBuild {
+				receiver,
+			})
+		})
+	}
+}
+
+/// A handle to an index that is being built in the background
+///
+/// Await it to wait for the build to finish. Records written while the index was being
+/// built are included in the finished index.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct IndexBuild {
+	receiver: channel::Receiver<Result<()>>,
+}
+
+impl IndexBuild {
+	/// Whether the build has finished, successfully or not
+	pub fn is_finished(&self) -> bool {
+		!self.receiver.is_empty() || self.receiver.is_closed()
+	}
+}
+
+impl IntoFuture for IndexBuild {
+	type Output = Result<()>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			match self.receiver.recv().await {
+				Ok(result) => result,
+				Err(error) => Err(Error::InternalError(error.to_string()).into()),
+			}
+		})
+	}
+}
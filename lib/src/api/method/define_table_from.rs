@@ -0,0 +1,75 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::FieldSchema;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql;
+use crate::sql::statements::DefineFieldStatement;
+use crate::sql::statements::DefineStatement;
+use crate::sql::statements::DefineTableStatement;
+use crate::sql::Permissions;
+use crate::sql::Statement;
+use crate::sql::TableType;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A future that defines a schemafull table from the fields of a Rust type
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DefineTableFrom<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) fields: Result<Vec<FieldSchema>>,
+}
+
+impl<C> DefineTableFrom<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> DefineTableFrom<'static, C> {
+		DefineTableFrom {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for DefineTableFrom<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<()>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let fields = self.fields?;
+			let mut table = DefineTableStatement::default();
+			table.name = self.table.as_str().into();
+			table.full = true;
+			table.kind = TableType::Normal;
+			table.permissions = Permissions::none();
+			let mut statements = vec![Statement::Define(DefineStatement::Table(table))];
+			for field in fields {
+				let mut statement = DefineFieldStatement::default();
+				statement.name = field.name;
+				statement.what = self.table.as_str().into();
+				statement.flex = field.flexible;
+				statement.kind = Some(field.kind);
+				statements.push(Statement::Define(DefineStatement::Field(statement)));
+			}
+			let mut query = sql::Query::default();
+			query.0 .0 = statements;
+			let router = self.client.router.extract()?;
+			let mut conn = Client::new(Method::Query);
+			conn.execute_query(router, Param::query(query, BTreeMap::new())).await?.check()?;
+			Ok(())
+		})
+	}
+}
@@ -0,0 +1,52 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Value;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A future which deletes the records matching a condition and counts them
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DeleteWhere<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) condition: String,
+}
+
+impl<C> DeleteWhere<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> DeleteWhere<'static, C> {
+		DeleteWhere {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for DeleteWhere<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<u64>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			let mut conn = Client::new(Method::DeleteWhere);
+			let param = vec![Value::Table(self.table.into()), self.condition.into()];
+			let value = conn.execute_value(router, Param::new(param)).await?;
+			Ok(from_value(value)?)
+		})
+	}
+}
@@ -0,0 +1,89 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::query::ValidQuery;
+use crate::api::method::Query;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::method::OnceLockExt;
+use crate::sql;
+use crate::sql::from_value;
+use serde::Deserialize;
+use serde::Serialize;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A query cost estimate future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ExplainCost<'r, C: Connection> {
+	pub(super) query: Query<'r, C>,
+}
+
+impl<C> ExplainCost<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> ExplainCost<'static, C> {
+		ExplainCost {
+			query: self.query.into_owned(),
+		}
+	}
+
+	/// Binds a parameter or parameters to the query
+	///
+	/// See [`Query::bind`] for the accepted binding formats.
+	pub fn bind(self, bindings: impl Serialize) -> Self {
+		ExplainCost {
+			query: self.query.bind(bindings),
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for ExplainCost<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<QueryCost>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		let ValidQuery {
+			client,
+			query: statements,
+			bindings,
+			..
+		} = match self.query.inner {
+			Ok(x) => x,
+			Err(error) => return Box::pin(async move { Err(error) }),
+		};
+
+		Box::pin(async move {
+			let router = client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::ExplainCost) {
+				return Err(Error::ExplainCostNotSupported.into());
+			}
+			let mut query = sql::Query::default();
+			query.0 .0 = statements;
+			let mut conn = Client::new(Method::ExplainCost);
+			let value = conn.execute_value(router, Param::query(query, bindings)).await?;
+			Ok(from_value(value)?)
+		})
+	}
+}
+
+/// The estimated cost of running a query
+///
+/// Both figures are estimates for comparing queries with each other, not predictions of how
+/// long a query takes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct QueryCost {
+	/// The estimated number of records read, including index lookups
+	pub cost: f64,
+	/// The estimated number of records the query selects
+	pub rows: u64,
+}
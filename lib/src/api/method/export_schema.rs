@@ -0,0 +1,50 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A future that exports the definitions of a database as SQL
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ExportSchema<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+}
+
+impl<C> ExportSchema<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> ExportSchema<'static, C> {
+		ExportSchema {
+			client: Cow::Owned(self.client.into_owned()),
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for ExportSchema<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<String>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::ExportSchema) {
+				return Err(Error::ExportSchemaNotSupported.into());
+			}
+			let mut conn = Client::new(Method::ExportSchema);
+			conn.execute(router, Param::new(Vec::new())).await
+		})
+	}
+}
@@ -0,0 +1,138 @@
+use crate::api::err::Error;
+use crate::api::opt::csv;
+use crate::api::opt::CsvOptions;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::statements::InsertStatement;
+use crate::sql::Data;
+use crate::sql::Statement;
+use crate::sql::Table;
+use crate::sql::Value;
+use crate::CsvImport;
+use crate::MalformedRow;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::fmt;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::io;
+use std::mem;
+use std::pin::Pin;
+
+/// A CSV import future
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ImportCsv<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) reader: Box<dyn io::Read + Send + Sync>,
+	pub(super) options: CsvOptions,
+}
+
+impl<C> fmt::Debug for ImportCsv<'_, C>
+where
+	C: Connection,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ImportCsv")
+			.field("client", &self.client)
+			.field("table", &self.table)
+			.field("options", &self.options)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<C> ImportCsv<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> ImportCsv<'static, C> {
+		ImportCsv {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for ImportCsv<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<CsvImport>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let options = self.options;
+			let mut rows = csv::Rows::new(self.reader, options.delimiter).peekable();
+			let names: Vec<String> = match options.headers {
+				true => match rows.next().transpose().map_err(Error::CsvRead)? {
+					Some(csv::Row {
+						values: Ok(names),
+						..
+					}) => names,
+					Some(csv::Row {
+						line,
+						values: Err(message),
+					}) => {
+						return Err(Error::MalformedCsvRow {
+							line,
+							message,
+						}
+						.into())
+					}
+					None => Vec::new(),
+				},
+				// Without a header row, the first row decides how many columns there are
+				false => match rows.peek() {
+					Some(Ok(csv::Row {
+						values: Ok(values),
+						..
+					})) => (0..values.len()).map(|i| i.to_string()).collect(),
+					_ => Vec::new(),
+				},
+			};
+			let mut imported = 0;
+			let mut malformed = Vec::new();
+			let mut batch = Vec::with_capacity(options.batch_size);
+			loop {
+				let row = rows.next().transpose().map_err(Error::CsvRead)?;
+				let done = row.is_none();
+				if let Some(row) = row {
+					match row.values.and_then(|values| options.record(&names, values)) {
+						Ok(record) => batch.push(record),
+						Err(message) if options.skip_malformed => malformed.push(MalformedRow {
+							line: row.line,
+							message,
+						}),
+						Err(message) => {
+							return Err(Error::MalformedCsvRow {
+								line: row.line,
+								message,
+							}
+							.into())
+						}
+					}
+					if batch.len() < options.batch_size {
+						continue;
+					}
+				}
+				if !batch.is_empty() {
+					imported += batch.len();
+					// Each insert statement runs in its own transaction
+					let mut insert = InsertStatement::default();
+					insert.into = Some(Table::from(self.table.as_str()).into());
+					insert.data = Data::SingleExpression(Value::from(mem::take(&mut batch)));
+					self.client.query(Statement::Insert(insert)).await?.check()?;
+				}
+				if done {
+					break;
+				}
+			}
+			Ok(CsvImport {
+				imported,
+				malformed,
+			})
+		})
+	}
+}
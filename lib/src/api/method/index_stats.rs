@@ -0,0 +1,74 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Value;
+use crate::Surreal;
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// An index statistics future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct IndexStats<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) index: String,
+}
+
+impl<C> IndexStats<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> IndexStats<'static, C> {
+		IndexStats {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for IndexStats<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<IndexStatistics>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::IndexStats) {
+				return Err(Error::IndexStatsNotSupported.into());
+			}
+			let mut conn = Client::new(Method::IndexStats);
+			let param = vec![Value::Table(self.table.into()), self.index.into()];
+			let value = conn.execute_value(router, Param::new(param)).await?;
+			Ok(from_value(value)?)
+		})
+	}
+}
+
+/// The cardinality of a unique or non-unique index
+///
+/// Array values add an entry for each of their items, so `total_entries` can be higher than the
+/// number of records on the table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IndexStatistics {
+	/// The number of distinct values in the index
+	pub distinct_keys: u64,
+	/// The number of entries in the index
+	pub total_entries: u64,
+	/// The share of entries holding a distinct value, from `0` for an empty index to `1`
+	pub selectivity: f64,
+}
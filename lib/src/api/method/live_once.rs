@@ -0,0 +1,73 @@
+use crate::api::err::Error;
+use crate::api::method::Query;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::Notification;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::timeout;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::timeout;
+
+/// A future which waits for the next notification of a live query
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct LiveOnce<'r, C: Connection, R> {
+	pub(super) query: Query<'r, C>,
+	pub(super) timeout: Duration,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C, R> LiveOnce<'_, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> LiveOnce<'static, C, R> {
+		LiveOnce {
+			query: self.query.into_owned(),
+			timeout: self.timeout,
+			response_type: PhantomData,
+		}
+	}
+
+	/// Binds a parameter or parameters to the query
+	///
+	/// See [`Query::bind`] for the accepted binding formats.
+	pub fn bind(self, bindings: impl Serialize) -> Self {
+		LiveOnce {
+			query: self.query.bind(bindings),
+			..self
+		}
+	}
+}
+
+impl<'r, Client, R> IntoFuture for LiveOnce<'r, Client, R>
+where
+	Client: Connection,
+	R: DeserializeOwned + Unpin + Send + Sync + 'r,
+{
+	type Output = Result<Notification<R>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut response = self.query.await?;
+			// Dropping the stream kills the live query, whether or not a notification arrived
+			let mut stream = response.stream::<Notification<R>>(0)?;
+			match timeout(self.timeout, stream.next()).await {
+				Ok(Some(notification)) => notification,
+				// The stream only ends when the connection is closed
+				Ok(None) => Err(Error::ConnectionUninitialised.into()),
+				Err(_) => Err(Error::Timeout(self.timeout).into()),
+			}
+		})
+	}
+}
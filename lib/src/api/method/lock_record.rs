@@ -0,0 +1,126 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::RecordId;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql;
+use crate::sql::Value;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// A record lock future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct LockRecord<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) record: RecordId,
+	pub(super) ttl: Duration,
+}
+
+impl<C> LockRecord<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> LockRecord<'static, C> {
+		LockRecord {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for LockRecord<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Uuid>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			let record = Value::from(self.record);
+			let claim = Uuid::new_v4();
+			let mut conn = Client::new(Method::Lock);
+			let param = vec![
+				record.clone(),
+				sql::Duration::from(self.ttl).into(),
+				sql::Uuid::from(claim).into(),
+			];
+			// The token of the lock held on the record tells whether this call claimed it
+			match conn.execute_value(router, Param::new(param)).await? {
+				Value::Uuid(token) if token.0 == claim => Ok(claim),
+				Value::Uuid(_) => Err(locked(record)),
+				value => Err(Error::FromValue {
+					value,
+					error: String::from("invalid lock token"),
+				}
+				.into()),
+			}
+		})
+	}
+}
+
+/// A record unlock future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct UnlockRecord<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) record: RecordId,
+	pub(super) token: Uuid,
+}
+
+impl<C> UnlockRecord<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> UnlockRecord<'static, C> {
+		UnlockRecord {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for UnlockRecord<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<()>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			let record = Value::from(self.record);
+			let mut conn = Client::new(Method::Unlock);
+			let param = vec![record.clone(), sql::Uuid::from(self.token).into()];
+			// A lock still held on the record belongs to someone else
+			match conn.execute_value(router, Param::new(param)).await? {
+				Value::None => Ok(()),
+				Value::Uuid(_) => Err(locked(record)),
+				value => Err(Error::FromValue {
+					value,
+					error: String::from("invalid lock token"),
+				}
+				.into()),
+			}
+		})
+	}
+}
+
+fn locked(record: Value) -> crate::Error {
+	match record {
+		Value::Thing(record) => Error::Locked(record).into(),
+		_ => unreachable!(),
+	}
+}
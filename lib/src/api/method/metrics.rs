@@ -0,0 +1,219 @@
+use crate::api::conn::DbResponse;
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::Surreal;
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// A metrics future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Metrics<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+}
+
+impl<C> Metrics<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> Metrics<'static, C> {
+		Metrics {
+			client: Cow::Owned(self.client.into_owned()),
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for Metrics<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<MetricsSnapshot>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::Metrics) {
+				return Err(Error::MetricsNotSupported.into());
+			}
+			let mut conn = Client::new(Method::Metrics);
+			let value = conn.execute_value(router, Param::new(Vec::new())).await?;
+			Ok(from_value(value)?)
+		})
+	}
+}
+
+/// The upper bounds of the buckets of the request latency histogram
+pub const LATENCY_BUCKETS: [Duration; 8] = [
+	Duration::from_millis(1),
+	Duration::from_millis(5),
+	Duration::from_millis(10),
+	Duration::from_millis(50),
+	Duration::from_millis(100),
+	Duration::from_millis(500),
+	Duration::from_secs(1),
+	Duration::from_secs(5),
+];
+
+/// Counters maintained by an embedded database since it was connected
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MetricsSnapshot {
+	/// The number of requests handled, excluding requests for metrics
+	pub requests: u64,
+	/// The number of query statements executed
+	pub queries: u64,
+	/// The number of failed requests and query statements
+	pub errors: u64,
+	/// The number of failed requests and query statements of each kind
+	pub error_kinds: ErrorCounts,
+	/// How long requests took to handle
+	pub latency: LatencyHistogram,
+}
+
+/// The number of errors of each kind
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ErrorCounts {
+	/// Queries which could not be parsed
+	pub parse: u64,
+	/// Requests or statements which were not allowed
+	pub permission: u64,
+	/// Queries which ran out of time
+	pub timeout: u64,
+	/// Errors thrown by the query itself
+	pub thrown: u64,
+	/// Any other error raised by the database
+	pub database: u64,
+	/// Requests which the client rejected, such as those exceeding a configured limit
+	pub client: u64,
+}
+
+/// A histogram of how long requests took to handle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct LatencyHistogram {
+	/// The number of requests in each of the [`LATENCY_BUCKETS`], which hold requests taking
+	/// longer than the previous bound and no longer than their own, followed by the requests
+	/// which took longer than every bound
+	pub counts: [u64; LATENCY_BUCKETS.len() + 1],
+	/// The time taken by all of the requests together
+	pub total: Duration,
+}
+
+impl MetricsSnapshot {
+	#[allow(dead_code)] // used by the embedded database
+	pub(crate) fn record(&mut self, response: &Result<DbResponse>, duration: Duration) {
+		self.requests += 1;
+		match response {
+			Ok(DbResponse::Query(response)) => {
+				self.queries += response.results.len() as u64;
+				for (_, result) in response.results.values() {
+					if let Err(error) = result {
+						self.record_error(error);
+					}
+				}
+			}
+			Ok(DbResponse::Other(_)) => {}
+			Err(error) => self.record_error(error),
+		}
+		let bucket = LATENCY_BUCKETS.iter().position(|bound| duration <= *bound);
+		self.latency.counts[bucket.unwrap_or(LATENCY_BUCKETS.len())] += 1;
+		self.latency.total += duration;
+	}
+
+	fn record_error(&mut self, error: &crate::Error) {
+		use crate::error::Db;
+		self.errors += 1;
+		let kinds = &mut self.error_kinds;
+		let count = match error {
+			crate::Error::Db(Db::InvalidQuery(_)) => &mut kinds.parse,
+			crate::Error::Db(
+				Db::IamError(_)
+				| Db::NsNotAllowed {
+					..
+				}
+				| Db::DbNotAllowed {
+					..
+				}
+				| Db::ScriptingNotAllowed
+				| Db::FunctionNotAllowed(_)
+				| Db::NetTargetNotAllowed(_),
+			) => &mut kinds.permission,
+			crate::Error::Db(Db::QueryTimedout) => &mut kinds.timeout,
+			crate::Error::Db(Db::Thrown(_)) => &mut kinds.thrown,
+			crate::Error::Db(_) => &mut kinds.database,
+			crate::Error::Api(_) => &mut kinds.client,
+		};
+		*count += 1;
+	}
+
+	/// Formats the metrics in the Prometheus text exposition format
+	///
+	/// All of the metric names start with `surrealdb_`. Errors are labelled with their `kind`,
+	/// and request latencies are reported as a histogram in seconds, so the output can be
+	/// served as is from an endpoint which Prometheus scrapes.
+	pub fn to_prometheus(&self) -> String {
+		fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+			out.push_str(&format!("# HELP surrealdb_{name} {help}\n"));
+			out.push_str(&format!("# TYPE surrealdb_{name} {kind}\n"));
+		}
+		let mut out = String::new();
+		header(&mut out, "requests_total", "counter", "The number of requests handled");
+		out.push_str(&format!("surrealdb_requests_total {}\n", self.requests));
+		header(&mut out, "queries_total", "counter", "The number of query statements executed");
+		out.push_str(&format!("surrealdb_queries_total {}\n", self.queries));
+		header(
+			&mut out,
+			"errors_total",
+			"counter",
+			"The number of failed requests and query statements",
+		);
+		let kinds = &self.error_kinds;
+		for (kind, count) in [
+			("parse", kinds.parse),
+			("permission", kinds.permission),
+			("timeout", kinds.timeout),
+			("thrown", kinds.thrown),
+			("database", kinds.database),
+			("client", kinds.client),
+		] {
+			out.push_str(&format!("surrealdb_errors_total{{kind=\"{kind}\"}} {count}\n"));
+		}
+		header(
+			&mut out,
+			"request_duration_seconds",
+			"histogram",
+			"How long requests took to handle",
+		);
+		// Prometheus buckets count every observation up to their bound
+		let mut count = 0;
+		for (i, bound) in LATENCY_BUCKETS.iter().enumerate() {
+			count += self.latency.counts[i];
+			let le = bound.as_secs_f64();
+			out.push_str(&format!(
+				"surrealdb_request_duration_seconds_bucket{{le=\"{le}\"}} {count}\n"
+			));
+		}
+		count += self.latency.counts[LATENCY_BUCKETS.len()];
+		out.push_str(&format!(
+			"surrealdb_request_duration_seconds_bucket{{le=\"+Inf\"}} {count}\n"
+		));
+		let sum = self.latency.total.as_secs_f64();
+		out.push_str(&format!("surrealdb_request_duration_seconds_sum {sum}\n"));
+		out.push_str(&format!("surrealdb_request_duration_seconds_count {count}\n"));
+		out
+	}
+}
@@ -4,26 +4,55 @@ pub(crate) mod live;
 pub(crate) mod query;
 
 mod authenticate;
+mod batch;
 mod begin;
 mod cancel;
+mod changes;
+mod changes_stream;
 mod commit;
 mod content;
+mod copy_to;
 mod create;
+mod create_if_not_exists;
+mod define_analyzer;
+mod define_index;
+mod define_table_from;
 mod delete;
+mod delete_where;
+mod explain_cost;
 mod export;
+mod export_schema;
 mod health;
 mod import;
+mod import_csv;
+mod index_stats;
 mod insert;
 mod invalidate;
+mod live_once;
+mod lock_record;
 mod merge;
+mod metrics;
 mod patch;
+mod ping;
+mod query_raw;
+mod query_with_stats;
+mod relate_many;
 mod select;
+mod select_diff;
+mod select_graph;
+mod select_one;
+mod select_paginated;
+mod select_value;
+mod select_with_meta;
 mod set;
 mod signin;
 mod signup;
+mod subscribe_table;
+mod transaction_with_retry;
 mod unset;
 mod update;
 mod upsert;
+mod upsert_many;
 mod use_db;
 mod use_ns;
 mod version;
@@ -32,36 +61,88 @@ mod version;
 mod tests;
 
 pub use authenticate::Authenticate;
+pub use batch::Batch;
+pub use batch::BatchStream;
 #[doc(hidden)] // Not supported yet
 pub use begin::Begin;
 #[doc(hidden)] // Not supported yet
 pub use begin::Transaction;
 #[doc(hidden)] // Not supported yet
 pub use cancel::Cancel;
+#[cfg(any(
+	feature = "kv-mem",
+	feature = "kv-tikv",
+	feature = "kv-rocksdb",
+	feature = "kv-fdb",
+	feature = "kv-indxdb",
+	feature = "kv-surrealkv",
+))]
+pub use changes::Changes;
+pub use changes_stream::ChangeFeedStream;
+pub use changes_stream::ChangesStream;
 #[doc(hidden)] // Not supported yet
 pub use commit::Commit;
 pub use content::Content;
+pub use copy_to::CopyTo;
 pub use create::Create;
+pub use create_if_not_exists::CreateIfNotExists;
+pub use define_analyzer::DefineAnalyzerPreset;
+pub use define_index::DefineIndexAsync;
+pub use define_index::IndexBuild;
+pub use define_table_from::DefineTableFrom;
 pub use delete::Delete;
+pub use delete_where::DeleteWhere;
+pub use explain_cost::ExplainCost;
+pub use explain_cost::QueryCost;
 pub use export::Backup;
 pub use export::Export;
+pub use export_schema::ExportSchema;
 pub use health::Health;
 pub use import::Import;
+pub use import_csv::ImportCsv;
+pub use index_stats::IndexStatistics;
+pub use index_stats::IndexStats;
 pub use insert::Insert;
 pub use invalidate::Invalidate;
 pub use live::Stream;
+pub use live_once::LiveOnce;
+pub use lock_record::LockRecord;
+pub use lock_record::UnlockRecord;
 pub use merge::Merge;
+pub use metrics::ErrorCounts;
+pub use metrics::LatencyHistogram;
+pub use metrics::Metrics;
+pub use metrics::MetricsSnapshot;
+pub use metrics::LATENCY_BUCKETS;
 pub use patch::Patch;
+pub use ping::Ping;
 pub use query::Query;
 pub use query::QueryStream;
+pub use query_raw::QueryRaw;
+pub use query_with_stats::QueryStats;
+pub use query_with_stats::QueryWithStats;
+pub use query_with_stats::StatementStats;
+pub use relate_many::RelateMany;
 pub use select::Select;
+pub use select_diff::SelectDiff;
+pub use select_graph::Graph;
+pub use select_graph::GraphEdge;
+pub use select_graph::SelectGraph;
+pub use select_one::SelectOne;
+pub use select_paginated::Pager;
+pub use select_value::SelectValue;
+pub use select_with_meta::SelectWithMeta;
 pub use set::Set;
 pub use signin::Signin;
 pub use signup::Signup;
+pub use subscribe_table::SubscribeTable;
+pub use subscribe_table::Subscription;
 use tokio::sync::watch;
+pub use transaction_with_retry::TransactionWithRetry;
 pub use unset::Unset;
 pub use update::Update;
 pub use upsert::Upsert;
+pub use upsert_many::UpsertMany;
 pub use use_db::UseDb;
 pub use use_ns::UseNs;
 pub use version::Version;
@@ -72,21 +153,31 @@ use crate::api::opt::auth;
 use crate::api::opt::auth::Credentials;
 use crate::api::opt::auth::Jwt;
 use crate::api::opt::IntoEndpoint;
+use crate::api::opt::Resource;
 use crate::api::Connect;
 use crate::api::Connection;
 use crate::api::OnceLockExt;
+use crate::api::Result;
 use crate::api::Surreal;
+use crate::opt::CopyOptions;
+use crate::opt::CsvOptions;
+use crate::opt::GraphSpec;
 use crate::opt::IntoExportDestination;
 use crate::opt::WaitFor;
+use crate::sql::statements::ShowSince;
 use crate::sql::to_value;
+use crate::sql::Statement;
 use crate::sql::Value;
 use serde::Serialize;
 use std::borrow::Cow;
+use std::future::Future;
+use std::io;
 use std::marker::PhantomData;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use std::time::Duration;
+use uuid::Uuid;
 
 use self::query::ValidQuery;
 
@@ -96,6 +187,11 @@ use self::query::ValidQuery;
 pub struct Stats {
 	/// The time taken to execute the query
 	pub execution_time: Option<Duration>,
+	/// The number of records examined while executing the query, on embedded databases
+	///
+	/// Records are counted whether or not they end up in the results, so a query which is
+	/// answered from an index examines fewer records than one which scans a whole table.
+	pub records_examined: Option<u64>,
 }
 
 /// Machine learning model marker type for import and export types
@@ -110,28 +206,49 @@ pub struct WithStats<T>(T);
 
 impl Method {
 	#[allow(dead_code)] // used by `ws` and `http`
-	pub(crate) fn as_str(&self) -> &str {
+	pub(crate) fn as_str(&self) -> &'static str {
 		match self {
 			Method::Authenticate => "authenticate",
+			Method::Batch => "batch",
 			Method::Create => "create",
+			Method::CreateIfAbsent => "create_if_absent",
+			Method::DefineIndexAsync => "define_index_async",
 			Method::Delete => "delete",
+			Method::DeleteWhere => "delete_where",
 			Method::Export => "export",
+			Method::ExplainCost => "explain_cost",
+			Method::ExportSchema => "export_schema",
+			Method::Graph => "select_graph",
 			Method::Health => "health",
+			Method::IndexStats => "index_stats",
 			Method::Import => "import",
 			Method::Invalidate => "invalidate",
 			Method::Insert => "insert",
 			Method::Kill => "kill",
 			Method::Live => "live",
+			Method::Lock => "lock",
 			Method::Merge => "merge",
+			Method::Metrics => "metrics",
 			Method::Patch => "patch",
+			Method::Ping => "ping",
 			Method::Query => "query",
+			// Raw queries only differ in how the client handles the results
+			Method::RawQuery => "query",
+			Method::QueryStats => "query_with_stats",
+			// Recomputing fields is sent as the query which does it
+			Method::RelateMany => "relate_many",
 			Method::Select => "select",
+			Method::SelectDiff => "select_diff",
+			Method::SelectMeta => "select_meta",
 			Method::Set => "set",
+			Method::ShowChanges => "show_changes",
 			Method::Signin => "signin",
 			Method::Signup => "signup",
+			Method::Unlock => "unlock",
 			Method::Unset => "unset",
 			Method::Update => "update",
 			Method::Upsert => "upsert",
+			Method::UpsertMany => "upsert_many",
 			Method::Use => "use",
 			Method::Version => "version",
 		}
@@ -666,7 +783,7 @@ where
 	/// # }
 	/// ```
 	pub fn query(&self, query: impl opt::IntoQuery) -> Query<C> {
-		let inner = query.into_query().map(|x| ValidQuery {
+		let inner = self.parse_query(query).map(|x| ValidQuery {
 			client: Cow::Borrowed(self),
 			query: x,
 			bindings: Default::default(),
@@ -678,6 +795,267 @@ where
 		}
 	}
 
+	/// Parses a query, reusing cached statements if the client has a statement cache
+	fn parse_query(&self, query: impl opt::IntoQuery) -> Result<Vec<Statement>> {
+		if let (Some(sql), Some(router)) = (query.as_sql(), self.router.get()) {
+			return router.statement_cache.parse(sql);
+		}
+		query.into_query()
+	}
+
+	/// Runs a set of SurrealQL statements and returns their results without deserialising them
+	///
+	/// Each statement produces one entry in the returned list, holding the value exactly as the
+	/// database returned it. Unlike [`Surreal::query`], types which have no direct equivalent in
+	/// serde, such as durations, record IDs and geometries, are left as they are.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::sql::Value;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let mut results = db.query_raw("RETURN 1h30m; RETURN person:tobie").await?;
+	/// if let Ok(Value::Duration(duration)) = results.remove(0) {
+	///     println!("{duration}");
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn query_raw(&self, query: impl opt::IntoQuery) -> QueryRaw<C> {
+		let inner = self.parse_query(query).map(|x| ValidQuery {
+			client: Cow::Borrowed(self),
+			query: x,
+			bindings: Default::default(),
+			register_live_queries: false,
+		});
+
+		QueryRaw {
+			query: Query {
+				inner,
+			},
+		}
+	}
+
+	/// Estimates the cost of a set of SurrealQL statements without running them
+	///
+	/// Each `SELECT` statement is planned the same way `EXPLAIN` plans it. The records it would
+	/// read are estimated from the number of records in each table it scans, and the number of
+	/// index entries each of its index lookups matches, which are counted without reading the
+	/// records. Nothing is executed, so other statements, which would write records, do not add
+	/// to the estimate. A query which uses an index has a lower cost than one which scans the whole
+	/// table. Remote engines do not support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # db.use_ns("namespace").use_db("database").await?;
+	/// let estimate = db.explain_cost("SELECT * FROM person WHERE email = $email").await?;
+	/// println!("about {} rows at a cost of {}", estimate.rows, estimate.cost);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn explain_cost(&self, query: impl opt::IntoQuery) -> ExplainCost<C> {
+		let inner = self.parse_query(query).map(|x| ValidQuery {
+			client: Cow::Borrowed(self),
+			query: x,
+			bindings: Default::default(),
+			register_live_queries: false,
+		});
+
+		ExplainCost {
+			query: Query {
+				inner,
+			},
+		}
+	}
+
+	/// Runs a set of SurrealQL statements and returns their results with execution statistics
+	///
+	/// Each statement produces one value in the returned list, along with how long it took and
+	/// how many records it examined and returned. Records are examined whether or not they
+	/// match, so a query which uses an index examines fewer records than one which scans the
+	/// whole table. Returns the error of the first statement which failed. Remote engines do
+	/// not support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # db.use_ns("namespace").use_db("database").await?;
+	/// let (results, stats) = db.query_with_stats("SELECT * FROM person WHERE age > 30").await?;
+	/// let statement = stats.statements[0];
+	/// println!(
+	///     "{} of {} records returned in {:?}",
+	///     statement.rows_returned, statement.rows_examined, statement.duration
+	/// );
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn query_with_stats(&self, query: impl opt::IntoQuery) -> QueryWithStats<C> {
+		let inner = self.parse_query(query).map(|x| ValidQuery {
+			client: Cow::Borrowed(self),
+			query: x,
+			bindings: Default::default(),
+			register_live_queries: false,
+		});
+
+		QueryWithStats {
+			query: Query {
+				inner,
+			},
+		}
+	}
+
+	/// Runs statements in a transaction, running it again when it conflicts with a concurrent one
+	///
+	/// `body` returns the statements to run inside the transaction. It is given the client so it
+	/// can read whatever it needs to build them. When the transaction fails to commit because a
+	/// concurrent transaction changed the same records, `body` is called again and the new
+	/// statements are run in a fresh transaction, up to `max_attempts` times in total. As it may
+	/// run more than once, `body` must be idempotent and should not have side effects of its own.
+	///
+	/// Other errors are not retried. Errors returned by `body` are returned straight away, and
+	/// the response is returned as is when a statement fails for another reason, or the last
+	/// attempt still conflicts. Only storage engines which detect conflicts between transactions,
+	/// such as SurrealKV, ever retry.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// db.use_ns("namespace").use_db("database").await?;
+	/// db.transaction_with_retry(5, |db| async move {
+	///     let sql = "math::sum(SELECT VALUE amount FROM order)";
+	///     let total: Option<i64> = db.query(sql).await?.take(0)?;
+	///     Ok(format!("UPDATE stats:orders SET total = {}", total.unwrap_or_default()))
+	/// })
+	/// .await?
+	/// .check()?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn transaction_with_retry<F, Fut, Q>(
+		&self,
+		max_attempts: u32,
+		body: F,
+	) -> TransactionWithRetry<C, F>
+	where
+		F: FnMut(Surreal<C>) -> Fut,
+		Fut: Future<Output = Result<Q>>,
+		Q: opt::IntoQuery,
+	{
+		TransactionWithRetry {
+			client: Cow::Borrowed(self),
+			max_attempts,
+			body,
+		}
+	}
+
+	/// Waits for the next notification of a live query
+	///
+	/// The query must start with a `LIVE SELECT` statement. The live query is killed as soon as
+	/// the first notification arrives, or with [`Error::Timeout`](crate::error::Api::Timeout)
+	/// if none arrives within `timeout`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use std::time::Duration;
+	/// use surrealdb::Notification;
+	///
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Wait up to a minute for the next adult to change
+	/// let notification: Notification<Person> = db
+	///     .live_once("LIVE SELECT * FROM person WHERE age >= 18", Duration::from_secs(60))
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn live_once<R>(&self, query: impl opt::IntoQuery, timeout: Duration) -> LiveOnce<C, R> {
+		LiveOnce {
+			query: self.query(query),
+			timeout,
+			response_type: PhantomData,
+		}
+	}
+
+	/// Runs a query which is expected to return exactly one value and deserialises it
+	///
+	/// Returns an error if the first statement returns more or fewer than one value.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Count the people in the database
+	/// let count: usize = db
+	///     .select_value("RETURN count(SELECT * FROM person)")
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_value<T>(&self, query: impl opt::IntoQuery) -> SelectValue<C, T> {
+		SelectValue {
+			query: self.query(query),
+			response_type: PhantomData,
+		}
+	}
+
+	/// Runs independent queries concurrently, yielding each result as soon as it is ready
+	///
+	/// Each item of the returned stream carries the index of its query in `queries`, as results
+	/// may arrive out of order. At most 8 queries run at the same time unless configured
+	/// otherwise with [`Batch::parallelism`].
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use futures::StreamExt;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let queries = vec!["SELECT * FROM person".to_owned(), "SELECT * FROM company".to_owned()];
+	/// let mut results = db.batch(queries).parallelism(2).await?;
+	/// while let Some((index, result)) = results.next().await {
+	///     println!("query {index} returned {:?}", result?);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn batch(&self, queries: Vec<String>) -> Batch<C> {
+		Batch::new(Cow::Borrowed(self), queries)
+	}
+
 	/// Selects all records in a table, or a specific record
 	///
 	/// # Examples
@@ -727,6 +1105,138 @@ where
 		}
 	}
 
+	/// Selects exactly one record and deserialises it
+	///
+	/// Returns [`Error::NotFound`](crate::error::Api::NotFound) if nothing matches and
+	/// [`Error::MultipleResults`](crate::error::Api::MultipleResults) if more than one
+	/// record does.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Select a specific record from a table
+	/// let person: Person = db.select_one(("person", "h5wxrf2ewk8xjxosxtyc")).await?;
+	///
+	/// // Select the only record in a range
+	/// let person: Person = db.select_one("person").range("jane".."john").await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_one<T>(&self, resource: impl Into<Resource>) -> SelectOne<C, T> {
+		SelectOne {
+			client: Cow::Borrowed(self),
+			resource: Ok(resource.into()),
+			range: None,
+			response_type: PhantomData,
+		}
+	}
+
+	/// Selects the records of a table one page at a time
+	///
+	/// Pages are read in record ID order and each page resumes after the last record ID of the
+	/// previous page, rather than skipping over an offset, so walking a large table stays
+	/// linear. A `page_size` of zero is treated as one.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let mut pager = db.select_paginated::<Person>("person", 50);
+	/// while let Some(page) = pager.next_page().await? {
+	///     println!("{} of {} people", page.items.len(), page.total);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_paginated<T>(&self, table: impl Into<String>, page_size: u32) -> Pager<C, T> {
+		Pager {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			page_size: page_size.max(1),
+			cursor: None,
+			total: None,
+			done: false,
+			response_type: PhantomData,
+		}
+	}
+
+	/// Subscribes to every change made to the records of a table
+	///
+	/// The returned stream yields a notification for each record created, updated or deleted
+	/// on the table. It manages the live query behind it, subscribing again if the live query
+	/// is closed or subscribing fails, and gives up after five failures in a row. Dropping the
+	/// stream kills the live query. Requires live query notifications to be enabled.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use futures::StreamExt;
+	///
+	/// # #[derive(Debug, serde::Deserialize)]
+	/// # struct Person;
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let mut people = db.subscribe_table::<Person>("person").await?;
+	/// while let Some(notification) = people.next().await {
+	///     let notification = notification?;
+	///     println!("{:?} {:?}", notification.action, notification.data);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn subscribe_table<T>(&self, table: impl Into<String>) -> SubscribeTable<C, T> {
+		SubscribeTable {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			response_type: PhantomData,
+		}
+	}
+
+	/// Selects records along with their version and last-modified time
+	///
+	/// The metadata is kept with each record on a table with a change feed, so tables without
+	/// a change feed return records with their metadata set to `None`. Remote engines do not
+	/// support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// db.query("DEFINE TABLE person CHANGEFEED 1d").await?;
+	/// let people = db.select_with_meta::<Person>("person").await?;
+	/// for person in people {
+	///     println!("{} is at version {:?}", person.id, person.version);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_with_meta<T>(&self, resource: impl Into<Resource>) -> SelectWithMeta<C, T> {
+		SelectWithMeta {
+			client: Cow::Borrowed(self),
+			resource: resource.into(),
+			response_type: PhantomData,
+		}
+	}
+
 	/// Creates a record in the database
 	///
 	/// # Examples
@@ -781,27 +1291,25 @@ where
 		}
 	}
 
-	/// Insert a record or records into a table
+	/// Creates a record unless it already exists
+	///
+	/// When the record is already there, the existing record is returned untouched with
+	/// [`CreatedRecord::created`] set to `false`. Concurrent calls for the same record create
+	/// it at most once. If the record exists but can't be selected, for example because the
+	/// table permissions hide it, the error for the record already existing is returned.
+	/// Remote engines do not support this method.
 	///
 	/// # Examples
 	///
 	/// ```no_run
 	/// use serde::Serialize;
-	/// use surrealdb::sql;
 	///
 	/// # #[derive(serde::Deserialize)]
 	/// # struct Person;
 	/// #
 	/// #[derive(Serialize)]
-	/// struct Settings {
-	///     active: bool,
-	///     marketing: bool,
-	/// }
-	///
-	/// #[derive(Serialize)]
-	/// struct User<'a> {
-	///     name: &'a str,
-	///     settings: Settings,
+	/// struct User {
+	///     name: &'static str,
 	/// }
 	///
 	/// # #[tokio::main]
@@ -811,24 +1319,81 @@ where
 	/// // Select the namespace/database to use
 	/// db.use_ns("namespace").use_db("database").await?;
 	///
-	/// // Insert a record with a specific ID
-	/// let person: Option<Person> = db.insert(("person", "tobie"))
+	/// let person = db.create_if_not_exists::<Person>(("person", "tobie"))
 	///     .content(User {
 	///         name: "Tobie",
-	///         settings: Settings {
-	///             active: true,
-	///             marketing: true,
-	///         },
 	///     })
 	///     .await?;
 	///
-	/// // Insert multiple records into the table
-	/// let people: Vec<Person> = db.insert("person")
-	///     .content(vec![
-	///         User {
-	///             name: "Tobie",
-	///             settings: Settings {
-	///                 active: true,
+	/// if !person.created {
+	///     // Tobie was already there
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// [`CreatedRecord::created`]: crate::CreatedRecord::created
+	pub fn create_if_not_exists<R>(
+		&self,
+		resource: impl opt::IntoResource<Option<R>>,
+	) -> CreateIfNotExists<C, R> {
+		CreateIfNotExists {
+			client: Cow::Borrowed(self),
+			resource: resource.into_resource(),
+			content: Ok(Value::None),
+			response_type: PhantomData,
+		}
+	}
+
+	/// Insert a record or records into a table
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::Serialize;
+	/// use surrealdb::sql;
+	///
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// #
+	/// #[derive(Serialize)]
+	/// struct Settings {
+	///     active: bool,
+	///     marketing: bool,
+	/// }
+	///
+	/// #[derive(Serialize)]
+	/// struct User<'a> {
+	///     name: &'a str,
+	///     settings: Settings,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Insert a record with a specific ID
+	/// let person: Option<Person> = db.insert(("person", "tobie"))
+	///     .content(User {
+	///         name: "Tobie",
+	///         settings: Settings {
+	///             active: true,
+	///             marketing: true,
+	///         },
+	///     })
+	///     .await?;
+	///
+	/// // Insert multiple records into the table
+	/// let people: Vec<Person> = db.insert("person")
+	///     .content(vec![
+	///         User {
+	///             name: "Tobie",
+	///             settings: Settings {
+	///                 active: true,
 	///                 marketing: false,
 	///             },
 	///         },
@@ -882,6 +1447,170 @@ where
 		}
 	}
 
+	/// Creates graph edges between records in a single transaction
+	///
+	/// Each edge is given as the record it starts from, the name of the edge table, the record it
+	/// points to and optionally the content of the edge. Either all of the edges are created or,
+	/// if any of them fails, none are. The created edges are returned in the order they were given.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::sql;
+	///
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Edge;
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # db.use_ns("namespace").use_db("database").await?;
+	/// let tobie = sql::thing("person:tobie")?;
+	/// let edges: Vec<Edge> = db
+	///     .relate_many(vec![
+	///         (tobie.clone(), "likes".to_owned(), sql::thing("post:one")?, None),
+	///         (tobie, "wrote".to_owned(), sql::thing("post:two")?, Some(sql::json("{ draft: true }")?)),
+	///     ])
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn relate_many<R>(
+		&self,
+		edges: Vec<(opt::RecordId, String, opt::RecordId, Option<Value>)>,
+	) -> RelateMany<C, R> {
+		RelateMany {
+			client: Cow::Borrowed(self),
+			edges,
+			response_type: PhantomData,
+		}
+	}
+
+	/// Traverses the graph from a record, following the edges the spec describes
+	///
+	/// Returns every record reached within the depth of the spec, along with the edges followed
+	/// to reach them. Each record is only traversed once, so cyclic graphs end as well. Remote
+	/// engines do not support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::opt::GraphDirection;
+	/// use surrealdb::opt::GraphSpec;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # db.use_ns("namespace").use_db("database").await?;
+	/// // Find friends of friends
+	/// let spec = GraphSpec::new().direction(GraphDirection::Out).edge("knows").depth(2);
+	/// let graph = db.select_graph(("person", "tobie"), spec).await?;
+	/// println!("{} people reached over {} edges", graph.nodes.len(), graph.edges.len());
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_graph(&self, start: impl Into<opt::RecordId>, spec: GraphSpec) -> SelectGraph<C> {
+		SelectGraph {
+			client: Cow::Borrowed(self),
+			start: start.into(),
+			spec,
+		}
+	}
+
+	/// Returns the JSON Patch which turns an expected state of a record into its current state
+	///
+	/// The patch is a list of RFC 6902 operations, with objects compared field by field and
+	/// arrays item by item. The record is compared in the form it deserialises to, so a record
+	/// which was read into a type and serialised back matches itself, and the patch is empty.
+	/// A record which does not exist is treated as having no fields, so its patch removes every
+	/// field of the expected state. Remote engines do not support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde_json::json;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let expected = json!({ "id": "person:tobie", "name": "Tobie" });
+	/// let patch = db.select_diff(("person", "tobie"), expected).await?;
+	/// for op in patch {
+	///     println!("{op}");
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn select_diff(
+		&self,
+		record: impl Into<opt::RecordId>,
+		expected: impl Serialize,
+	) -> SelectDiff<C> {
+		SelectDiff {
+			client: Cow::Borrowed(self),
+			record: record.into(),
+			expected: to_value(expected).map_err(Into::into),
+		}
+	}
+
+	/// Acquires an advisory lock on a record, returning the token which releases it
+	///
+	/// The lock does not stop anything from reading or writing the record. It only stops other
+	/// callers of this method from locking the same record until it is released with
+	/// [`unlock_record`](Self::unlock_record) or its time to live runs out, so that workers
+	/// can each claim a different record. Trying to lock a record which is already locked
+	/// returns [`Error::Locked`](crate::error::Api::Locked). Locks are kept by the datastore,
+	/// apart from the records of the selected database, so they are shared by every client of
+	/// that database and can't be read or released with queries.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use std::time::Duration;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let token = db.lock_record(("job", "one"), Duration::from_secs(30)).await?;
+	/// // Process the job while holding the lock
+	/// db.unlock_record(("job", "one"), token).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn lock_record(&self, record: impl Into<opt::RecordId>, ttl: Duration) -> LockRecord<C> {
+		LockRecord {
+			client: Cow::Borrowed(self),
+			record: record.into(),
+			ttl,
+		}
+	}
+
+	/// Releases an advisory lock acquired with [`lock_record`](Self::lock_record)
+	///
+	/// Releasing a lock which has already expired or been released does nothing. Trying to
+	/// release a lock with a token other than the one it was acquired with, while it is still
+	/// held, returns [`Error::Locked`](crate::error::Api::Locked).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use std::time::Duration;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let token = db.lock_record(("job", "one"), Duration::from_secs(30)).await?;
+	/// db.unlock_record(("job", "one"), token).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn unlock_record(&self, record: impl Into<opt::RecordId>, token: Uuid) -> UnlockRecord<C> {
+		UnlockRecord {
+			client: Cow::Borrowed(self),
+			record: record.into(),
+			token,
+		}
+	}
+
 	/// Updates all records in a table, or a specific record
 	///
 	/// # Examples
@@ -1041,6 +1770,44 @@ where
 		}
 	}
 
+	/// Upserts several records in a single transaction
+	///
+	/// Each record is given as its ID and its content, which replaces an existing record or
+	/// creates a new one. Either all of the records are written or, if any of them fails, for
+	/// example because of a field assertion, none are. The written records are returned in the
+	/// order they were given, along with whether each one was created.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::sql;
+	///
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Person;
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # db.use_ns("namespace").use_db("database").await?;
+	/// let people: Vec<surrealdb::UpsertedRecord<Person>> = db
+	///     .upsert_many(vec![
+	///         (sql::thing("person:tobie")?, sql::json("{ name: 'Tobie' }")?),
+	///         (sql::thing("person:jaime")?, sql::json("{ name: 'Jaime' }")?),
+	///     ])
+	///     .await?;
+	/// for person in people {
+	///     println!("{} created: {}", person.id, person.created);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn upsert_many<R>(&self, records: Vec<(opt::RecordId, Value)>) -> UpsertMany<C, R> {
+		UpsertMany {
+			client: Cow::Borrowed(self),
+			records,
+			response_type: PhantomData,
+		}
+	}
+
 	/// Updates all records in a table, or a specific record
 	///
 	/// # Examples
@@ -1233,6 +2000,289 @@ where
 		}
 	}
 
+	/// Deletes the records of a table which match a condition, returning how many were deleted
+	///
+	/// The condition is a SurrealQL expression, as it would be written after `WHERE`. The
+	/// deleted records are not sent back, which makes this cheaper than
+	/// [`delete`](Self::delete) when only the number of deleted records is needed.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// db.use_ns("namespace").use_db("database").await?;
+	/// let deleted = db.delete_where("session", "expires_at < time::now()").await?;
+	/// println!("{deleted} sessions expired");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn delete_where(
+		&self,
+		table: impl Into<String>,
+		condition: impl Into<String>,
+	) -> DeleteWhere<C> {
+		DeleteWhere {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			condition: condition.into(),
+		}
+	}
+
+	/// Reads the change feed of a table
+	///
+	/// Returns up to `limit` changes made to the table since the given versionstamp or
+	/// timestamp, in the order in which they were committed. The table, or its database,
+	/// must have a change feed defined.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Read the first 10 changes made to the person table
+	/// let changes = db.changes("person", 0, 10).await?;
+	/// for change in changes {
+	///     println!("{:?} {}", change.action, change.record);
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn changes(
+		&self,
+		table: impl Into<String>,
+		since: impl Into<ShowSince>,
+		limit: u32,
+	) -> Changes<C> {
+		Changes {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			since: since.into(),
+			limit,
+		}
+	}
+
+	/// Tails the change feed of a table
+	///
+	/// The returned stream yields the changes made to the table since the given versionstamp
+	/// or timestamp, then keeps yielding changes as they are committed. A live query on the
+	/// table signals when there are new changes, so the table, or its database, must have a
+	/// change feed defined and live query notifications must be enabled. Dropping the stream
+	/// kills the live query.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use futures::StreamExt;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # db.use_ns("namespace").use_db("database").await?;
+	/// let mut changes = db.changes_stream("person", 0).await?;
+	/// while let Some(change) = changes.next().await {
+	///     let change = change?;
+	///     println!("{} {:?} {}", change.versionstamp, change.action, change.record);
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn changes_stream(
+		&self,
+		table: impl Into<String>,
+		since: impl Into<ShowSince>,
+	) -> ChangesStream<C> {
+		ChangesStream {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			since: since.into(),
+		}
+	}
+
+	/// Defines a full-text analyzer with sensible defaults for a language
+	///
+	/// The analyzer splits text on whitespace, punctuation and changes of character class,
+	/// lowercases each token and stems it with the snowball stemmer for the language. The
+	/// language is given by its English name or its ISO 639 code, such as `"english"`, `"en"`
+	/// or `"eng"`. Defining an analyzer which already exists is an error.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// db.define_analyzer_preset("person_search", "english").await?;
+	/// db.query("DEFINE INDEX person_bio ON person FIELDS bio SEARCH ANALYZER person_search BM25")
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn define_analyzer_preset(
+		&self,
+		name: impl Into<String>,
+		language: impl Into<String>,
+	) -> DefineAnalyzerPreset<C> {
+		DefineAnalyzerPreset {
+			client: Cow::Borrowed(self),
+			name: name.into(),
+			language: language.into(),
+		}
+	}
+
+	/// Defines a schemafull table with a field for each field of a Rust type
+	///
+	/// The schema is derived from the way the type deserialises, as described by
+	/// [`TableSchema`](crate::opt::TableSchema). Values of the type can then be stored in the
+	/// table, while values with fields of the wrong type are rejected. Defining a table which
+	/// already exists is an error.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::{Deserialize, Serialize};
+	///
+	/// #[derive(Serialize, Deserialize)]
+	/// struct Address {
+	///     city: String,
+	///     postcode: Option<String>,
+	/// }
+	///
+	/// #[derive(Serialize, Deserialize)]
+	/// struct Person {
+	///     name: String,
+	///     age: u8,
+	///     address: Address,
+	///     tags: Vec<String>,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # db.use_ns("namespace").use_db("database").await?;
+	/// db.define_table_from::<Person>("person").await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn define_table_from<T>(&self, table: impl Into<String>) -> DefineTableFrom<C>
+	where
+		T: opt::TableSchema,
+	{
+		DefineTableFrom {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			fields: T::table_fields(),
+		}
+	}
+
+	/// Starts building an index in the background
+	///
+	/// The `DEFINE INDEX` statement is run without blocking other requests on the connection.
+	/// Awaiting this method returns as soon as the build has started, giving back a handle
+	/// that can be awaited to wait for the build to finish. Records written to the table while
+	/// the index is being built are tracked, and brought up to date in the index once the build
+	/// has finished, so the finished index includes them without being rebuilt.
+	///
+	/// Remote engines do not support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let build = db.define_index_async("DEFINE INDEX email ON user FIELDS email UNIQUE").await?;
+	/// // Keep using the database while the index is being built
+	/// db.query("CREATE user SET email = 'tobie@surrealdb.com'").await?;
+	/// build.await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn define_index_async(&self, spec: impl opt::IntoQuery) -> DefineIndexAsync<C> {
+		DefineIndexAsync {
+			client: Cow::Borrowed(self),
+			query: spec.into_query(),
+		}
+	}
+
+	/// Returns the cardinality and selectivity of a unique or non-unique index
+	///
+	/// The statistics are read from the entries of the index as they are at the time of the
+	/// call, so they go stale as records change and only cover the records indexed so far while
+	/// an index is still being built in the background. Treat them as approximate. Returns an
+	/// error if the index does not exist. Remote engines do not support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// db.query("DEFINE INDEX email ON user FIELDS email UNIQUE").await?;
+	/// let stats = db.index_stats("user", "email").await?;
+	/// println!("{} distinct keys out of {}", stats.distinct_keys, stats.total_entries);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn index_stats(&self, table: impl Into<String>, index: impl Into<String>) -> IndexStats<C> {
+		IndexStats {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			index: index.into(),
+		}
+	}
+
+	/// Returns the metrics the embedded database has collected since it was connected
+	///
+	/// The snapshot can be formatted for Prometheus to scrape with
+	/// [`MetricsSnapshot::to_prometheus`]. Remote engines do not support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let metrics = db.metrics().await?;
+	/// println!("{} queries executed", metrics.queries);
+	/// let text = metrics.to_prometheus();
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn metrics(&self) -> Metrics<C> {
+		Metrics {
+			client: Cow::Borrowed(self),
+		}
+	}
+
+	/// Measures the round-trip latency of the connection
+	///
+	/// Unlike [`health`](Self::health), this does not touch the storage engine.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let latency = db.ping().await?;
+	/// println!("round trip took {latency:?}");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn ping(&self) -> Ping<C> {
+		Ping {
+			client: Cow::Borrowed(self),
+		}
+	}
+
 	/// Returns the version of the server
 	///
 	/// # Examples
@@ -1269,6 +2319,29 @@ where
 		}
 	}
 
+	/// Changes the capacity of the channel requests are sent to the connection on
+	///
+	/// This adjusts the bound set by [`Connect::with_capacity`] without reconnecting. Requests
+	/// already waiting in the channel are still handled, in order, before those sent after
+	/// resizing. A capacity of `0` makes the channel unbounded, and resizing to the current
+	/// capacity does nothing.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// // Allow more requests to queue up during a busy period
+	/// db.resize_channel(10_000)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn resize_channel(&self, capacity: usize) -> Result<()> {
+		self.router.extract()?.sender.resize(capacity);
+		Ok(())
+	}
+
 	/// Wait for the selected event to happen before proceeding
 	pub async fn wait_for(&self, event: WaitFor) {
 		let mut rx = self.waiter.0.subscribe();
@@ -1328,6 +2401,31 @@ where
 		}
 	}
 
+	/// Exports the definitions of the current database as SQL, without any records
+	///
+	/// The returned statements define the users, accesses, params, functions, analyzers,
+	/// tables, fields, indexes and events of the database selected with
+	/// [`use_ns`](Self::use_ns) and [`use_db`](Self::use_db), in an order that can be imported
+	/// again. Remote engines do not support this method.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// db.use_ns("namespace").use_db("database").await?;
+	/// let schema = db.export_schema().await?;
+	/// println!("{schema}");
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn export_schema(&self) -> ExportSchema<C> {
+		ExportSchema {
+			client: Cow::Borrowed(self),
+		}
+	}
+
 	/// Restores the database from a file
 	///
 	/// # Support
@@ -1358,4 +2456,86 @@ where
 			import_type: PhantomData,
 		}
 	}
+
+	/// Imports the rows of a CSV file into a table
+	///
+	/// Each row becomes a record, with a field for each column. The reader is only read once
+	/// the import is awaited, and rows are inserted in batches as they are read, each batch in
+	/// its own transaction, so the whole file is never held in memory. Malformed rows are left
+	/// out and listed in the returned [`CsvImport`](crate::CsvImport), unless
+	/// [`CsvOptions::skip_malformed`] is turned off.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::opt::CsvOptions;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let file = std::fs::File::open("people.csv").unwrap();
+	/// let options = CsvOptions::new().delimiter(b';').column("Full Name", "name");
+	/// let report = db.import_csv("person", file, options).await?;
+	/// println!("imported {} people", report.imported);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn import_csv(
+		&self,
+		table: impl Into<String>,
+		reader: impl io::Read + Send + Sync + 'static,
+		options: CsvOptions,
+	) -> ImportCsv<C> {
+		ImportCsv {
+			client: Cow::Borrowed(self),
+			table: table.into(),
+			reader: Box::new(reader),
+			options,
+		}
+	}
+
+	/// Copies the selected database into the database selected on another connection
+	///
+	/// Definitions and records are streamed from an export of this database and inserted
+	/// into the target in batches, without buffering the whole export.
+	///
+	/// # Support
+	///
+	/// The source must support [exports](Surreal::export). The target can use any engine.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use surrealdb::opt::Conflict;
+	/// use surrealdb::opt::CopyOptions;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # let replica = surrealdb::engine::any::connect("mem://").await?;
+	/// db.use_ns("namespace").use_db("database").await?;
+	/// replica.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Copy the `person` table, failing if any of its records already exist
+	/// let options = CopyOptions::new().tables(["person"]).conflict(Conflict::Error);
+	/// db.copy_to(&replica, options).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn copy_to<'r, D>(
+		&'r self,
+		target: &'r Surreal<D>,
+		options: CopyOptions,
+	) -> CopyTo<'r, C, D>
+	where
+		D: Connection,
+	{
+		CopyTo {
+			client: Cow::Borrowed(self),
+			target: Cow::Borrowed(target),
+			options,
+		}
+	}
 }
@@ -0,0 +1,52 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::method::OnceLockExt;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::std::Instant;
+
+/// A ping future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Ping<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+}
+
+impl<C> Ping<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> Ping<'static, C> {
+		Ping {
+			client: Cow::Owned(self.client.into_owned()),
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for Ping<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Duration>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			let start = Instant::now();
+			let mut conn = Client::new(Method::Ping);
+			conn.execute_unit(router, Param::new(Vec::new())).await?;
+			Ok(start.elapsed())
+		})
+	}
+}
@@ -709,6 +709,7 @@ mod tests {
 			.map(|result| {
 				let stats = Stats {
 					execution_time: Default::default(),
+					records_examined: Default::default(),
 				};
 				(stats, result)
 			})
@@ -0,0 +1,71 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::method::query::ValidQuery;
+use crate::api::method::Query;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::OnceLockExt;
+use crate::sql;
+use crate::sql::Value;
+use serde::Serialize;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A raw query future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct QueryRaw<'r, C: Connection> {
+	pub(super) query: Query<'r, C>,
+}
+
+impl<C> QueryRaw<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> QueryRaw<'static, C> {
+		QueryRaw {
+			query: self.query.into_owned(),
+		}
+	}
+
+	/// Binds a parameter or parameters to the query
+	///
+	/// See [`Query::bind`] for the accepted binding formats.
+	pub fn bind(self, bindings: impl Serialize) -> Self {
+		QueryRaw {
+			query: self.query.bind(bindings),
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for QueryRaw<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Vec<Result<Value>>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		let ValidQuery {
+			client,
+			query: statements,
+			bindings,
+			..
+		} = match self.query.inner {
+			Ok(x) => x,
+			Err(error) => return Box::pin(async move { Err(error) }),
+		};
+
+		Box::pin(async move {
+			let router = client.router.extract()?;
+			let mut query = sql::Query::default();
+			query.0 .0 = statements;
+			let param = Param::query(query, bindings);
+			let mut conn = Client::new(Method::RawQuery);
+			let response = conn.execute_query(router, param).await?;
+			Ok(response.results.into_values().map(|(_, result)| result).collect())
+		})
+	}
+}
@@ -0,0 +1,119 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::query::ValidQuery;
+use crate::api::method::Query;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::method::OnceLockExt;
+use crate::sql;
+use crate::sql::Value;
+use serde::Serialize;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// A query future which also returns execution statistics
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct QueryWithStats<'r, C: Connection> {
+	pub(super) query: Query<'r, C>,
+}
+
+impl<C> QueryWithStats<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> QueryWithStats<'static, C> {
+		QueryWithStats {
+			query: self.query.into_owned(),
+		}
+	}
+
+	/// Binds a parameter or parameters to the query
+	///
+	/// See [`Query::bind`] for the accepted binding formats.
+	pub fn bind(self, bindings: impl Serialize) -> Self {
+		QueryWithStats {
+			query: self.query.bind(bindings),
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for QueryWithStats<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<(Vec<Value>, QueryStats)>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		let ValidQuery {
+			client,
+			query: statements,
+			bindings,
+			..
+		} = match self.query.inner {
+			Ok(x) => x,
+			Err(error) => return Box::pin(async move { Err(error) }),
+		};
+
+		Box::pin(async move {
+			let router = client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::QueryStats) {
+				return Err(Error::QueryStatsNotSupported.into());
+			}
+			let mut query = sql::Query::default();
+			query.0 .0 = statements;
+			let param = Param::query(query, bindings);
+			let mut conn = Client::new(Method::QueryStats);
+			let response = conn.execute_query(router, param).await?;
+			let mut values = Vec::with_capacity(response.results.len());
+			let mut stats = QueryStats::default();
+			for (_, (statement, result)) in response.results {
+				let value = result?;
+				stats.statements.push(StatementStats {
+					duration: statement.execution_time.unwrap_or_default(),
+					rows_examined: statement.records_examined.unwrap_or_default(),
+					rows_returned: match &value {
+						Value::Array(array) => array.len() as u64,
+						Value::None => 0,
+						_ => 1,
+					},
+				});
+				values.push(value);
+			}
+			Ok((values, stats))
+		})
+	}
+}
+
+/// The execution statistics of each statement in a query
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct QueryStats {
+	/// The statistics of each statement, in the order the statements ran
+	pub statements: Vec<StatementStats>,
+}
+
+impl QueryStats {
+	/// The time taken to execute all of the statements
+	pub fn duration(&self) -> Duration {
+		self.statements.iter().map(|statement| statement.duration).sum()
+	}
+}
+
+/// The execution statistics of a single statement
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StatementStats {
+	/// The time taken to execute the statement
+	pub duration: Duration,
+	/// The number of records the statement read, whether or not they matched
+	pub rows_examined: u64,
+	/// The number of values the statement returned
+	pub rows_returned: u64,
+}
@@ -0,0 +1,70 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::RecordId;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Value;
+use crate::Surreal;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+/// A future that creates several graph edges in one transaction
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RelateMany<'r, C: Connection, R> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) edges: Vec<(RecordId, String, RecordId, Option<Value>)>,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C, R> RelateMany<'_, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> RelateMany<'static, C, R> {
+		RelateMany {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client, R> IntoFuture for RelateMany<'r, Client, R>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<Vec<R>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			if self.edges.is_empty() {
+				return Ok(Vec::new());
+			}
+			let edges: Vec<Value> = self
+				.edges
+				.into_iter()
+				.map(|(from, kind, to, data)| {
+					Value::from(vec![
+						Value::from(from),
+						Value::from(kind),
+						Value::from(to),
+						data.unwrap_or_default(),
+					])
+				})
+				.collect();
+			let router = self.client.router.extract()?;
+			let mut conn = Client::new(Method::RelateMany);
+			let value = conn.execute_value(router, Param::new(vec![edges.into()])).await?;
+			Ok(from_value(value)?)
+		})
+	}
+}
@@ -0,0 +1,63 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::RecordId;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::sql::Value;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A record diff future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SelectDiff<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) record: RecordId,
+	pub(super) expected: Result<Value>,
+}
+
+impl<C> SelectDiff<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> SelectDiff<'static, C> {
+		SelectDiff {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for SelectDiff<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Vec<Value>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::SelectDiff) {
+				return Err(Error::SelectDiffNotSupported.into());
+			}
+			let mut conn = Client::new(Method::SelectDiff);
+			let param = vec![Value::from(self.record), self.expected?];
+			match conn.execute_value(router, Param::new(param)).await? {
+				Value::Array(ops) => Ok(ops.0),
+				value => Err(Error::FromValue {
+					value,
+					error: String::from("invalid JSON Patch"),
+				}
+				.into()),
+			}
+		})
+	}
+}
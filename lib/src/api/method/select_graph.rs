@@ -0,0 +1,95 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::GraphSpec;
+use crate::api::opt::RecordId;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Table;
+use crate::sql::Value;
+use crate::Surreal;
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A graph traversal future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SelectGraph<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) start: RecordId,
+	pub(super) spec: GraphSpec,
+}
+
+impl<C> SelectGraph<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> SelectGraph<'static, C> {
+		SelectGraph {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for SelectGraph<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<Graph>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::Graph) {
+				return Err(Error::GraphNotSupported.into());
+			}
+			// The edge tables are escaped as they are written into the traversal
+			let path = match &self.spec.edges[..] {
+				[] => format!("{}?", self.spec.arrow()),
+				edges => {
+					let edges: Vec<String> =
+						edges.iter().map(|edge| Table::from(edge.as_str()).to_string()).collect();
+					format!("{}({})", self.spec.arrow(), edges.join(", "))
+				}
+			};
+			let mut conn = Client::new(Method::Graph);
+			let param = vec![Value::from(self.start), path.into(), self.spec.depth.into()];
+			let value = conn.execute_value(router, Param::new(param)).await?;
+			Ok(from_value(value)?)
+		})
+	}
+}
+
+/// The records and edges reached by traversing a graph
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Graph {
+	/// The records reached, starting with the record the traversal started from
+	pub nodes: Vec<RecordId>,
+	/// The edges followed to reach them
+	pub edges: Vec<GraphEdge>,
+}
+
+/// An edge followed while traversing a graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GraphEdge {
+	/// The ID of the edge record
+	pub id: RecordId,
+	/// The record the edge points from
+	#[serde(rename = "in")]
+	pub from: RecordId,
+	/// The record the edge points to
+	#[serde(rename = "out")]
+	pub to: RecordId,
+}
@@ -0,0 +1,84 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::Range;
+use crate::api::opt::Resource;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Id;
+use crate::sql::Value;
+use crate::Surreal;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+/// A single record select future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SelectOne<'r, C: Connection, T> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) resource: Result<Resource>,
+	pub(super) range: Option<Range<Id>>,
+	pub(super) response_type: PhantomData<T>,
+}
+
+impl<C, T> SelectOne<'_, C, T>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> SelectOne<'static, C, T> {
+		SelectOne {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+
+	/// Restricts the records selected to those in the specified range
+	pub fn range(mut self, bounds: impl Into<Range<Id>>) -> Self {
+		self.range = Some(bounds.into());
+		self
+	}
+}
+
+impl<'r, Client, T> IntoFuture for SelectOne<'r, Client, T>
+where
+	Client: Connection,
+	T: DeserializeOwned + 'r,
+{
+	type Output = Result<T>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		let SelectOne {
+			client,
+			resource,
+			range,
+			..
+		} = self;
+		Box::pin(async move {
+			let param = match range {
+				Some(range) => resource?.with_range(range)?.into(),
+				None => resource?.into(),
+			};
+			let mut conn = Client::new(Method::Select);
+			let value =
+				conn.execute_value(client.router.extract()?, Param::new(vec![param])).await?;
+			let value = match value {
+				Value::Array(mut array) => match array.len() {
+					0 => return Err(Error::NotFound.into()),
+					1 => array.0.remove(0),
+					len => return Err(Error::MultipleResults(len).into()),
+				},
+				Value::None | Value::Null => return Err(Error::NotFound.into()),
+				value => value,
+			};
+			Ok(from_value(value)?)
+		})
+	}
+}
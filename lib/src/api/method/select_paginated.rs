@@ -0,0 +1,99 @@
+use crate::api::err::Error;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Table;
+use crate::sql::Thing;
+use crate::sql::Value;
+use crate::Page;
+use crate::Surreal;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+/// Walks the records of a table one page at a time
+///
+/// Pages are read in record ID order. Each page starts right after the last ID of the
+/// previous one instead of skipping over the records already seen, so reading a page
+/// costs the same no matter how far into the table it is. The table is counted once,
+/// when the first page is read, and that total is reported on every page.
+#[derive(Debug)]
+pub struct Pager<'r, C: Connection, T> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) page_size: u32,
+	pub(super) cursor: Option<Thing>,
+	pub(super) total: Option<u64>,
+	pub(super) done: bool,
+	pub(super) response_type: PhantomData<T>,
+}
+
+impl<C, T> Pager<'_, C, T>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> Pager<'static, C, T> {
+		Pager {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<C, T> Pager<'_, C, T>
+where
+	C: Connection,
+	T: DeserializeOwned,
+{
+	/// Fetches the next page, returning `None` once the last page has been returned
+	pub async fn next_page(&mut self) -> Result<Option<Page<T>>> {
+		if self.done {
+			return Ok(None);
+		}
+		let table = Table::from(self.table.as_str());
+		// Fetch one extra record to find out whether there is a page after this one
+		let limit = u64::from(self.page_size) + 1;
+		let select = match &self.cursor {
+			Some(cursor) => format!("SELECT * FROM {cursor}>.. LIMIT {limit}"),
+			None => format!("SELECT * FROM {table} LIMIT {limit}"),
+		};
+		// Only the first page counts the table, as counting is a full scan
+		let query = match self.total {
+			Some(_) => select,
+			None => format!("{select}; SELECT count() FROM {table} GROUP ALL"),
+		};
+		let mut response = self.client.query(query).await?.check()?;
+		let invalid = |value: Value, error: &str| Error::FromValue {
+			value,
+			error: error.to_owned(),
+		};
+		let mut records = match response.take::<Value>(0)? {
+			Value::Array(records) => records,
+			value => return Err(invalid(value, "expected an array of records").into()),
+		};
+		let total = match self.total {
+			Some(total) => total,
+			None => {
+				let total: Option<u64> = response.take((1, "count"))?;
+				let total = total.unwrap_or_default();
+				self.total = Some(total);
+				total
+			}
+		};
+		let has_next = records.len() as u64 == limit;
+		records.truncate(self.page_size as usize);
+		if let Some(record) = records.last() {
+			match record.clone().record() {
+				Some(id) => self.cursor = Some(id),
+				None => return Err(invalid(record.clone(), "expected a record with an ID").into()),
+			}
+		}
+		self.done = !has_next;
+		Ok(Some(Page {
+			items: from_value(records.into())?,
+			total,
+			has_next,
+		}))
+	}
+}
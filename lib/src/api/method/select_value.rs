@@ -0,0 +1,67 @@
+use crate::api::err::Error;
+use crate::api::method::Query;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Value;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+/// A single value query future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SelectValue<'r, C: Connection, T> {
+	pub(super) query: Query<'r, C>,
+	pub(super) response_type: PhantomData<T>,
+}
+
+impl<'r, C, T> SelectValue<'r, C, T>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> SelectValue<'static, C, T> {
+		SelectValue {
+			query: self.query.into_owned(),
+			response_type: PhantomData,
+		}
+	}
+
+	/// Binds a parameter or parameters to the query
+	///
+	/// See [`Query::bind`] for the accepted binding formats.
+	pub fn bind(self, bindings: impl Serialize) -> Self {
+		SelectValue {
+			query: self.query.bind(bindings),
+			response_type: PhantomData,
+		}
+	}
+}
+
+impl<'r, Client, T> IntoFuture for SelectValue<'r, Client, T>
+where
+	Client: Connection,
+	T: DeserializeOwned + 'r,
+{
+	type Output = Result<T>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut response = self.query.await?;
+			let value = match response.take::<Value>(0)? {
+				Value::Array(mut array) => match array.len() {
+					1 => array.0.remove(0),
+					len => return Err(Error::NotSingleValue(len).into()),
+				},
+				Value::None => return Err(Error::NotSingleValue(0).into()),
+				value => value,
+			};
+			Ok(from_value(value)?)
+		})
+	}
+}
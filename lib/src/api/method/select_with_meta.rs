@@ -0,0 +1,98 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::Resource;
+use crate::api::Connection;
+use crate::api::ExtraFeatures;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Value;
+use crate::RecordWithMeta;
+use crate::Surreal;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+/// A select future which also returns the metadata of each record
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SelectWithMeta<'r, C: Connection, T> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) resource: Resource,
+	pub(super) response_type: PhantomData<T>,
+}
+
+impl<C, T> SelectWithMeta<'_, C, T>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> SelectWithMeta<'static, C, T> {
+		SelectWithMeta {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client, T> IntoFuture for SelectWithMeta<'r, Client, T>
+where
+	Client: Connection,
+	T: DeserializeOwned + 'r,
+{
+	type Output = Result<Vec<RecordWithMeta<T>>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			if !router.features.contains(&ExtraFeatures::RecordMeta) {
+				return Err(Error::RecordMetaNotSupported.into());
+			}
+			let mut conn = Client::new(Method::SelectMeta);
+			let value = conn.execute_value(router, Param::new(vec![self.resource.into()])).await?;
+			into_records(value)
+		})
+	}
+}
+
+/// Splits the records returned by the database into their metadata and data
+fn into_records<T: DeserializeOwned>(value: Value) -> Result<Vec<RecordWithMeta<T>>> {
+	let invalid = |value: Value| Error::FromValue {
+		value,
+		error: String::from("invalid record metadata"),
+	};
+	let Value::Array(records) = value else {
+		return Err(invalid(value).into());
+	};
+	let mut out = Vec::with_capacity(records.len());
+	for record in records {
+		let Value::Object(mut record) = record else {
+			return Err(invalid(record).into());
+		};
+		let Some(Value::Thing(id)) = record.remove("id") else {
+			return Err(invalid(record.into()).into());
+		};
+		let version = match record.remove("version") {
+			Some(Value::None) | None => None,
+			Some(version) => Some(u64::try_from(version.clone()).map_err(|_| invalid(version))?),
+		};
+		let last_modified = match record.remove("last_modified") {
+			Some(Value::Datetime(time)) => Some(time),
+			Some(Value::None) | None => None,
+			Some(time) => return Err(invalid(time).into()),
+		};
+		let data = from_value(record.remove("data").unwrap_or_default())?;
+		out.push(RecordWithMeta {
+			id,
+			version,
+			last_modified,
+			data,
+		});
+	}
+	Ok(out)
+}
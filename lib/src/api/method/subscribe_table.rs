@@ -0,0 +1,161 @@
+use crate::api::method::live::Stream;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::Notification;
+use crate::Surreal;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::time::sleep;
+#[cfg(target_arch = "wasm32")]
+use wasmtimer::tokio::sleep;
+
+/// How many times in a row subscribing again may fail before the subscription ends
+const MAX_RETRIES: u32 = 5;
+/// How long to wait before trying to subscribe again after a failure
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+type LiveStream<C, R> = Stream<'static, C, Vec<R>>;
+type Subscribing<C, R> =
+	Pin<Box<dyn Future<Output = Result<LiveStream<C, R>>> + Send + Sync + 'static>>;
+
+/// A future which subscribes to every change on a table
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SubscribeTable<'r, C: Connection, R> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) table: String,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C, R> SubscribeTable<'_, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> SubscribeTable<'static, C, R> {
+		SubscribeTable {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client, R> IntoFuture for SubscribeTable<'r, Client, R>
+where
+	Client: Connection,
+	R: DeserializeOwned + Unpin + Send + Sync + 'static,
+{
+	type Output = Result<Subscription<Client, R>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let client = self.client.into_owned();
+			let stream = subscribe(client.clone(), self.table.clone(), Duration::ZERO).await?;
+			Ok(Subscription {
+				client,
+				table: self.table,
+				retries: 0,
+				state: State::Live(stream),
+			})
+		})
+	}
+}
+
+fn subscribe<C, R>(client: Surreal<C>, table: String, delay: Duration) -> Subscribing<C, R>
+where
+	C: Connection,
+	R: DeserializeOwned + Unpin + Send + Sync + 'static,
+{
+	Box::pin(async move {
+		if !delay.is_zero() {
+			sleep(delay).await;
+		}
+		client.select::<Vec<R>>(table).into_owned().live().await
+	})
+}
+
+enum State<C: Connection, R> {
+	Live(LiveStream<C, R>),
+	Subscribing(Subscribing<C, R>),
+	Closed,
+}
+
+/// A stream of the changes made to every record on a table
+///
+/// Dropping the stream kills the live query behind it.
+#[must_use = "streams do nothing unless you poll them"]
+pub struct Subscription<C: Connection, R> {
+	client: Surreal<C>,
+	table: String,
+	retries: u32,
+	state: State<C, R>,
+}
+
+// Nothing is pinned in place, the pending subscription is boxed
+impl<C: Connection, R> Unpin for Subscription<C, R> {}
+
+impl<C, R> std::fmt::Debug for Subscription<C, R>
+where
+	C: Connection,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Subscription").field("table", &self.table).finish()
+	}
+}
+
+impl<C, R> futures::Stream for Subscription<C, R>
+where
+	C: Connection,
+	R: DeserializeOwned + Unpin + Send + Sync + 'static,
+{
+	type Item = Result<Notification<R>>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = &mut *self;
+		loop {
+			match &mut this.state {
+				State::Live(stream) => match stream.poll_next_unpin(cx) {
+					// The live query was closed underneath us, so subscribe again
+					Poll::Ready(None) => {
+						this.state = State::Subscribing(subscribe(
+							this.client.clone(),
+							this.table.clone(),
+							Duration::ZERO,
+						));
+					}
+					poll => return poll,
+				},
+				State::Subscribing(future) => match future.as_mut().poll(cx) {
+					Poll::Ready(Ok(stream)) => {
+						this.retries = 0;
+						this.state = State::Live(stream);
+					}
+					Poll::Ready(Err(error)) => {
+						this.retries += 1;
+						this.state = match this.retries < MAX_RETRIES {
+							true => State::Subscribing(subscribe(
+								this.client.clone(),
+								this.table.clone(),
+								RETRY_DELAY,
+							)),
+							false => State::Closed,
+						};
+						return Poll::Ready(Some(Err(error)));
+					}
+					Poll::Pending => return Poll::Pending,
+				},
+				State::Closed => return Poll::Ready(None),
+			}
+		}
+	}
+}
@@ -11,16 +11,21 @@ use crate::api::opt::auth::Jwt;
 use crate::api::opt::auth::Namespace;
 use crate::api::opt::auth::Record;
 use crate::api::opt::auth::Root;
+use crate::api::opt::GraphSpec;
 use crate::api::opt::PatchOp;
 use crate::api::Response as QueryResponse;
 use crate::api::Surreal;
 use crate::sql::statements::BeginStatement;
 use crate::sql::statements::CommitStatement;
+use crate::sql::thing;
+use crate::sql::Value;
+use crate::UpsertedRecord;
 use once_cell::sync::Lazy;
 use protocol::Client;
 use protocol::Test;
 use semver::Version;
 use std::ops::Bound;
+use std::time::Duration;
 use types::User;
 use types::USER;
 
@@ -110,17 +115,26 @@ async fn api() {
 		.query(CommitStatement::default())
 		.await
 		.unwrap();
+	let _: QueryResponse = DB
+		.transaction_with_retry(3, |_| async { Ok("UPDATE account:one SET balance += 300.00") })
+		.await
+		.unwrap();
+	let _ = DB.query_with_stats("SELECT * FROM user").await.unwrap();
+	let _ = DB.explain_cost("SELECT * FROM user").await.unwrap();
 
 	// create
 	let _: Vec<User> = DB.create(USER).await.unwrap();
 	let _: Option<User> = DB.create((USER, "john")).await.unwrap();
 	let _: Vec<User> = DB.create(USER).content(User::default()).await.unwrap();
 	let _: Option<User> = DB.create((USER, "john")).content(User::default()).await.unwrap();
+	let _ = DB.create_if_not_exists::<User>((USER, "john")).await.unwrap();
+	let _ = DB.create_if_not_exists::<User>((USER, "john")).content(User::default()).await.unwrap();
 
 	// select
 	let _: Vec<User> = DB.select(USER).await.unwrap();
 	let _: Option<User> = DB.select((USER, "john")).await.unwrap();
 	let _: Vec<User> = DB.select(USER).range(..).await.unwrap();
+	let _ = DB.select_with_meta::<User>(USER).await.unwrap();
 	let _: Vec<User> = DB.select(USER).range(.."john").await.unwrap();
 	let _: Vec<User> = DB.select(USER).range(..="john").await.unwrap();
 	let _: Vec<User> = DB.select(USER).range("jane"..).await.unwrap();
@@ -159,13 +173,40 @@ async fn api() {
 	let _: Vec<User> = DB.delete(USER).await.unwrap();
 	let _: Option<User> = DB.delete((USER, "john")).await.unwrap();
 	let _: Vec<User> = DB.delete(USER).range("jane".."john").await.unwrap();
+	let _: u64 = DB.delete_where(USER, "age < 18").await.unwrap();
+
+	// changes
+	let _ = DB.changes(USER, 0, 10).await.unwrap();
+	let edge = (thing("user:john").unwrap(), "knows".to_owned(), thing("user:jane").unwrap(), None);
+	let _: Vec<User> = DB.relate_many(vec![edge]).await.unwrap();
+	let record = (thing("user:john").unwrap(), Value::None);
+	let _: Vec<UpsertedRecord<User>> = DB.upsert_many(vec![record]).await.unwrap();
 
 	// export
 	let _: () = DB.export("backup.sql").await.unwrap();
+	let _: String = DB.export_schema().await.unwrap();
+
+	// index stats
+	let _ = DB.index_stats(USER, "name").await.unwrap();
+
+	// select graph
+	let _ = DB.select_diff((USER, "john"), User::default()).await.unwrap();
+	let token = DB.lock_record((USER, "john"), Duration::from_secs(10)).await.unwrap();
+	DB.unlock_record((USER, "john"), token).await.unwrap();
+	let _ = DB.select_graph((USER, "john"), GraphSpec::new().edge("knows").depth(2)).await.unwrap();
 
 	// import
 	let _: () = DB.import("backup.sql").await.unwrap();
 
+	// metrics
+	let _ = DB.metrics().await.unwrap();
+	let _ = DB.batch(vec![String::from("SELECT * FROM user")]).parallelism(2).await.unwrap();
+	let build = DB.define_index_async("DEFINE INDEX foo ON bar FIELDS baz").await.unwrap();
+	build.await.unwrap();
+
+	// ping
+	let _ = DB.ping().await.unwrap();
+
 	// version
 	let _: Version = DB.version().await.unwrap();
 }
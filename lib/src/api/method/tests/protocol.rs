@@ -1,10 +1,12 @@
 use super::server;
+use crate::api::conn::route_channel;
 use crate::api::conn::Connection;
 use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
 use crate::api::conn::Router;
+use crate::api::conn::StatementCache;
 use crate::api::opt::Endpoint;
 use crate::api::opt::IntoEndpoint;
 use crate::api::Connect;
@@ -69,13 +71,25 @@ impl Connection for Client {
 		capacity: usize,
 	) -> Pin<Box<dyn Future<Output = Result<Surreal<Self>>> + Send + Sync + 'static>> {
 		Box::pin(async move {
-			let (route_tx, route_rx) = flume::bounded(capacity);
+			let statement_cache = StatementCache::new(_address.config.statement_cache_size);
+			let (route_tx, route_rx) = route_channel(capacity);
 			let mut features = HashSet::new();
 			features.insert(ExtraFeatures::Backup);
+			features.insert(ExtraFeatures::IndexBuilds);
+			features.insert(ExtraFeatures::Metrics);
+			features.insert(ExtraFeatures::CreateIfAbsent);
+			features.insert(ExtraFeatures::ExportSchema);
+			features.insert(ExtraFeatures::Graph);
+			features.insert(ExtraFeatures::QueryStats);
+			features.insert(ExtraFeatures::ExplainCost);
+			features.insert(ExtraFeatures::IndexStats);
+			features.insert(ExtraFeatures::RecordMeta);
+			features.insert(ExtraFeatures::SelectDiff);
 			let router = Router {
 				features,
 				sender: route_tx,
 				last_id: AtomicI64::new(0),
+				statement_cache,
 			};
 			server::mock(route_rx);
 			Ok(Surreal::new_from_router_waiter(
@@ -2,15 +2,20 @@ use super::types::User;
 use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Route;
+use crate::api::conn::RouteReceiver;
+use crate::api::method::Graph;
+use crate::api::method::IndexStatistics;
+use crate::api::method::MetricsSnapshot;
+use crate::api::method::QueryCost;
 use crate::api::Response as QueryResponse;
 use crate::sql::to_value;
 use crate::sql::Value;
-use flume::Receiver;
 use futures::StreamExt;
+use std::collections::BTreeMap;
 
-pub(super) fn mock(route_rx: Receiver<Option<Route>>) {
+pub(super) fn mock(route_rx: RouteReceiver) {
 	tokio::spawn(async move {
-		let mut stream = route_rx.into_stream();
+		let mut stream = route_rx;
 
 		while let Some(Some(Route {
 			request,
@@ -19,9 +24,17 @@ pub(super) fn mock(route_rx: Receiver<Option<Route>>) {
 		{
 			let (_, method, param) = request;
 			let mut params = param.other;
+			if let Some(sender) = param.index_build_sender {
+				let _ = sender.try_send(Ok(()));
+			}
 
 			let result = match method {
-				Method::Invalidate | Method::Health => match &params[..] {
+				Method::Batch => unreachable!(),
+				Method::DefineIndexAsync => match &params[..] {
+					[] => Ok(DbResponse::Other(Value::None)),
+					_ => unreachable!(),
+				},
+				Method::Invalidate | Method::Health | Method::Ping => match &params[..] {
 					[] => Ok(DbResponse::Other(Value::None)),
 					_ => unreachable!(),
 				},
@@ -35,6 +48,10 @@ pub(super) fn mock(route_rx: Receiver<Option<Route>>) {
 					)),
 					_ => unreachable!(),
 				},
+				Method::Metrics => match &params[..] {
+					[] => Ok(DbResponse::Other(to_value(MetricsSnapshot::default()).unwrap())),
+					_ => unreachable!(),
+				},
 				Method::Version => match &params[..] {
 					[] => Ok(DbResponse::Other("1.0.0".into())),
 					_ => unreachable!(),
@@ -51,15 +68,28 @@ pub(super) fn mock(route_rx: Receiver<Option<Route>>) {
 					[_, _] => Ok(DbResponse::Other(Value::None)),
 					_ => unreachable!(),
 				},
-				Method::Query => match param.query {
+				Method::Query | Method::RawQuery | Method::QueryStats => match param.query {
 					Some(_) => Ok(DbResponse::Query(QueryResponse::new())),
 					_ => unreachable!(),
 				},
+				Method::ExplainCost => match param.query {
+					Some(_) => Ok(DbResponse::Other(to_value(QueryCost::default()).unwrap())),
+					_ => unreachable!(),
+				},
 				Method::Create => match &params[..] {
 					[_] => Ok(DbResponse::Other(to_value(User::default()).unwrap())),
 					[_, user] => Ok(DbResponse::Other(user.clone())),
 					_ => unreachable!(),
 				},
+				Method::CreateIfAbsent => match &params[..] {
+					[Value::Thing(..)] | [Value::Thing(..), _] => {
+						let mut object = BTreeMap::new();
+						object.insert("created".to_owned(), Value::from(true));
+						object.insert("record".to_owned(), to_value(User::default()).unwrap());
+						Ok(DbResponse::Other(Value::from(object)))
+					}
+					_ => unreachable!(),
+				},
 				Method::Select | Method::Delete => match &params[..] {
 					[Value::Thing(..)] => Ok(DbResponse::Other(to_value(User::default()).unwrap())),
 					[Value::Table(..) | Value::Array(..) | Value::Range(..)] => {
@@ -67,6 +97,12 @@ pub(super) fn mock(route_rx: Receiver<Option<Route>>) {
 					}
 					_ => unreachable!(),
 				},
+				Method::SelectMeta => match &params[..] {
+					[Value::Thing(..) | Value::Table(..) | Value::Array(..) | Value::Range(..)] => {
+						Ok(DbResponse::Other(Value::Array(Default::default())))
+					}
+					_ => unreachable!(),
+				},
 				Method::Upsert | Method::Update | Method::Merge | Method::Patch => {
 					match &params[..] {
 						[Value::Thing(..)] | [Value::Thing(..), _] => {
@@ -88,6 +124,56 @@ pub(super) fn mock(route_rx: Receiver<Option<Route>>) {
 					}
 					_ => unreachable!(),
 				},
+				Method::ShowChanges => match &params[..] {
+					[Value::Table(..), _, _] => {
+						Ok(DbResponse::Other(Value::Array(Default::default())))
+					}
+					_ => unreachable!(),
+				},
+				Method::DeleteWhere => match &params[..] {
+					[Value::Table(..), Value::Strand(..)] => Ok(DbResponse::Other(0.into())),
+					_ => unreachable!(),
+				},
+				Method::RelateMany => match &params[..] {
+					[Value::Array(..)] => Ok(DbResponse::Other(Value::Array(Default::default()))),
+					_ => unreachable!(),
+				},
+				Method::UpsertMany => match &params[..] {
+					[Value::Array(..)] => Ok(DbResponse::Other(Value::Array(Default::default()))),
+					_ => unreachable!(),
+				},
+				Method::ExportSchema => match &params[..] {
+					[] => Ok(DbResponse::Other(Value::from("OPTION IMPORT;"))),
+					_ => unreachable!(),
+				},
+				Method::IndexStats => match &params[..] {
+					[Value::Table(..), Value::Strand(..)] => {
+						Ok(DbResponse::Other(to_value(IndexStatistics::default()).unwrap()))
+					}
+					_ => unreachable!(),
+				},
+				Method::SelectDiff => match &params[..] {
+					[Value::Thing(..), _] => {
+						Ok(DbResponse::Other(Value::Array(Default::default())))
+					}
+					_ => unreachable!(),
+				},
+				Method::Lock => match &params[..] {
+					[Value::Thing(..), Value::Duration(..), claim @ Value::Uuid(..)] => {
+						Ok(DbResponse::Other(claim.clone()))
+					}
+					_ => unreachable!(),
+				},
+				Method::Unlock => match &params[..] {
+					[Value::Thing(..), Value::Uuid(..)] => Ok(DbResponse::Other(Value::None)),
+					_ => unreachable!(),
+				},
+				Method::Graph => match &params[..] {
+					[Value::Thing(..), Value::Strand(..), Value::Number(..)] => {
+						Ok(DbResponse::Other(to_value(Graph::default()).unwrap()))
+					}
+					_ => unreachable!(),
+				},
 				Method::Export | Method::Import => match param.file {
 					Some(_) => Ok(DbResponse::Other(Value::None)),
 					_ => unreachable!(),
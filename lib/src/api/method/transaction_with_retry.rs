@@ -0,0 +1,72 @@
+use crate::api::opt::IntoQuery;
+use crate::api::Connection;
+use crate::api::Response;
+use crate::api::Result;
+use crate::error::Db;
+use crate::sql::statements::BeginStatement;
+use crate::sql::statements::CommitStatement;
+use crate::sql::Statement;
+use crate::Surreal;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::pin::Pin;
+
+/// A transaction future which is retried when it conflicts with a concurrent transaction
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TransactionWithRetry<'r, C: Connection, F> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) max_attempts: u32,
+	pub(super) body: F,
+}
+
+impl<C, F> TransactionWithRetry<'_, C, F>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> TransactionWithRetry<'static, C, F> {
+		TransactionWithRetry {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client, F, Fut, Q> IntoFuture for TransactionWithRetry<'r, Client, F>
+where
+	Client: Connection,
+	F: FnMut(Surreal<Client>) -> Fut + Send + Sync + 'r,
+	Fut: Future<Output = Result<Q>> + Send + Sync + 'r,
+	Q: IntoQuery,
+{
+	type Output = Result<Response>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(mut self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let mut attempt = 1;
+			loop {
+				let body = (self.body)(self.client.clone().into_owned()).await?.into_query()?;
+				let mut statements = Vec::with_capacity(body.len() + 2);
+				statements.push(Statement::Begin(BeginStatement::default()));
+				statements.extend(body);
+				statements.push(Statement::Commit(CommitStatement::default()));
+				let response = self.client.query(statements).await?;
+				if attempt >= self.max_attempts || !conflicted(&response) {
+					return Ok(response);
+				}
+				attempt += 1;
+			}
+		})
+	}
+}
+
+/// Whether the transaction failed to commit because of a concurrent transaction
+fn conflicted(response: &Response) -> bool {
+	response
+		.results
+		.values()
+		.any(|(_, result)| matches!(result, Err(crate::Error::Db(Db::TxRetryable))))
+}
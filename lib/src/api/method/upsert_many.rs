@@ -0,0 +1,92 @@
+use crate::api::conn::Method;
+use crate::api::conn::Param;
+use crate::api::err::Error;
+use crate::api::method::OnceLockExt;
+use crate::api::opt::RecordId;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::sql::from_value;
+use crate::sql::Value;
+use crate::Surreal;
+use crate::UpsertedRecord;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::future::Future;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+/// A future that upserts several records in one transaction
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct UpsertMany<'r, C: Connection, R> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) records: Vec<(RecordId, Value)>,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C, R> UpsertMany<'_, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> UpsertMany<'static, C, R> {
+		UpsertMany {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client, R> IntoFuture for UpsertMany<'r, Client, R>
+where
+	Client: Connection,
+	R: DeserializeOwned + 'r,
+{
+	type Output = Result<Vec<UpsertedRecord<R>>>;
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			if self.records.is_empty() {
+				return Ok(Vec::new());
+			}
+			let records: Vec<Value> = self
+				.records
+				.into_iter()
+				.map(|(id, data)| Value::from(vec![Value::from(id), data]))
+				.collect();
+			let router = self.client.router.extract()?;
+			let mut conn = Client::new(Method::UpsertMany);
+			let value = conn.execute_value(router, Param::new(vec![records.into()])).await?;
+			into_upserted(value)
+		})
+	}
+}
+
+fn into_upserted<R: DeserializeOwned>(value: Value) -> Result<Vec<UpsertedRecord<R>>> {
+	let invalid = |value: Value| Error::FromValue {
+		value,
+		error: String::from("invalid upsert response"),
+	};
+	let Value::Array(records) = value else {
+		return Err(invalid(value).into());
+	};
+	let mut out = Vec::with_capacity(records.len());
+	for record in records {
+		let Value::Object(mut record) = record else {
+			return Err(invalid(record).into());
+		};
+		let Some(Value::Thing(id)) = record.remove("id") else {
+			return Err(invalid(record.into()).into());
+		};
+		let created = record.remove("created").is_some_and(|created| created.is_true());
+		let record = from_value(record.remove("record").unwrap_or_default())?;
+		out.push(UpsertedRecord {
+			id,
+			created,
+			record,
+		});
+	}
+	Ok(out)
+}
@@ -162,7 +162,18 @@ where
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub(crate) enum ExtraFeatures {
 	Backup,
+	Batch,
+	CreateIfAbsent,
+	ExplainCost,
+	ExportSchema,
+	Graph,
+	IndexBuilds,
+	IndexStats,
 	LiveQueries,
+	Metrics,
+	QueryStats,
+	RecordMeta,
+	SelectDiff,
 }
 
 /// A database client instance for embedded or remote databases
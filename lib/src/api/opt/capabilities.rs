@@ -48,6 +48,11 @@ use surrealdb_core::dbs::capabilities::{
 ///
 /// Will allow all and only all `http::*` functions except the function `http::post`.
 ///
+/// User-defined functions are filtered the same way under the `fn` family, and a trailing
+/// wildcard may follow a nested module. For example, `fn::reports::*` matches
+/// `fn::reports::daily` but not `fn::admin::reset`. Denied functions are rejected before
+/// they run.
+///
 /// Examples:
 /// - Allow all functions: `--allow-funcs`
 /// - Allow all functions except `http.*`: `--allow-funcs --deny-funcs 'http.*'`
@@ -1,4 +1,11 @@
+use super::ErrorHook;
+use super::ExperimentalFeature;
+use super::QueryContext;
+use super::QueryLog;
+use super::QueryLogEntry;
 use crate::opt::capabilities::Capabilities;
+use crate::sql::Permission;
+use std::collections::HashSet;
 #[cfg(any(
 	feature = "kv-mem",
 	feature = "kv-surrealkv",
@@ -7,9 +14,12 @@ use crate::opt::capabilities::Capabilities;
 	feature = "kv-tikv",
 ))]
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use surrealdb_core::{dbs::Capabilities as CoreCapabilities, iam::Level};
 
+pub use surrealdb_core::kvs::IsolationLevel;
+
 /// Configuration for server connection, including: strictness, notifications, query_timeout, transaction_timeout
 #[derive(Debug, Clone, Default)]
 pub struct Config {
@@ -17,8 +27,27 @@ pub struct Config {
 	pub(crate) notifications: bool,
 	pub(crate) query_timeout: Option<Duration>,
 	pub(crate) transaction_timeout: Option<Duration>,
+	pub(crate) max_result_rows: Option<usize>,
+	pub(crate) max_statements_per_query: Option<usize>,
+	pub(crate) max_bound_params: Option<usize>,
+	pub(crate) max_concurrent_queries: usize,
+	pub(crate) deny_full_scans: bool,
+	pub(crate) full_scan_threshold: Option<u64>,
+	pub(crate) statement_cache_size: usize,
+	pub(crate) isolation: Option<IsolationLevel>,
+	pub(crate) flush_interval: Option<Duration>,
+	pub(crate) connect_retry: Option<(u32, Duration)>,
+	pub(crate) session_expiry: Option<Duration>,
+	pub(crate) allow_guest: Option<bool>,
+	pub(crate) guest_permissions: Option<Permission>,
+	pub(crate) timezone: Option<String>,
+	pub(crate) query_log: Option<QueryLog>,
+	pub(crate) error_hook: Option<ErrorHook>,
+	pub(crate) experimental: HashSet<ExperimentalFeature>,
 	#[cfg(any(feature = "native-tls", feature = "rustls"))]
 	pub(crate) tls_config: Option<super::Tls>,
+	#[cfg(any(feature = "native-tls", feature = "rustls"))]
+	pub(crate) tls: Option<super::TlsConfig>,
 	// Only used by the local engines
 	// `Level::No` in this context means no authentication information was configured
 	pub(crate) auth: Level,
@@ -86,6 +115,215 @@ impl Config {
 		self
 	}
 
+	/// Set the maximum number of rows a single `SELECT` may return
+	///
+	/// `SELECT` statements without a `LIMIT` of their own stop once they return more rows than
+	/// the maximum, and their result is replaced by an error, as is the result of any `SELECT`
+	/// whose own `LIMIT` lets it return more. Aggregate queries returning few rows and results
+	/// of other statements are unaffected. This only applies to embedded databases. Results
+	/// are unbounded by default.
+	pub fn max_result_rows(mut self, rows: impl Into<Option<usize>>) -> Self {
+		self.max_result_rows = rows.into();
+		self
+	}
+
+	/// Set the maximum number of statements a single query may contain
+	///
+	/// Queries with more statements are rejected before any of them are executed. Comments and
+	/// empty statements are not counted. This only applies to embedded databases. Queries are
+	/// unbounded by default.
+	pub fn max_statements_per_query(mut self, statements: impl Into<Option<usize>>) -> Self {
+		self.max_statements_per_query = statements.into();
+		self
+	}
+
+	/// Set the maximum number of parameters a single query may bind
+	///
+	/// Queries binding more parameters are rejected before any of their statements are
+	/// executed. Variables set on the connection with [`set`](crate::Surreal::set) are not
+	/// counted. This only applies to embedded databases. Parameters are unbounded by default.
+	pub fn max_bound_params(mut self, params: impl Into<Option<usize>>) -> Self {
+		self.max_bound_params = params.into();
+		self
+	}
+
+	/// Set the maximum number of queries an embedded database executes at the same time
+	///
+	/// With a limit set, queries and other requests which only read or write records run in
+	/// the background, so requests sent over a connection without waiting for each other
+	/// execute concurrently, up to the limit. Requests beyond it wait for a free slot rather than
+	/// being rejected. The queries of a [`batch`](crate::Surreal::batch) and index builds started
+	/// with [`define_index_async`](crate::Surreal::define_index_async) share the same slots.
+	/// Requests which change the state of the connection, such as signing in or setting a
+	/// variable, don't take a slot. This only applies to embedded databases. A limit of `0`, the
+	/// default, means queries are not limited, although requests sent over a connection are then
+	/// executed one after another.
+	pub fn max_concurrent_queries(mut self, queries: usize) -> Self {
+		self.max_concurrent_queries = queries;
+		self
+	}
+
+	/// Set whether queries which would scan a whole large table are rejected
+	///
+	/// Before a query is executed, the plan of each of its `SELECT` statements is explained.
+	/// When a statement would iterate over a table without using an index, and the table holds
+	/// more records than the [`full_scan_threshold`](Self::full_scan_threshold), the query is
+	/// rejected before any of its statements are executed. This also applies to selects
+	/// without a `WHERE` clause. This only applies to embedded databases. Full scans are
+	/// allowed by default.
+	pub fn deny_full_scans(mut self, deny: bool) -> Self {
+		self.deny_full_scans = deny;
+		self
+	}
+
+	/// Set how many records a table may hold before full scans of it are denied
+	///
+	/// Only used when full scans are denied with [`deny_full_scans`](Self::deny_full_scans).
+	/// Defaults to 1000 records.
+	pub fn full_scan_threshold(mut self, records: u64) -> Self {
+		self.full_scan_threshold = Some(records);
+		self
+	}
+
+	/// Set how many parsed queries to keep for reuse
+	///
+	/// Queries passed as SurrealQL text are parsed before they are sent to the database. With a
+	/// cache, the statements parsed from the same text are reused instead, which avoids parsing
+	/// queries which are run repeatedly with different bindings. Bindings are never cached.
+	/// The least recently used queries are evicted first. Caching is disabled by default.
+	pub fn statement_cache_size(mut self, size: usize) -> Self {
+		self.statement_cache_size = size;
+		self
+	}
+
+	/// Set the isolation level of transactions on embedded databases
+	///
+	/// With [`IsolationLevel::Snapshot`], concurrent transactions only conflict when they write
+	/// the same records. [`IsolationLevel::Serializable`] also rejects a transaction when the
+	/// records it read were changed by a concurrent commit. Connecting fails if the storage
+	/// engine cannot provide the requested level. By default, each engine uses its own level.
+	pub fn isolation(mut self, level: IsolationLevel) -> Self {
+		self.isolation = Some(level);
+		self
+	}
+
+	/// Sync writes to disk at most once per interval on embedded databases
+	///
+	/// Commits are grouped together between syncs, which speeds up bulk loads on the `rocksdb`
+	/// and `surrealkv` engines. **Warning:** if the machine crashes or loses power before the
+	/// next sync, the writes committed since the last one are lost, even though their queries
+	/// succeeded. A clean shutdown does not lose any writes. A zero interval syncs every commit. Connecting fails if the storage
+	/// engine manages its own durability.
+	pub fn flush_interval(mut self, interval: Duration) -> Self {
+		self.flush_interval = Some(interval);
+		self
+	}
+
+	/// Set how many times an embedded database tries to open its storage before giving up
+	///
+	/// When the storage engine can't be opened or reached, such as a TiKV cluster which is
+	/// still starting up, connecting waits for the backoff and tries again, doubling the wait
+	/// after each failed attempt. Connecting resolves once the storage is reachable or the
+	/// attempts run out. Other errors fail the connection straight away. The storage is only
+	/// tried once by default.
+	pub fn connect_retry(mut self, attempts: u32, backoff: Duration) -> Self {
+		self.connect_retry = Some((attempts, backoff));
+		self
+	}
+
+	/// Set how long a signed in session may stay idle before it expires
+	///
+	/// Once a session has expired, requests to an embedded database are rejected with
+	/// [`Error::SessionExpired`](crate::error::Api::SessionExpired) until the connection signs
+	/// in or authenticates again. Health and version checks are still answered. Sessions
+	/// never expire by default.
+	pub fn session_expiry(mut self, expiry: Duration) -> Self {
+		self.session_expiry = Some(expiry);
+		self
+	}
+
+	/// Set whether sessions which have not signed in may run queries on an embedded database
+	///
+	/// When guests are not allowed, requests from a session which has not signed in or
+	/// authenticated are rejected with [`Error::NotAuthenticated`](crate::error::Api::NotAuthenticated),
+	/// except for signing in and health and version checks. When they are allowed, guests can
+	/// access whatever the permissions of the database let anyone access, even when a root
+	/// user is configured. This overrides the guest access set in the
+	/// [`capabilities`](Self::capabilities).
+	pub fn allow_guest(mut self, allow: bool) -> Self {
+		self.allow_guest = Some(allow);
+		self
+	}
+
+	/// Set the permission guests fall back to on tables which don't specify their own
+	///
+	/// A table defined without a `PERMISSIONS` clause, or created implicitly by writing to it,
+	/// lets guests read it as this permission allows. Guests still can't create, update or
+	/// delete records on such tables. Tables with permissions of their own and sessions which
+	/// have signed in are unaffected. This only applies to embedded databases.
+	pub fn guest_permissions(mut self, permission: Permission) -> Self {
+		self.guest_permissions = Some(permission);
+		self
+	}
+
+	/// Set the default timezone, as an IANA name such as `Europe/London`
+	///
+	/// Functions which format datetimes without an explicit timezone, such as `time::format`,
+	/// use this timezone instead of UTC. Datetimes are still stored in UTC. This only applies
+	/// to embedded databases, and connecting fails if the name is not a known timezone.
+	pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+		self.timezone = Some(timezone.into());
+		self
+	}
+
+	/// Set a sink which receives an entry for every request an embedded database executes
+	///
+	/// Each entry records the statement, the user and the namespace and database it ran in,
+	/// when it started, how long it took and whether it failed. The sink is called on a
+	/// separate thread, in the order the requests ran, so a slow sink does not hold up other
+	/// requests. Requests sent to remote servers are not logged.
+	pub fn query_log(mut self, sink: Arc<dyn Fn(QueryLogEntry) + Send + Sync>) -> Self {
+		self.query_log = Some(QueryLog(sink));
+		self
+	}
+
+	/// Set a hook which observes every error an embedded database returns
+	///
+	/// The hook is called with each error before it is returned, along with the request that
+	/// produced it. A query calls the hook once for every statement which failed, with the
+	/// failed statement as its context. The hook can't change the error, and it runs before
+	/// the next request is handled, so it should return quickly. Errors returned by remote
+	/// servers are not observed.
+	#[allow(clippy::type_complexity)]
+	pub fn error_hook(
+		mut self,
+		hook: Arc<dyn Fn(&crate::Error, &QueryContext) + Send + Sync>,
+	) -> Self {
+		self.error_hook = Some(ErrorHook(hook));
+		self
+	}
+
+	/// Resolves the capabilities, with guest access overridden if it was set
+	#[allow(dead_code)] // used by the embedded engines
+	pub(crate) fn resolve_capabilities(&self) -> CoreCapabilities {
+		match self.allow_guest {
+			Some(allow) => self.capabilities.clone().with_guest_access(allow),
+			None => self.capabilities.clone(),
+		}
+	}
+
+	/// Resolves the configured default timezone, if any
+	#[allow(dead_code)] // used by the embedded engines
+	pub(crate) fn resolve_timezone(&self) -> crate::Result<Option<chrono_tz::Tz>> {
+		match &self.timezone {
+			Some(name) => match name.parse() {
+				Ok(tz) => Ok(Some(tz)),
+				Err(_) => Err(crate::api::err::Error::InvalidTimezone(name.clone()).into()),
+			},
+			None => Ok(None),
+		}
+	}
+
 	/// Set the default user
 	pub fn user(mut self, user: crate::opt::auth::Root<'_>) -> Self {
 		self.auth = Level::Root;
@@ -99,6 +337,7 @@ impl Config {
 	#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
 	pub fn rustls(mut self, config: rustls::ClientConfig) -> Self {
 		self.tls_config = Some(super::Tls::Rust(config));
+		self.tls = None;
 		self
 	}
 
@@ -107,9 +346,33 @@ impl Config {
 	#[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
 	pub fn native_tls(mut self, config: native_tls::TlsConnector) -> Self {
 		self.tls_config = Some(super::Tls::Native(config));
+		self.tls = None;
+		self
+	}
+
+	/// Configure TLS for remote connections using the enabled TLS backend
+	///
+	/// This replaces any connector previously supplied through `rustls` or `native_tls`.
+	/// Local engines ignore this setting.
+	#[cfg(any(feature = "native-tls", feature = "rustls"))]
+	#[cfg_attr(docsrs, doc(cfg(any(feature = "native-tls", feature = "rustls"))))]
+	pub fn tls(mut self, config: super::TlsConfig) -> Self {
+		self.tls = Some(config);
+		self.tls_config = None;
 		self
 	}
 
+	/// Resolves the TLS connector remote engines should use, if any
+	#[cfg(any(feature = "native-tls", feature = "rustls"))]
+	#[allow(dead_code)] // used by the remote engines
+	pub(crate) fn tls_connector(&self) -> crate::Result<Option<super::Tls>> {
+		match (&self.tls_config, &self.tls) {
+			(Some(tls), _) => Ok(Some(tls.clone())),
+			(None, Some(config)) => config.build().map(Some),
+			(None, None) => Ok(None),
+		}
+	}
+
 	/// Set the interval at which the database should run node maintenance tasks
 	pub fn tick_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
 		self.tick_interval = interval.into().filter(|x| !x.is_zero());
@@ -122,6 +385,16 @@ impl Config {
 		self
 	}
 
+	/// Enable experimental features for the embedded database
+	///
+	/// Experimental features are disabled by default, and using one which is not enabled
+	/// returns [`Error::ExperimentalFeatureDisabled`](crate::error::Api::ExperimentalFeatureDisabled).
+	/// Remote engines ignore this setting.
+	pub fn experimental(mut self, features: Vec<ExperimentalFeature>) -> Self {
+		self.experimental = features.into_iter().collect();
+		self
+	}
+
 	#[cfg(any(
 		feature = "kv-mem",
 		feature = "kv-surrealkv",
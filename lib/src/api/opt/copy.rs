@@ -0,0 +1,50 @@
+/// How to handle definitions and records which already exist in the target datastore
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Conflict {
+	/// Keep the existing definitions and records in the target
+	#[default]
+	Skip,
+	/// Stop copying and return an error
+	Error,
+}
+
+/// Options for copying one datastore into another
+#[derive(Debug, Clone, Default)]
+pub struct CopyOptions {
+	pub(crate) tables: Option<Vec<String>>,
+	pub(crate) conflict: Conflict,
+}
+
+impl CopyOptions {
+	/// Create options which copy every table, skipping anything that already exists
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Only copy the definitions and records of these tables
+	///
+	/// Definitions which do not belong to a table, such as users, params and functions,
+	/// are always copied.
+	pub fn tables<I, S>(mut self, tables: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.tables = Some(tables.into_iter().map(Into::into).collect());
+		self
+	}
+
+	/// Set how definitions and records which already exist in the target are handled
+	pub fn conflict(mut self, conflict: Conflict) -> Self {
+		self.conflict = conflict;
+		self
+	}
+
+	pub(crate) fn includes(&self, table: &str) -> bool {
+		match &self.tables {
+			Some(tables) => tables.iter().any(|tb| tb == table),
+			None => true,
+		}
+	}
+}
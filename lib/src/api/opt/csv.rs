@@ -0,0 +1,252 @@
+use crate::sql::Number;
+use crate::sql::Object;
+use crate::sql::Strand;
+use crate::sql::Value;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+
+/// Options for importing a CSV file into a table
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+	pub(crate) delimiter: u8,
+	pub(crate) headers: bool,
+	pub(crate) infer_types: bool,
+	pub(crate) columns: HashMap<String, String>,
+	pub(crate) batch_size: usize,
+	pub(crate) skip_malformed: bool,
+}
+
+impl Default for CsvOptions {
+	fn default() -> Self {
+		Self {
+			delimiter: b',',
+			headers: true,
+			infer_types: true,
+			columns: HashMap::new(),
+			batch_size: 1000,
+			skip_malformed: true,
+		}
+	}
+}
+
+impl CsvOptions {
+	/// Create options for a comma separated file with a header row
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Set the byte which separates the values in a row
+	pub fn delimiter(mut self, delimiter: u8) -> Self {
+		self.delimiter = delimiter;
+		self
+	}
+
+	/// Set whether the first row holds the column names
+	///
+	/// Without a header row, columns are named after their position, starting from `0`.
+	pub fn headers(mut self, headers: bool) -> Self {
+		self.headers = headers;
+		self
+	}
+
+	/// Set whether values are converted to booleans and numbers where they look like one
+	///
+	/// Otherwise every value is imported as a string. Empty values are left out of the
+	/// record either way.
+	pub fn infer_types(mut self, infer: bool) -> Self {
+		self.infer_types = infer;
+		self
+	}
+
+	/// Import a column into a field with a different name
+	///
+	/// Columns which are not mapped are imported into a field named after the column.
+	pub fn column(mut self, column: impl Into<String>, field: impl Into<String>) -> Self {
+		self.columns.insert(column.into(), field.into());
+		self
+	}
+
+	/// Set how many rows are inserted in each transaction
+	///
+	/// A size of zero is treated as one.
+	pub fn batch_size(mut self, size: usize) -> Self {
+		self.batch_size = size.max(1);
+		self
+	}
+
+	/// Set whether malformed rows are reported and skipped, rather than failing the import
+	///
+	/// When they are not skipped, a malformed row stops the import. Batches before the one
+	/// holding the malformed row have already been inserted by then. By default, malformed rows
+	/// are skipped.
+	pub fn skip_malformed(mut self, skip: bool) -> Self {
+		self.skip_malformed = skip;
+		self
+	}
+
+	/// The name of the field a column is imported into
+	pub(crate) fn field(&self, column: &str) -> String {
+		self.columns.get(column).cloned().unwrap_or_else(|| column.to_owned())
+	}
+
+	/// Converts a single value, leaving out empty ones
+	pub(crate) fn value(&self, value: String) -> Option<Value> {
+		if value.is_empty() {
+			return None;
+		}
+		if !self.infer_types {
+			return Some(Value::Strand(Strand::from(value)));
+		}
+		Some(match value.as_str() {
+			"true" => Value::Bool(true),
+			"false" => Value::Bool(false),
+			_ => match value.parse::<i64>() {
+				Ok(v) => Value::Number(Number::Int(v)),
+				Err(_) => match value.parse::<f64>() {
+					Ok(v) if v.is_finite() => Value::Number(Number::Float(v)),
+					_ => Value::Strand(Strand::from(value)),
+				},
+			},
+		})
+	}
+
+	/// Converts the values of a row into a record
+	pub(crate) fn record(&self, names: &[String], row: Vec<String>) -> Result<Value, String> {
+		if row.len() != names.len() {
+			return Err(format!("expected {} values but found {}", names.len(), row.len()));
+		}
+		let mut record = Object::default();
+		for (name, value) in names.iter().zip(row) {
+			if let Some(value) = self.value(value) {
+				record.insert(self.field(name), value);
+			}
+		}
+		Ok(record.into())
+	}
+}
+
+/// A row of a CSV file along with the line it starts on
+pub(crate) struct Row {
+	pub(crate) line: usize,
+	pub(crate) values: Result<Vec<String>, String>,
+}
+
+/// The characters of a reader, read a line at a time
+struct Chars<R> {
+	reader: BufReader<R>,
+	line: VecDeque<char>,
+}
+
+impl<R: Read> Chars<R> {
+	fn next(&mut self) -> io::Result<Option<char>> {
+		self.fill()?;
+		Ok(self.line.pop_front())
+	}
+
+	fn peek(&mut self) -> io::Result<Option<char>> {
+		self.fill()?;
+		Ok(self.line.front().copied())
+	}
+
+	fn fill(&mut self) -> io::Result<()> {
+		if self.line.is_empty() {
+			let mut line = String::new();
+			self.reader.read_line(&mut line)?;
+			self.line.extend(line.chars());
+		}
+		Ok(())
+	}
+}
+
+/// Reads the rows of a CSV file, following RFC 4180
+///
+/// Values may be quoted to contain delimiters, line breaks and doubled quotes. Blank lines
+/// are skipped. Only the row being parsed is held in memory.
+pub(crate) struct Rows<R> {
+	chars: Chars<R>,
+	delimiter: char,
+	line: usize,
+}
+
+impl<R: Read> Rows<R> {
+	pub(crate) fn new(reader: R, delimiter: u8) -> Self {
+		Self {
+			chars: Chars {
+				reader: BufReader::new(reader),
+				line: VecDeque::new(),
+			},
+			delimiter: delimiter as char,
+			line: 1,
+		}
+	}
+
+	fn row(&mut self) -> io::Result<Option<Row>> {
+		let chars = &mut self.chars;
+		while chars.peek()?.is_some() {
+			let start = self.line;
+			let mut values = Vec::new();
+			let mut value = String::new();
+			let mut error = None;
+			loop {
+				match chars.next()? {
+					Some('"') if value.is_empty() => loop {
+						match chars.next()? {
+							Some('"') if chars.peek()? == Some('"') => {
+								chars.next()?;
+								value.push('"');
+							}
+							Some('"') => break,
+							Some(c) => {
+								if c == '\n' {
+									self.line += 1;
+								}
+								value.push(c);
+							}
+							None => {
+								error = Some("a quoted value is never closed".to_owned());
+								break;
+							}
+						}
+					},
+					Some(c) if c == self.delimiter => values.push(std::mem::take(&mut value)),
+					Some('\r') if chars.peek()? == Some('\n') => {}
+					Some('\n') | None => {
+						self.line += 1;
+						break;
+					}
+					Some('"') => {
+						error.get_or_insert_with(|| {
+							"a quote appears inside an unquoted value".to_owned()
+						});
+					}
+					Some(c) => value.push(c),
+				}
+			}
+			values.push(value);
+			// Skip blank lines
+			if error.is_none() && values.len() == 1 && values[0].is_empty() {
+				continue;
+			}
+			return Ok(Some(Row {
+				line: start,
+				values: match error {
+					Some(error) => Err(error),
+					None => Ok(values),
+				},
+			}));
+		}
+		Ok(None)
+	}
+}
+
+impl<R: Read> Iterator for Rows<R> {
+	type Item = io::Result<Row>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.row().transpose()
+	}
+}
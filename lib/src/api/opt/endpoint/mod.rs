@@ -113,6 +113,45 @@ mod tests {
 			assert_eq!(converted, format!("{scheme}{path}"), "failed to convert `{path}`");
 		}
 	}
+
+	#[cfg(all(feature = "protocol-ws", any(feature = "native-tls", feature = "rustls")))]
+	#[test]
+	fn tls_config_round_trips_into_endpoint() {
+		use crate::api::engine::remote::ws::Wss;
+		use crate::opt::TlsConfig;
+
+		let tls = TlsConfig::new()
+			.root_certificates("root ca bundle")
+			.client_auth("client cert", "client key")
+			.danger_accept_invalid_certs(true);
+		let config = Config::new().tls(tls.clone());
+		let endpoint = IntoEndpoint::<Wss>::into_endpoint(("localhost:8000", config)).unwrap();
+		assert_eq!(endpoint.url.as_str(), "wss://localhost:8000/");
+		assert_eq!(endpoint.config.tls.as_ref(), Some(&tls));
+		assert_eq!(tls.root_certificates.as_deref(), Some(&b"root ca bundle"[..]));
+		assert_eq!(tls.client_certificate.as_deref(), Some(&b"client cert"[..]));
+		assert_eq!(tls.client_key.as_deref(), Some(&b"client key"[..]));
+		assert!(tls.danger_accept_invalid_certs);
+	}
+
+	#[cfg(all(feature = "protocol-http", feature = "rustls"))]
+	#[test]
+	fn tls_config_builds_connector() {
+		use crate::api::engine::remote::http::Https;
+		use crate::opt::TlsConfig;
+
+		// Accepting invalid certificates does not need any roots
+		let config = Config::new().tls(TlsConfig::new().danger_accept_invalid_certs(true));
+		let endpoint = IntoEndpoint::<Https>::into_endpoint(("localhost:8000", config)).unwrap();
+		assert!(endpoint.config.tls_connector().unwrap().is_some());
+		// An unparsable root bundle is reported instead of silently ignored
+		let config = Config::new().tls(TlsConfig::new().root_certificates("not a certificate"));
+		let endpoint = IntoEndpoint::<Https>::into_endpoint(("localhost:8000", config)).unwrap();
+		assert!(endpoint.config.tls_connector().is_err());
+		// No TLS options means the backend defaults are used
+		let endpoint = IntoEndpoint::<Https>::into_endpoint("localhost:8000").unwrap();
+		assert!(endpoint.config.tls_connector().unwrap().is_none());
+	}
 }
 
 #[derive(Debug)]
@@ -0,0 +1,47 @@
+use crate::Error;
+use std::fmt;
+use std::sync::Arc;
+
+/// The request which produced an error, as passed to the hook set by [`Config::error_hook`]
+///
+/// [`Config::error_hook`]: super::Config::error_hook
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct QueryContext {
+	/// The name of the method which was called, such as `query` or `select`
+	pub method: &'static str,
+	/// The statement which failed, or the method and parameters of any other request
+	///
+	/// The parameters of requests which carry credentials, such as `signin`, are left out.
+	pub statement: String,
+	/// The ID of the user who ran the request, or `None` for anonymous sessions
+	pub user: Option<String>,
+	/// The namespace selected when the request ran
+	pub namespace: Option<String>,
+	/// The database selected when the request ran
+	pub database: Option<String>,
+}
+
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct ErrorHook(
+	// Only called by the embedded engines
+	#[cfg_attr(
+		not(any(
+			feature = "kv-mem",
+			feature = "kv-tikv",
+			feature = "kv-rocksdb",
+			feature = "kv-fdb",
+			feature = "kv-indxdb",
+			feature = "kv-surrealkv",
+		)),
+		allow(dead_code)
+	)]
+	pub(crate) Arc<dyn Fn(&Error, &QueryContext) + Send + Sync>,
+);
+
+impl fmt::Debug for ErrorHook {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("ErrorHook(..)")
+	}
+}
@@ -0,0 +1,20 @@
+use std::fmt;
+
+/// A feature which is not stable yet and has to be enabled explicitly
+///
+/// Experimental features may change or be removed in any release. See
+/// [`Config::experimental`](super::Config::experimental) for enabling them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExperimentalFeature {
+	/// Defining `HNSW` vector indexes, the newest index type
+	HnswIndex,
+}
+
+impl fmt::Display for ExperimentalFeature {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::HnswIndex => f.write_str("hnsw_index"),
+		}
+	}
+}
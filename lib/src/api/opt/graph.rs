@@ -0,0 +1,69 @@
+/// The direction in which edges are followed from a record
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GraphDirection {
+	/// Follow edges from their `in` record to their `out` record
+	#[default]
+	Out,
+	/// Follow edges from their `out` record to their `in` record
+	In,
+	/// Follow edges either way
+	Both,
+}
+
+/// Describes which edges to follow when traversing a graph
+#[derive(Debug, Clone)]
+pub struct GraphSpec {
+	pub(crate) direction: GraphDirection,
+	pub(crate) edges: Vec<String>,
+	pub(crate) depth: u32,
+}
+
+impl Default for GraphSpec {
+	fn default() -> Self {
+		Self {
+			direction: GraphDirection::Out,
+			edges: Vec::new(),
+			depth: 1,
+		}
+	}
+}
+
+impl GraphSpec {
+	/// Create a spec which follows outgoing edges of any table for a single hop
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Set the direction in which edges are followed
+	pub fn direction(mut self, direction: GraphDirection) -> Self {
+		self.direction = direction;
+		self
+	}
+
+	/// Only follow edges stored on this table
+	///
+	/// Can be called more than once to follow edges from several tables. Edges from any table
+	/// are followed if no table is set.
+	pub fn edge(mut self, table: impl Into<String>) -> Self {
+		self.edges.push(table.into());
+		self
+	}
+
+	/// Set how many hops away from the starting record to traverse
+	///
+	/// Records which were already reached are not traversed again, so cycles in the graph
+	/// end as well. A depth of zero only returns the starting record.
+	pub fn depth(mut self, depth: u32) -> Self {
+		self.depth = depth;
+		self
+	}
+
+	pub(crate) fn arrow(&self) -> &'static str {
+		match self.direction {
+			GraphDirection::Out => "->",
+			GraphDirection::In => "<-",
+			GraphDirection::Both => "<->",
+		}
+	}
+}
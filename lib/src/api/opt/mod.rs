@@ -4,10 +4,17 @@ pub mod auth;
 pub mod capabilities;
 
 mod config;
+mod copy;
+pub(crate) mod csv;
 mod endpoint;
+mod error_hook;
+mod experimental;
 mod export;
+mod graph;
 mod query;
+mod query_log;
 mod resource;
+mod table_schema;
 mod tls;
 
 use crate::sql::to_value;
@@ -17,10 +24,17 @@ use dmp::Diff;
 use serde::Serialize;
 
 pub use config::*;
+pub use copy::*;
+pub use csv::CsvOptions;
 pub use endpoint::*;
+pub use error_hook::*;
+pub use experimental::*;
 pub use export::*;
+pub use graph::*;
 pub use query::*;
+pub use query_log::*;
 pub use resource::*;
+pub use table_schema::*;
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 pub use tls::*;
 
@@ -14,6 +14,12 @@ use std::mem;
 pub trait IntoQuery {
 	/// Converts an input into SQL statements
 	fn into_query(self) -> Result<Vec<Statement>>;
+
+	/// The SurrealQL text of the input, if it still needs to be parsed
+	#[doc(hidden)]
+	fn as_sql(&self) -> Option<&str> {
+		None
+	}
 }
 
 impl IntoQuery for sql::Query {
@@ -158,18 +164,30 @@ impl IntoQuery for &str {
 	fn into_query(self) -> Result<Vec<Statement>> {
 		syn::parse(self)?.into_query()
 	}
+
+	fn as_sql(&self) -> Option<&str> {
+		Some(self)
+	}
 }
 
 impl IntoQuery for &String {
 	fn into_query(self) -> Result<Vec<Statement>> {
 		syn::parse(self)?.into_query()
 	}
+
+	fn as_sql(&self) -> Option<&str> {
+		Some(self)
+	}
 }
 
 impl IntoQuery for String {
 	fn into_query(self) -> Result<Vec<Statement>> {
 		syn::parse(&self)?.into_query()
 	}
+
+	fn as_sql(&self) -> Option<&str> {
+		Some(self)
+	}
 }
 
 /// Represents a way to take a single query result from a list of responses
@@ -0,0 +1,51 @@
+use crate::sql::Datetime;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A request executed by an embedded database, as passed to the sink set by [`Config::query_log`]
+///
+/// [`Config::query_log`]: super::Config::query_log
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct QueryLogEntry {
+	/// The statements of a query, or the method and parameters of any other request
+	///
+	/// The parameters of requests which carry credentials, such as `signin`, are left out.
+	pub statement: String,
+	/// The ID of the user who ran the request, or `None` for anonymous sessions
+	pub user: Option<String>,
+	/// The namespace selected when the request ran
+	pub namespace: Option<String>,
+	/// The database selected when the request ran
+	pub database: Option<String>,
+	/// When the request started
+	pub started_at: Datetime,
+	/// How long the request took to execute
+	pub duration: Duration,
+	/// The error returned by the request, or `None` if it succeeded
+	pub error: Option<String>,
+}
+
+#[derive(Clone)]
+pub(crate) struct QueryLog(
+	// Only called by the embedded engines
+	#[cfg_attr(
+		not(any(
+			feature = "kv-mem",
+			feature = "kv-tikv",
+			feature = "kv-rocksdb",
+			feature = "kv-fdb",
+			feature = "kv-indxdb",
+			feature = "kv-surrealkv",
+		)),
+		allow(dead_code)
+	)]
+	pub(crate) Arc<dyn Fn(QueryLogEntry) + Send + Sync>,
+);
+
+impl fmt::Debug for QueryLog {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("QueryLog(..)")
+	}
+}
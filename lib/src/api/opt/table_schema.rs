@@ -0,0 +1,514 @@
+use crate::api::err::Error;
+use crate::sql::Ident;
+use crate::sql::Idiom;
+use crate::sql::Kind;
+use crate::sql::Part;
+use serde::de;
+use serde::de::DeserializeOwned;
+use serde::de::DeserializeSeed;
+use serde::de::IntoDeserializer;
+use serde::de::Visitor;
+use serde::Deserializer;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A field of a table, as derived by [`TableSchema::table_fields`]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FieldSchema {
+	/// The path of the field, with `*` standing for the items of an array
+	pub name: Idiom,
+	/// The type of the values the field accepts
+	pub kind: Kind,
+	/// Whether values nested under the field are kept even though they are not in the schema
+	pub flexible: bool,
+}
+
+/// A type which describes the records of a table
+///
+/// The schema is derived from the way the type deserialises, so it is implemented for every
+/// type which implements [`Deserialize`](serde::Deserialize), though only structs describe a
+/// table. Each field becomes a field of the table, with its type inferred from its Rust
+/// type:
+///
+/// * Booleans, integers, floats, strings and bytes map to `bool`, `int`, `float`, `string` and
+///   `bytes`.
+/// * `Option<T>` maps to `option<T>`, and sequences such as `Vec<T>` map to `array<T>`.
+/// * Nested structs map to `object`, with a field defined for each of their own fields.
+/// * Maps such as `HashMap` map to flexible objects, as their keys are not known up front.
+/// * The datetimes, durations, UUIDs, record IDs and geometries of the [`sql`](crate::sql)
+///   module map to their own types.
+/// * Enums, tuples and types which deserialise any value map to flexible `any` fields.
+///
+/// A top-level `id` field is left out, as every table already has one.
+pub trait TableSchema {
+	/// Derives the fields of the table, with each nested field following its parent
+	fn table_fields() -> crate::api::Result<Vec<FieldSchema>>;
+}
+
+impl<T> TableSchema for T
+where
+	T: DeserializeOwned,
+{
+	fn table_fields() -> crate::api::Result<Vec<FieldSchema>> {
+		let type_name = std::any::type_name::<T>();
+		let failed = |message: String| {
+			crate::Error::from(Error::TableSchema {
+				type_name,
+				message,
+			})
+		};
+		// Some types only accept particular values, so a failed trace is retried with the
+		// next sample for the value which was rejected
+		let mut samples = HashMap::new();
+		let mut state = loop {
+			let mut state = State {
+				samples: samples.clone(),
+				..Default::default()
+			};
+			let result = T::deserialize(Tracer {
+				state: &mut state,
+				path: Vec::new(),
+			});
+			match (result, state.last_sample.take()) {
+				(Ok(_), _) => break state,
+				(Err(error), Some((path, count))) => {
+					let index = samples.entry(path).or_insert(0);
+					*index += 1;
+					if *index >= count {
+						return Err(failed(error.0));
+					}
+				}
+				(Err(error), None) => return Err(failed(error.0)),
+			}
+		};
+		if !matches!(state.kind, Some((Kind::Object, false))) {
+			return Err(failed(String::from("only structs describe the records of a table")));
+		}
+		state.fields.retain(|field| field.name.first() != Some(&Part::from("id")));
+		Ok(state.fields)
+	}
+}
+
+// The samples tried for each kind of value, in order
+const STRING_SAMPLES: &[&str] =
+	&["", "0", "1970-01-01T00:00:00Z", "00000000-0000-0000-0000-000000000000"];
+const ANY_SAMPLES: usize = 4;
+
+#[derive(Default)]
+struct State {
+	fields: Vec<FieldSchema>,
+	// The kind of the value which was traced last, and whether it is flexible
+	kind: Option<(Kind, bool)>,
+	// The sample to use for each path which has more than one
+	samples: HashMap<String, usize>,
+	// The path and number of samples of the last value which had more than one
+	last_sample: Option<(String, usize)>,
+}
+
+struct Tracer<'a> {
+	state: &'a mut State,
+	path: Vec<Part>,
+}
+
+impl Tracer<'_> {
+	fn child(&mut self, part: Option<Part>) -> Tracer<'_> {
+		let mut path = self.path.clone();
+		path.extend(part);
+		Tracer {
+			state: &mut *self.state,
+			path,
+		}
+	}
+
+	/// Picks the sample to use for this value, out of `count` samples
+	fn sample(&mut self, count: usize) -> usize {
+		let path = Idiom::from(self.path.clone()).to_string();
+		let index = self.state.samples.get(&path).copied().unwrap_or_default();
+		self.state.last_sample = (count > 1).then_some((path, count));
+		index
+	}
+
+	fn leaf(self, kind: Kind) {
+		self.state.last_sample = None;
+		self.state.kind = Some((kind, false));
+	}
+}
+
+/// The kinds of the types in the `sql` module, by the name they deserialise with
+fn token_kind(name: &str) -> Option<Kind> {
+	let kind = match name.strip_prefix("$surrealdb::private::sql::")? {
+		"Datetime" => Kind::Datetime,
+		"Duration" => Kind::Duration,
+		"Geometry" => Kind::Geometry(Vec::new()),
+		"Number" => Kind::Number,
+		"Strand" => Kind::String,
+		"Thing" => Kind::Record(Vec::new()),
+		"Uuid" => Kind::Uuid,
+		_ => return None,
+	};
+	Some(kind)
+}
+
+#[derive(Debug)]
+struct TraceError(String);
+
+impl fmt::Display for TraceError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl std::error::Error for TraceError {}
+
+impl de::Error for TraceError {
+	fn custom<T: fmt::Display>(msg: T) -> Self {
+		Self(msg.to_string())
+	}
+}
+
+macro_rules! trace_leaf {
+	($($method:ident => $visit:ident($($value:expr)?), $kind:expr;)+) => {
+		$(
+			fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+			where
+				V: Visitor<'de>,
+			{
+				self.leaf($kind);
+				visitor.$visit($($value)?)
+			}
+		)+
+	};
+}
+
+impl<'de> Deserializer<'de> for Tracer<'_> {
+	type Error = TraceError;
+
+	trace_leaf! {
+		deserialize_bool => visit_bool(false), Kind::Bool;
+		deserialize_i8 => visit_i8(0), Kind::Int;
+		deserialize_i16 => visit_i16(0), Kind::Int;
+		deserialize_i32 => visit_i32(0), Kind::Int;
+		deserialize_i64 => visit_i64(0), Kind::Int;
+		deserialize_i128 => visit_i128(0), Kind::Int;
+		deserialize_u8 => visit_u8(0), Kind::Int;
+		deserialize_u16 => visit_u16(0), Kind::Int;
+		deserialize_u32 => visit_u32(0), Kind::Int;
+		deserialize_u64 => visit_u64(0), Kind::Int;
+		deserialize_u128 => visit_u128(0), Kind::Int;
+		deserialize_f32 => visit_f32(0.0), Kind::Float;
+		deserialize_f64 => visit_f64(0.0), Kind::Float;
+		deserialize_char => visit_char('0'), Kind::String;
+		deserialize_bytes => visit_bytes(&[]), Kind::Bytes;
+		deserialize_byte_buf => visit_byte_buf(Vec::new()), Kind::Bytes;
+		deserialize_unit => visit_unit(), Kind::Null;
+		deserialize_ignored_any => visit_unit(), Kind::Any;
+	}
+
+	fn deserialize_str<V>(mut self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let sample = STRING_SAMPLES[self.sample(STRING_SAMPLES.len())];
+		self.state.kind = Some((Kind::String, false));
+		visitor.visit_str(sample)
+	}
+
+	fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		self.deserialize_str(visitor)
+	}
+
+	fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		self.deserialize_str(visitor)
+	}
+
+	fn deserialize_any<V>(mut self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let sample = self.sample(ANY_SAMPLES);
+		let start = self.state.fields.len();
+		let value = match sample {
+			0 => visitor.visit_unit(),
+			1 => visitor.visit_i64(0),
+			2 => visitor.visit_str(""),
+			_ => visitor.visit_map(FieldAccess::new(self.child(None), &[])),
+		}?;
+		// Anything could be stored here, so nothing under it is part of the schema
+		self.state.fields.truncate(start);
+		self.state.kind = Some((Kind::Any, true));
+		Ok(value)
+	}
+
+	fn deserialize_option<V>(mut self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let value = visitor.visit_some(self.child(None))?;
+		let (kind, flexible) = self.state.kind.take().unwrap_or_default();
+		self.state.kind = Some((Kind::Option(Box::new(kind)), flexible));
+		Ok(value)
+	}
+
+	fn deserialize_unit_struct<V>(
+		self,
+		_: &'static str,
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		self.deserialize_unit(visitor)
+	}
+
+	fn deserialize_newtype_struct<V>(
+		mut self,
+		name: &'static str,
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let start = self.state.fields.len();
+		let value = visitor.visit_newtype_struct(self.child(None))?;
+		if let Some(kind) = token_kind(name) {
+			self.state.fields.truncate(start);
+			self.state.kind = Some((kind, false));
+		}
+		Ok(value)
+	}
+
+	fn deserialize_seq<V>(mut self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let value = visitor.visit_seq(ItemAccess {
+			tracer: self.child(Some(Part::All)),
+			remaining: 1,
+		})?;
+		let (kind, flexible) = self.state.kind.take().unwrap_or_default();
+		self.state.kind = Some((Kind::Array(Box::new(kind), None), flexible));
+		Ok(value)
+	}
+
+	fn deserialize_tuple<V>(mut self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let start = self.state.fields.len();
+		let value = visitor.visit_seq(ItemAccess {
+			tracer: self.child(None),
+			remaining: len,
+		})?;
+		// The items of a tuple each have their own type
+		self.state.fields.truncate(start);
+		self.state.kind = Some((Kind::Array(Box::new(Kind::Any), Some(len as u64)), true));
+		Ok(value)
+	}
+
+	fn deserialize_tuple_struct<V>(
+		self,
+		_: &'static str,
+		len: usize,
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		self.deserialize_tuple(len, visitor)
+	}
+
+	fn deserialize_map<V>(mut self, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let value = visitor.visit_map(FieldAccess::new(self.child(None), &[]))?;
+		self.state.kind = Some((Kind::Object, true));
+		Ok(value)
+	}
+
+	fn deserialize_struct<V>(
+		mut self,
+		name: &'static str,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let start = self.state.fields.len();
+		let value = visitor.visit_map(FieldAccess::new(self.child(None), fields))?;
+		match token_kind(name) {
+			Some(kind) => {
+				self.state.fields.truncate(start);
+				self.state.kind = Some((kind, false));
+			}
+			None => self.state.kind = Some((Kind::Object, false)),
+		}
+		Ok(value)
+	}
+
+	fn deserialize_enum<V>(
+		mut self,
+		name: &'static str,
+		variants: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		let Some(variant) = variants.get(self.sample(variants.len())) else {
+			return Err(de::Error::custom(format!("the enum `{name}` has no variants")));
+		};
+		let start = self.state.fields.len();
+		let value = visitor.visit_enum(VariantAccess {
+			tracer: self.child(None),
+			variant,
+		})?;
+		// Each variant has its own shape, so nothing under an enum is part of the schema
+		self.state.fields.truncate(start);
+		self.state.kind = match token_kind(name) {
+			Some(kind) => Some((kind, false)),
+			None => Some((Kind::Any, true)),
+		};
+		Ok(value)
+	}
+}
+
+/// Hands a struct the fields it expects, tracing the value of each
+struct FieldAccess<'a> {
+	tracer: Tracer<'a>,
+	fields: std::slice::Iter<'static, &'static str>,
+	current: Option<&'static str>,
+}
+
+impl<'a> FieldAccess<'a> {
+	fn new(tracer: Tracer<'a>, fields: &'static [&'static str]) -> Self {
+		Self {
+			tracer,
+			fields: fields.iter(),
+			current: None,
+		}
+	}
+}
+
+impl<'de> de::MapAccess<'de> for FieldAccess<'_> {
+	type Error = TraceError;
+
+	fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+	where
+		K: DeserializeSeed<'de>,
+	{
+		self.current = self.fields.next().copied();
+		match self.current {
+			Some(field) => seed.deserialize(field.into_deserializer()).map(Some),
+			None => Ok(None),
+		}
+	}
+
+	fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+	where
+		V: DeserializeSeed<'de>,
+	{
+		let Some(field) = self.current else {
+			return Err(de::Error::custom("a value was read before its key"));
+		};
+		let tracer = self.tracer.child(Some(Part::Field(Ident::from(field))));
+		// The field is listed before any fields nested under it
+		let index = tracer.state.fields.len();
+		tracer.state.fields.push(FieldSchema {
+			name: Idiom::from(tracer.path.clone()),
+			kind: Kind::Any,
+			flexible: false,
+		});
+		let value = seed.deserialize(tracer)?;
+		let (kind, flexible) = self.tracer.state.kind.take().unwrap_or_default();
+		let schema = &mut self.tracer.state.fields[index];
+		schema.kind = kind;
+		schema.flexible = flexible;
+		Ok(value)
+	}
+}
+
+/// Hands a sequence a number of items, tracing each
+struct ItemAccess<'a> {
+	tracer: Tracer<'a>,
+	remaining: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for ItemAccess<'_> {
+	type Error = TraceError;
+
+	fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+	where
+		T: DeserializeSeed<'de>,
+	{
+		if self.remaining == 0 {
+			return Ok(None);
+		}
+		self.remaining -= 1;
+		seed.deserialize(self.tracer.child(None)).map(Some)
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		Some(self.remaining)
+	}
+}
+
+/// Picks a variant of an enum and traces its contents
+struct VariantAccess<'a> {
+	tracer: Tracer<'a>,
+	variant: &'static str,
+}
+
+impl<'de> de::EnumAccess<'de> for VariantAccess<'_> {
+	type Error = TraceError;
+	type Variant = Self;
+
+	fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), Self::Error>
+	where
+		V: DeserializeSeed<'de>,
+	{
+		let variant = seed.deserialize(self.variant.into_deserializer())?;
+		Ok((variant, self))
+	}
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess<'_> {
+	type Error = TraceError;
+
+	fn unit_variant(self) -> Result<(), Self::Error> {
+		Ok(())
+	}
+
+	fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+	where
+		T: DeserializeSeed<'de>,
+	{
+		seed.deserialize(self.tracer)
+	}
+
+	fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		self.tracer.deserialize_tuple(len, visitor)
+	}
+
+	fn struct_variant<V>(
+		self,
+		fields: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: Visitor<'de>,
+	{
+		self.tracer.deserialize_struct("", fields, visitor)
+	}
+}
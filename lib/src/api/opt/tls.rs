@@ -12,3 +12,172 @@ pub enum Tls {
 	#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
 	Rust(rustls::ClientConfig),
 }
+
+/// Portable TLS options for remote connections
+///
+/// Unlike [`Tls`], which takes a fully built connector, these options are turned into a
+/// connector for whichever TLS backend is enabled when the connection is established.
+/// Certificates and keys are expected to be PEM encoded. Local engines ignore these options.
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "native-tls", feature = "rustls"))))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsConfig {
+	pub(crate) root_certificates: Option<Vec<u8>>,
+	pub(crate) client_certificate: Option<Vec<u8>>,
+	pub(crate) client_key: Option<Vec<u8>>,
+	pub(crate) danger_accept_invalid_certs: bool,
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+impl TlsConfig {
+	/// Create a TLS configuration which verifies servers using the default roots
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// Trust the certificates in the supplied PEM bundle instead of the default roots
+	pub fn root_certificates(mut self, pem: impl Into<Vec<u8>>) -> Self {
+		self.root_certificates = Some(pem.into());
+		self
+	}
+
+	/// Authenticate to the server using a PEM encoded certificate chain and private key
+	pub fn client_auth(mut self, certificate: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+		self.client_certificate = Some(certificate.into());
+		self.client_key = Some(key.into());
+		self
+	}
+
+	/// Accept any server certificate, including self-signed and expired ones
+	///
+	/// This disables server verification entirely and should only be used in development.
+	pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+		self.danger_accept_invalid_certs = accept;
+		self
+	}
+
+	/// Builds a connector for the enabled TLS backend, preferring Rustls
+	pub(crate) fn build(&self) -> crate::Result<Tls> {
+		#[cfg(feature = "rustls")]
+		return self.build_rustls().map(Tls::Rust);
+		#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+		return self.build_native().map(Tls::Native);
+	}
+
+	#[cfg(feature = "rustls")]
+	fn build_rustls(&self) -> crate::Result<rustls::ClientConfig> {
+		use rustls::client::ServerCertVerified;
+		use rustls::client::ServerCertVerifier;
+		use rustls::Certificate;
+		use rustls::OwnedTrustAnchor;
+		use rustls::PrivateKey;
+		use rustls::RootCertStore;
+		use rustls::ServerName;
+		use rustls_pemfile::Item;
+		use std::sync::Arc;
+		use std::time::SystemTime;
+
+		struct NoVerifier;
+
+		impl ServerCertVerifier for NoVerifier {
+			fn verify_server_cert(
+				&self,
+				_end_entity: &Certificate,
+				_intermediates: &[Certificate],
+				_server_name: &ServerName,
+				_scts: &mut dyn Iterator<Item = &[u8]>,
+				_ocsp_response: &[u8],
+				_now: SystemTime,
+			) -> Result<ServerCertVerified, rustls::Error> {
+				Ok(ServerCertVerified::assertion())
+			}
+		}
+
+		let mut roots = RootCertStore::empty();
+		match &self.root_certificates {
+			// Server certificates are not verified at all, so there is nothing to trust
+			_ if self.danger_accept_invalid_certs => {}
+			Some(pem) => {
+				for cert in pem_certificates(pem)? {
+					roots.add(&Certificate(cert)).map_err(invalid)?;
+				}
+			}
+			None => {
+				roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|ta| {
+					OwnedTrustAnchor::from_subject_spki_name_constraints(
+						ta.subject,
+						ta.spki,
+						ta.name_constraints,
+					)
+				}));
+			}
+		}
+		let builder =
+			rustls::ClientConfig::builder().with_safe_defaults().with_root_certificates(roots);
+		let mut config = match (&self.client_certificate, &self.client_key) {
+			(Some(certificate), Some(key)) => {
+				let chain = pem_certificates(certificate)?.into_iter().map(Certificate).collect();
+				let key = rustls_pemfile::read_all(&mut key.as_slice())
+					.map_err(invalid)?
+					.into_iter()
+					.find_map(|item| match item {
+						Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => Some(key),
+						_ => None,
+					})
+					.ok_or_else(|| invalid("no private key found in the client key"))?;
+				builder.with_client_auth_cert(chain, PrivateKey(key)).map_err(invalid)?
+			}
+			_ => builder.with_no_client_auth(),
+		};
+		if self.danger_accept_invalid_certs {
+			config.dangerous().set_certificate_verifier(Arc::new(NoVerifier));
+		}
+		Ok(config)
+	}
+
+	#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+	fn build_native(&self) -> crate::Result<native_tls::TlsConnector> {
+		let mut builder = native_tls::TlsConnector::builder();
+		builder.danger_accept_invalid_certs(self.danger_accept_invalid_certs);
+		if let Some(pem) = &self.root_certificates {
+			builder.disable_built_in_roots(true);
+			for block in pem_blocks(pem) {
+				builder.add_root_certificate(
+					native_tls::Certificate::from_pem(block).map_err(invalid)?,
+				);
+			}
+		}
+		if let (Some(certificate), Some(key)) = (&self.client_certificate, &self.client_key) {
+			builder.identity(native_tls::Identity::from_pkcs8(certificate, key).map_err(invalid)?);
+		}
+		builder.build().map_err(invalid)
+	}
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn invalid(error: impl std::fmt::Display) -> crate::Error {
+	crate::api::err::Error::InvalidTlsConfig(error.to_string()).into()
+}
+
+/// Decodes every certificate in a PEM bundle
+#[cfg(feature = "rustls")]
+fn pem_certificates(pem: &[u8]) -> crate::Result<Vec<Vec<u8>>> {
+	let certs = rustls_pemfile::certs(&mut &pem[..]).map_err(invalid)?;
+	if certs.is_empty() {
+		return Err(invalid("no certificates found in the PEM bundle"));
+	}
+	Ok(certs)
+}
+
+/// Splits a PEM bundle into its individual certificate blocks
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+fn pem_blocks(pem: &[u8]) -> impl Iterator<Item = &[u8]> {
+	const END: &[u8] = b"-----END CERTIFICATE-----";
+	let mut rest = pem;
+	std::iter::from_fn(move || {
+		let end = rest.windows(END.len()).position(|window| window == END)? + END.len();
+		let (block, tail) = rest.split_at(end);
+		rest = tail;
+		Some(block)
+	})
+}
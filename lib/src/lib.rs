@@ -192,6 +192,87 @@ pub struct Notification<R> {
 	pub data: R,
 }
 
+/// A change read from a table change feed
+///
+/// Changes are returned in the order in which they were committed. The `versionstamp` identifies the transaction which made the change.
+/// For deletions the data is `NONE`. For everything else, it's the newly created record or updated record depending on whether the action
+/// is create or update.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Change {
+	pub versionstamp: u128,
+	pub action: Action,
+	pub record: sql::Thing,
+	pub data: sql::Value,
+}
+
+/// A record returned along with its metadata
+///
+/// The `version` counts the writes to the record, starting at `1` when it is created, and
+/// `last_modified` is when it was last written. Both are kept with records on tables with a change
+/// feed, so they are `None` for tables without one.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RecordWithMeta<T> {
+	pub id: sql::Thing,
+	pub version: Option<u64>,
+	pub last_modified: Option<sql::Datetime>,
+	pub data: T,
+}
+
+/// A page of records returned by [`Pager::next_page`](crate::method::Pager::next_page)
+///
+/// `total` is the number of records in the whole table at the time the first page was read.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Page<T> {
+	pub items: Vec<T>,
+	pub total: u64,
+	pub has_next: bool,
+}
+
+/// A record returned by `create_if_not_exists` along with whether that call created it
+///
+/// When the record already existed, `created` is `false` and `record` holds the existing record
+/// untouched.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CreatedRecord<T> {
+	pub created: bool,
+	pub record: T,
+}
+
+/// A record written by `upsert_many` along with whether that call created it
+///
+/// When the record already existed, `created` is `false` and `record` holds the record after it
+/// was updated.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct UpsertedRecord<T> {
+	pub id: sql::Thing,
+	pub created: bool,
+	pub record: T,
+}
+
+/// The outcome of an [`import_csv`](crate::Surreal::import_csv) call
+///
+/// `imported` is the number of records inserted. Rows which could not be imported are listed in
+/// `malformed` instead of failing the whole import.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CsvImport {
+	pub imported: usize,
+	pub malformed: Vec<MalformedRow>,
+}
+
+/// A row of a CSV file which could not be imported
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct MalformedRow {
+	pub line: usize,
+	pub message: String,
+}
+
 /// An error originating from the SurrealDB client library
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 pub enum Error {
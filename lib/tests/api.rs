@@ -21,6 +21,7 @@ mod api_integration {
 	use surrealdb::opt::auth::Root;
 	use surrealdb::opt::capabilities::Capabilities;
 	use surrealdb::opt::Config;
+	use surrealdb::opt::ExperimentalFeature;
 	use surrealdb::opt::PatchOp;
 	use surrealdb::opt::Resource;
 	use surrealdb::sql::statements::BeginStatement;
@@ -144,6 +145,8 @@ mod api_integration {
 
 		include!("api/mod.rs");
 		include!("api/live.rs");
+		include!("api/record_lock.rs");
+		include!("api/record_lock_clients.rs");
 	}
 
 	#[cfg(feature = "protocol-http")]
@@ -173,6 +176,8 @@ mod api_integration {
 
 		include!("api/mod.rs");
 		include!("api/backup.rs");
+		include!("api/record_lock.rs");
+		include!("api/record_lock_clients.rs");
 	}
 
 	#[cfg(feature = "kv-mem")]
@@ -253,6 +258,590 @@ mod api_integration {
 			};
 		}
 
+		#[test_log::test(tokio::test)]
+		async fn max_result_rows() {
+			let db = Surreal::new::<Mem>(Config::new().max_result_rows(3)).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			db.query("CREATE |item:5| RETURN NONE").await.unwrap().check().unwrap();
+			// Results over the cap are rejected
+			let mut response = db.query("SELECT * FROM item").await.unwrap();
+			let Error::Api(ApiError::ResultTooLarge(3)) =
+				response.take::<Vec<RecordId>>(0).unwrap_err()
+			else {
+				panic!("expected the result to be too large");
+			};
+			let Error::Api(ApiError::ResultTooLarge(3)) =
+				db.select::<Vec<RecordId>>("item").await.unwrap_err()
+			else {
+				panic!("expected the result to be too large");
+			};
+			// Limited and aggregate queries are not affected
+			let mut response = db
+				.query("SELECT * FROM item LIMIT 3")
+				.query("SELECT count() FROM item GROUP ALL")
+				.await
+				.unwrap();
+			let records: Vec<RecordId> = response.take(0).unwrap();
+			assert_eq!(records.len(), 3);
+			let count: Option<usize> = response.take((1, "count")).unwrap();
+			assert_eq!(count, Some(5));
+			// Only the rows of `SELECT` statements are capped
+			let mut response = db.query("RETURN [1, 2, 3, 4, 5]").await.unwrap();
+			let values: Vec<i64> = response.take(0).unwrap();
+			assert_eq!(values.len(), 5);
+			// A `LIMIT` of its own doesn't let a `SELECT` return more rows
+			let mut response = db.query("SELECT * FROM item LIMIT 4").await.unwrap();
+			let Error::Api(ApiError::ResultTooLarge(3)) =
+				response.take::<Vec<RecordId>>(0).unwrap_err()
+			else {
+				panic!("expected the result to be too large");
+			};
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn query_log() {
+			use surrealdb::opt::QueryLogEntry;
+			let entries = Arc::new(Mutex::new(Vec::<QueryLogEntry>::new()));
+			let sink = entries.clone();
+			let config = Config::new()
+				.user(Root {
+					username: ROOT_USER,
+					password: ROOT_PASS,
+				})
+				.query_log(Arc::new(move |entry| sink.lock().unwrap().push(entry)));
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.signin(Root {
+				username: ROOT_USER,
+				password: ROOT_PASS,
+			})
+			.await
+			.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			db.query("CREATE person:tobie").await.unwrap().check().unwrap();
+			let _: Option<RecordId> = db.select(("person", "tobie")).await.unwrap();
+			db.query("THROW 'denied'").await.unwrap().check().unwrap_err();
+			// Entries are delivered in the background
+			let entries = tokio::time::timeout(Duration::from_secs(5), async {
+				loop {
+					if entries.lock().unwrap().len() >= 5 {
+						break entries.lock().unwrap().clone();
+					}
+					tokio::time::sleep(Duration::from_millis(10)).await;
+				}
+			})
+			.await
+			.unwrap();
+			let statements: Vec<_> = entries.iter().map(|entry| entry.statement.as_str()).collect();
+			assert_eq!(
+				statements,
+				[
+					"signin",
+					"use('namespace', 'database')",
+					"CREATE person:tobie;",
+					"select(person:tobie)",
+					"THROW 'denied';",
+				]
+			);
+			assert_eq!(entries[0].user, None);
+			let last = &entries[4];
+			assert_eq!(last.user.as_deref(), Some(ROOT_USER));
+			assert_eq!(last.namespace.as_deref(), Some("namespace"));
+			assert_eq!(last.database.as_deref(), Some("database"));
+			assert!(last.error.as_deref().unwrap().contains("denied"), "{last:?}");
+			assert!(entries[..4].iter().all(|entry| entry.error.is_none()));
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn error_hook() {
+			use surrealdb::opt::QueryContext;
+			let observed = Arc::new(Mutex::new(Vec::<(String, QueryContext)>::new()));
+			let hook = observed.clone();
+			let config = Config::new()
+				.user(Root {
+					username: ROOT_USER,
+					password: ROOT_PASS,
+				})
+				.error_hook(Arc::new(move |error, context| {
+					hook.lock().unwrap().push((error.to_string(), context.clone()));
+				}));
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.signin(Root {
+				username: ROOT_USER,
+				password: ROOT_PASS,
+			})
+			.await
+			.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			db.query("CREATE person:tobie").await.unwrap().check().unwrap();
+			assert!(observed.lock().unwrap().is_empty());
+			// Every failed statement of a query is observed with its own context
+			let mut response = db
+				.query(
+					"BEGIN; CREATE person:jaime; COMMIT; THROW 'first'; RETURN 1; THROW 'second'",
+				)
+				.await
+				.unwrap();
+			let error = response.take_errors();
+			assert_eq!(error.len(), 2);
+			// Errors of other requests are observed with the request as their context
+			let error = db.create::<Option<RecordId>>(("person", "tobie")).await.unwrap_err();
+			db.invalidate().await.unwrap();
+			db.query("THROW 'anonymous'").await.unwrap_err();
+			let observed = observed.lock().unwrap().clone();
+			let statements: Vec<_> =
+				observed.iter().map(|(_, context)| context.statement.as_str()).collect();
+			assert_eq!(
+				statements,
+				["THROW 'first'", "THROW 'second'", "create(person:tobie)", "THROW 'anonymous';"]
+			);
+			assert!(observed[0].0.contains("first"), "{observed:?}");
+			assert!(observed[1].0.contains("second"), "{observed:?}");
+			// The hook sees the same error the caller receives
+			assert_eq!(observed[2].0, error.to_string());
+			assert_eq!(observed[2].1.method, "create");
+			for (_, context) in &observed[..3] {
+				assert_eq!(context.user.as_deref(), Some(ROOT_USER));
+				assert_eq!(context.namespace.as_deref(), Some("namespace"));
+				assert_eq!(context.database.as_deref(), Some("database"));
+			}
+			assert_eq!(observed[0].1.method, "query");
+			assert_eq!(observed[3].1.user, None);
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn max_concurrent_queries() {
+			use futures::StreamExt;
+			let config = Config::new()
+				.user(Root {
+					username: ROOT_USER,
+					password: ROOT_PASS,
+				})
+				.max_concurrent_queries(1);
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.signin(Root {
+				username: ROOT_USER,
+				password: ROOT_PASS,
+			})
+			.await
+			.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			// Each query only sees its own marker if no other query runs at the same time
+			let queries = (0..4)
+				.map(|i| {
+					format!(
+						"CREATE marker:{i}; SLEEP 50ms; LET $seen = count(SELECT * FROM marker); \
+						 DELETE marker:{i}; RETURN $seen;"
+					)
+				})
+				.collect();
+			let mut results = db.batch(queries).parallelism(4).await.unwrap();
+			while let Some((_, result)) = results.next().await {
+				assert_eq!(result.unwrap(), 1.into());
+			}
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn max_concurrent_queries_runs_queries_serially() {
+			// Returns the most markers seen by two slow queries sent at the same time
+			async fn most_seen(limit: usize) -> i64 {
+				let config = Config::new().max_concurrent_queries(limit);
+				let db = Surreal::new::<Mem>(config).await.unwrap();
+				db.use_ns("namespace").use_db("database").await.unwrap();
+				// Each query waits for a while for the other one to create its marker
+				let wait = "IF count(SELECT * FROM marker) < 2 { sleep(10ms) };".repeat(50);
+				let queries = (0..2).map(|i| {
+					let db = db.clone();
+					let query = format!(
+						"CREATE marker:{i}; {wait} LET $seen = count(SELECT * FROM marker); \
+						 DELETE marker:{i}; RETURN $seen;"
+					);
+					async move {
+						let mut response = db.query(query).await.unwrap();
+						let last = response.num_statements() - 1;
+						let seen: Option<i64> = response.take(last).unwrap();
+						seen.unwrap()
+					}
+				});
+				futures::future::join_all(queries).await.into_iter().max().unwrap()
+			}
+			// Without the limit getting in the way, the queries see each other's marker
+			assert_eq!(most_seen(2).await, 2);
+			assert_eq!(most_seen(1).await, 1);
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn max_concurrent_queries_keeps_session_changes_in_order() {
+			use futures::StreamExt;
+			let config = Config::new().max_concurrent_queries(2);
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			// A slow query sent first still runs on the database it was sent to
+			let slow = async {
+				let mut response = db.query("SLEEP 100ms; RETURN session::db()").await.unwrap();
+				let db: Option<String> = response.take(1).unwrap();
+				db
+			};
+			let changes = async {
+				db.use_db("other").await.unwrap();
+				db.set("name", "tobie").await.unwrap();
+				// The changes are visible to the request sent right after them
+				let mut response = db.query("RETURN session::db(); RETURN $name").await.unwrap();
+				let current: Option<String> = response.take(0).unwrap();
+				let name: Option<String> = response.take(1).unwrap();
+				(current, name)
+			};
+			let (slow, (current, name)) = tokio::join!(slow, changes);
+			assert_eq!(current.as_deref(), Some("other"));
+			assert_eq!(name.as_deref(), Some("tobie"));
+			assert_eq!(slow.as_deref(), Some("database"));
+			// Live queries started by a query are registered with the connection
+			let mut stream = db
+				.query("LIVE SELECT * FROM person")
+				.await
+				.unwrap()
+				.stream::<surrealdb::Notification<RecordId>>(0)
+				.unwrap();
+			db.query("CREATE person:tobie").await.unwrap().check().unwrap();
+			let notification = tokio::time::timeout(Duration::from_secs(5), stream.next())
+				.await
+				.unwrap()
+				.unwrap()
+				.unwrap();
+			assert_eq!(notification.action, surrealdb::Action::Create);
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn unsupported_isolation() {
+			use surrealdb::opt::IsolationLevel;
+			// Write transactions on the memory engine always run one after another
+			Surreal::new::<Mem>(Config::new().isolation(IsolationLevel::Serializable))
+				.await
+				.unwrap();
+			let Err(Error::Db(DbError::Unsupported {
+				..
+			})) = Surreal::new::<Mem>(Config::new().isolation(IsolationLevel::Snapshot)).await
+			else {
+				panic!("expected snapshot isolation to be unsupported");
+			};
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn default_timezone() {
+			let config = Config::new().timezone("Asia/Tokyo");
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			let mut response = db
+				.query("RETURN time::format(d'2024-01-01T20:30:00Z', '%Y-%m-%d %H:%M %Z')")
+				.query("RETURN <string> d'2024-01-01T20:30:00Z'")
+				.await
+				.unwrap();
+			let formatted: Option<String> = response.take(0).unwrap();
+			assert_eq!(formatted.as_deref(), Some("2024-01-02 05:30 JST"));
+			// Datetimes themselves remain in UTC
+			let stored: Option<String> = response.take(1).unwrap();
+			assert_eq!(stored.as_deref(), Some("2024-01-01T20:30:00Z"));
+			// Unknown timezones are rejected when connecting
+			let config = Config::new().timezone("Mars/Olympus_Mons");
+			let Error::Api(ApiError::InvalidTimezone(name)) =
+				Surreal::new::<Mem>(config).await.unwrap_err()
+			else {
+				panic!("expected the timezone to be invalid");
+			};
+			assert_eq!(name, "Mars/Olympus_Mons");
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn custom_function_capabilities() {
+			let capabilities = Capabilities::default()
+				.with_allow_function("fn::x")
+				.unwrap()
+				.with_allow_function("fn::reports::*")
+				.unwrap();
+			let config = Config::new().capabilities(capabilities);
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.use_ns("test").use_db("test").await.unwrap();
+			db.query(
+				"
+				DEFINE FUNCTION fn::x() { RETURN 'x' };
+				DEFINE FUNCTION fn::y() { RETURN 'y' };
+				DEFINE FUNCTION fn::reports::daily() { RETURN 'daily' };
+				",
+			)
+			.await
+			.unwrap()
+			.check()
+			.unwrap();
+			let mut response =
+				db.query("RETURN fn::x(); RETURN fn::reports::daily()").await.unwrap();
+			let x: Option<String> = response.take(0).unwrap();
+			assert_eq!(x.as_deref(), Some("x"));
+			let daily: Option<String> = response.take(1).unwrap();
+			assert_eq!(daily.as_deref(), Some("daily"));
+			let error = db.query("RETURN fn::y()").await.unwrap().check().unwrap_err();
+			let Error::Db(DbError::FunctionNotAllowed(name)) = error else {
+				panic!("{error:?}");
+			};
+			assert_eq!(name, "fn::y");
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn max_statements_per_query() {
+			let db = Surreal::new::<Mem>(Config::new().max_statements_per_query(2)).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			// Queries over the cap are rejected before anything runs
+			let Error::Api(ApiError::TooManyStatements {
+				count: 3,
+				max: 2,
+			}) = db.query("CREATE item:1; CREATE item:2; CREATE item:3").await.unwrap_err()
+			else {
+				panic!("expected too many statements");
+			};
+			let records: Vec<RecordId> = db.select("item").await.unwrap();
+			assert!(records.is_empty());
+			// Comments and empty statements are not counted
+			db.query("-- create two items\nCREATE item:1;; CREATE item:2;")
+				.await
+				.unwrap()
+				.check()
+				.unwrap();
+			let records: Vec<RecordId> = db.select("item").await.unwrap();
+			assert_eq!(records.len(), 2);
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn max_bound_params() {
+			let db = Surreal::new::<Mem>(Config::new().max_bound_params(2)).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			// Queries over the cap are rejected before anything runs
+			let Error::Api(ApiError::TooManyParameters {
+				count: 3,
+				max: 2,
+			}) = db.query("CREATE item:1 SET a = $a, b = $b, c = $c")
+				.bind(("a", 1))
+				.bind(("b", 2))
+				.bind(("c", 3))
+				.await
+				.unwrap_err()
+			else {
+				panic!("expected too many parameters");
+			};
+			let records: Vec<RecordId> = db.select("item").await.unwrap();
+			assert!(records.is_empty());
+			// Variables set on the connection are not counted
+			db.set("c", 3).await.unwrap();
+			db.query("CREATE item:1 SET a = $a, b = $b, c = $c")
+				.bind(("a", 1))
+				.bind(("b", 2))
+				.await
+				.unwrap()
+				.check()
+				.unwrap();
+			let records: Vec<RecordId> = db.select("item").await.unwrap();
+			assert_eq!(records.len(), 1);
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn allow_guest() {
+			async fn guest_db(allow: bool) -> Surreal<Db> {
+				let root = Root {
+					username: ROOT_USER,
+					password: ROOT_PASS,
+				};
+				let config = Config::new().user(root).allow_guest(allow);
+				let db = Surreal::new::<Mem>(config).await.unwrap();
+				db.signin(root).await.unwrap();
+				db.use_ns("namespace").use_db("database").await.unwrap();
+				db.query("DEFINE TABLE item PERMISSIONS FULL; CREATE item:one;")
+					.await
+					.unwrap()
+					.check()
+					.unwrap();
+				// Sign out to continue as a guest
+				db.invalidate().await.unwrap();
+				db
+			}
+			// Without guest access, requests from a session which has not signed in are rejected
+			let db = guest_db(false).await;
+			let error = db.select::<Vec<RecordId>>("item").await.unwrap_err();
+			assert!(matches!(error, Error::Api(ApiError::NotAuthenticated)), "{error}");
+			let error = db.query("RETURN 1").await.unwrap_err();
+			assert!(matches!(error, Error::Api(ApiError::NotAuthenticated)), "{error}");
+			db.health().await.unwrap();
+			// With guest access, public records can be read without signing in
+			let db = guest_db(true).await;
+			let records: Vec<RecordId> = db.select("item").await.unwrap();
+			assert_eq!(records.len(), 1);
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn guest_permissions() {
+			use surrealdb::sql::Permission;
+			async fn guest_db(permission: Permission) -> Surreal<Db> {
+				let root = Root {
+					username: ROOT_USER,
+					password: ROOT_PASS,
+				};
+				let config =
+					Config::new().user(root).allow_guest(true).guest_permissions(permission);
+				let db = Surreal::new::<Mem>(config).await.unwrap();
+				db.signin(root).await.unwrap();
+				db.use_ns("namespace").use_db("database").await.unwrap();
+				db.query(
+					"
+					DEFINE TABLE item;
+					DEFINE TABLE public PERMISSIONS FULL;
+					DEFINE TABLE private PERMISSIONS NONE;
+					CREATE item:one, public:one, private:one;
+					",
+				)
+				.await
+				.unwrap()
+				.check()
+				.unwrap();
+				// The signed in session still sees every table
+				let records: Vec<RecordId> = db.select("item").await.unwrap();
+				assert_eq!(records.len(), 1);
+				// Sign out to continue as a guest
+				db.invalidate().await.unwrap();
+				db
+			}
+			// With a restrictive default, guests can't read tables without permissions
+			let db = guest_db(Permission::None).await;
+			let records: Vec<RecordId> = db.select("item").await.unwrap();
+			assert!(records.is_empty());
+			let records: Vec<RecordId> = db.select("public").await.unwrap();
+			assert_eq!(records.len(), 1);
+			// With a permissive default they can, while explicit permissions still apply
+			let db = guest_db(Permission::Full).await;
+			let records: Vec<RecordId> = db.select("item").await.unwrap();
+			assert_eq!(records.len(), 1);
+			let records: Vec<RecordId> = db.select("private").await.unwrap();
+			assert!(records.is_empty());
+			// The default only lets guests read, so they still can't write
+			db.query("CREATE item:two; DELETE item:one").await.unwrap();
+			let records: Vec<RecordId> = db.select("item").await.unwrap();
+			assert_eq!(records.len(), 1);
+			assert_eq!(records[0].id, thing("item:one").unwrap());
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn session_expiry() {
+			use std::time::Duration;
+			let root = Root {
+				username: ROOT_USER,
+				password: ROOT_PASS,
+			};
+			let config = Config::new().user(root).session_expiry(Duration::from_millis(200));
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.signin(root).await.unwrap();
+			db.query("INFO FOR ROOT").await.unwrap().check().unwrap();
+			// Once the session has been idle for too long, privileged queries are rejected
+			tokio::time::sleep(Duration::from_millis(300)).await;
+			let error = db.query("INFO FOR ROOT").await.unwrap_err();
+			assert!(matches!(error, Error::Api(ApiError::SessionExpired)), "{error}");
+			let error = db.query("RETURN 1").await.unwrap_err();
+			assert!(matches!(error, Error::Api(ApiError::SessionExpired)), "{error}");
+			// Health checks are still answered
+			db.health().await.unwrap();
+			// Signing in again restores access
+			db.signin(root).await.unwrap();
+			db.query("INFO FOR ROOT").await.unwrap().check().unwrap();
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn deny_full_scans() {
+			let config = Config::new().deny_full_scans(true).full_scan_threshold(10);
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			let mut sql = String::from("DEFINE INDEX email ON user FIELDS email;");
+			for i in 0..20 {
+				sql.push_str(&format!("CREATE user SET email = 'user{i}', age = {i};"));
+			}
+			for i in 0..5 {
+				sql.push_str(&format!("CREATE team SET size = {i};"));
+			}
+			db.query(sql).await.unwrap().check().unwrap();
+			// Filtering a large table on an unindexed field is rejected
+			let Error::Api(ApiError::FullScanDenied {
+				table,
+				threshold: 10,
+			}) = db.query("SELECT * FROM user WHERE age = 5").await.unwrap_err()
+			else {
+				panic!("expected the full scan to be denied");
+			};
+			assert_eq!(table, "user");
+			// Filtering on an indexed field runs
+			let mut response = db
+				.query("SELECT * FROM user WHERE email = 'user5'")
+				.await
+				.unwrap()
+				.check()
+				.unwrap();
+			let users: Vec<RecordId> = response.take(0).unwrap();
+			assert_eq!(users.len(), 1);
+			// Small tables are exempt
+			let mut response = db.query("SELECT * FROM team WHERE size = 2").await.unwrap();
+			let teams: Vec<RecordId> = response.take(0).unwrap();
+			assert_eq!(teams.len(), 1);
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn experimental_features() {
+			let sql = "DEFINE INDEX hnsw_pts ON pts FIELDS point HNSW DIMENSION 4";
+			// Experimental features are disabled by default
+			let db = Surreal::new::<Mem>(()).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			match db.query(sql).await.unwrap_err() {
+				Error::Api(ApiError::ExperimentalFeatureDisabled(
+					ExperimentalFeature::HnswIndex,
+				)) => {}
+				error => panic!("{error:?}"),
+			}
+			match db.define_index_async(sql).await.unwrap_err() {
+				Error::Api(ApiError::ExperimentalFeatureDisabled(
+					ExperimentalFeature::HnswIndex,
+				)) => {}
+				error => panic!("{error:?}"),
+			}
+			// Nothing was defined, while other index types are unaffected
+			db.query("DEFINE INDEX name ON pts FIELDS name").await.unwrap().check().unwrap();
+			let mut response = db.query("INFO FOR TABLE pts").await.unwrap();
+			let indexes: Option<serde_json::Value> = response.take("indexes").unwrap();
+			assert_eq!(indexes, Some(json!({ "name": "DEFINE INDEX name ON pts FIELDS name" })));
+			// Enabling the feature lets it run
+			let config = Config::new().experimental(vec![ExperimentalFeature::HnswIndex]);
+			let db = Surreal::new::<Mem>(config).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			db.query(sql).await.unwrap().check().unwrap();
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn statement_cache() {
+			let cached = Surreal::new::<Mem>(Config::new().statement_cache_size(10)).await.unwrap();
+			let uncached = Surreal::new::<Mem>(()).await.unwrap();
+			let sql = "CREATE type::thing('item', $id) SET value = $value; SELECT * FROM item ORDER BY id";
+			for db in [&cached, &uncached] {
+				db.use_ns("namespace").use_db("database").await.unwrap();
+			}
+			// The same query text is run with different bindings each time
+			for (id, value) in [(1, "one"), (2, "two"), (3, "three")] {
+				let mut results = Vec::new();
+				for db in [&cached, &uncached] {
+					let mut response =
+						db.query(sql).bind(("id", id)).bind(("value", value)).await.unwrap();
+					let records: Value = response.take(1).unwrap();
+					results.push(records);
+				}
+				assert_eq!(results[0], results[1]);
+			}
+			let records: Vec<RecordId> =
+				cached.query(sql).bind(("id", 4)).await.unwrap().take(1).unwrap();
+			assert_eq!(records.len(), 4);
+			// Invalid queries still fail
+			cached.query("SELEC * FROM item").await.unwrap_err();
+			cached.query("SELEC * FROM item").await.unwrap_err();
+		}
+
 		#[test_log::test(tokio::test)]
 		async fn surreal_clone() {
 			use surrealdb::engine::any::Any;
@@ -269,6 +858,18 @@ mod api_integration {
 		include!("api/mod.rs");
 		include!("api/live.rs");
 		include!("api/backup.rs");
+		include!("api/metrics.rs");
+		include!("api/index_build.rs");
+		include!("api/record_meta.rs");
+		include!("api/create_if_absent.rs");
+		include!("api/export_schema.rs");
+		include!("api/index_stats.rs");
+		include!("api/explain_cost.rs");
+		include!("api/select_diff.rs");
+		include!("api/record_lock.rs");
+		include!("api/select_graph.rs");
+		include!("api/query_with_stats.rs");
+		include!("api/define_table_from.rs");
 	}
 
 	#[cfg(feature = "kv-rocksdb")]
@@ -304,6 +905,18 @@ mod api_integration {
 		include!("api/mod.rs");
 		include!("api/live.rs");
 		include!("api/backup.rs");
+		include!("api/metrics.rs");
+		include!("api/index_build.rs");
+		include!("api/record_meta.rs");
+		include!("api/create_if_absent.rs");
+		include!("api/export_schema.rs");
+		include!("api/index_stats.rs");
+		include!("api/explain_cost.rs");
+		include!("api/select_diff.rs");
+		include!("api/record_lock.rs");
+		include!("api/select_graph.rs");
+		include!("api/query_with_stats.rs");
+		include!("api/define_table_from.rs");
 	}
 
 	#[cfg(feature = "kv-rocksdb")]
@@ -339,6 +952,18 @@ mod api_integration {
 		include!("api/mod.rs");
 		include!("api/live.rs");
 		include!("api/backup.rs");
+		include!("api/metrics.rs");
+		include!("api/index_build.rs");
+		include!("api/record_meta.rs");
+		include!("api/create_if_absent.rs");
+		include!("api/export_schema.rs");
+		include!("api/index_stats.rs");
+		include!("api/explain_cost.rs");
+		include!("api/select_diff.rs");
+		include!("api/record_lock.rs");
+		include!("api/select_graph.rs");
+		include!("api/query_with_stats.rs");
+		include!("api/define_table_from.rs");
 	}
 
 	#[cfg(feature = "kv-tikv")]
@@ -372,6 +997,18 @@ mod api_integration {
 		include!("api/mod.rs");
 		include!("api/live.rs");
 		include!("api/backup.rs");
+		include!("api/metrics.rs");
+		include!("api/index_build.rs");
+		include!("api/record_meta.rs");
+		include!("api/create_if_absent.rs");
+		include!("api/export_schema.rs");
+		include!("api/index_stats.rs");
+		include!("api/explain_cost.rs");
+		include!("api/select_diff.rs");
+		include!("api/record_lock.rs");
+		include!("api/select_graph.rs");
+		include!("api/query_with_stats.rs");
+		include!("api/define_table_from.rs");
 	}
 
 	#[cfg(feature = "kv-fdb")]
@@ -402,6 +1039,18 @@ mod api_integration {
 		include!("api/mod.rs");
 		include!("api/live.rs");
 		include!("api/backup.rs");
+		include!("api/metrics.rs");
+		include!("api/index_build.rs");
+		include!("api/record_meta.rs");
+		include!("api/create_if_absent.rs");
+		include!("api/export_schema.rs");
+		include!("api/index_stats.rs");
+		include!("api/explain_cost.rs");
+		include!("api/select_diff.rs");
+		include!("api/record_lock.rs");
+		include!("api/select_graph.rs");
+		include!("api/query_with_stats.rs");
+		include!("api/define_table_from.rs");
 	}
 
 	#[cfg(feature = "kv-surrealkv")]
@@ -434,9 +1083,153 @@ mod api_integration {
 			tokio::fs::remove_dir_all(path).await.unwrap();
 		}
 
+		#[test_log::test(tokio::test)]
+		async fn connect_retry() {
+			use std::time::Duration;
+			// The storage can't be opened while its parent directory is a file
+			let blocker = format!("/tmp/{}", Ulid::new());
+			let path = format!("{blocker}/data.db");
+			tokio::fs::write(&blocker, "").await.unwrap();
+			let error = Surreal::new::<SurrealKV>(path.as_str()).await.unwrap_err();
+			assert!(error.to_string().contains("Unable to connect"), "{error}");
+			// Removing the file lets a later attempt succeed
+			let remove = tokio::spawn({
+				let blocker = blocker.clone();
+				async move {
+					tokio::time::sleep(Duration::from_millis(200)).await;
+					tokio::fs::remove_file(blocker).await.unwrap();
+				}
+			});
+			let config = Config::new().connect_retry(10, Duration::from_millis(20));
+			let db = Surreal::new::<SurrealKV>((path.as_str(), config)).await.unwrap();
+			remove.await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			db.query("CREATE person:tobie").await.unwrap().check().unwrap();
+			// Errors other than failing to reach the storage are not tried again
+			let config = Config::new()
+				.connect_retry(10, Duration::from_secs(10))
+				.timezone("Nowhere/Nothing");
+			let started = std::time::Instant::now();
+			Surreal::new::<SurrealKV>((path.as_str(), config)).await.unwrap_err();
+			assert!(started.elapsed() < Duration::from_secs(5));
+			drop(db);
+			tokio::fs::remove_dir_all(blocker).await.unwrap();
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn transaction_isolation() {
+			use futures::StreamExt;
+			use surrealdb::opt::IsolationLevel;
+			// Each doctor may only go off call if the other one is still on call
+			async fn on_call_after_write_skew(level: IsolationLevel) -> usize {
+				let path = format!("/tmp/{}.db", Ulid::new());
+				let config = Config::new().isolation(level);
+				let db = Surreal::new::<SurrealKV>((path, config)).await.unwrap();
+				db.use_ns("namespace").use_db("database").await.unwrap();
+				db.query("CREATE doctor:alice, doctor:bob SET on_call = true")
+					.await
+					.unwrap()
+					.check()
+					.unwrap();
+				let queries = ["alice", "bob"].map(|doctor| {
+					format!(
+						"BEGIN;
+						LET $on_call = SELECT VALUE on_call FROM doctor:alice, doctor:bob;
+						SLEEP 200ms;
+						IF $on_call = [true, true] {{ UPDATE doctor:{doctor} SET on_call = false }};
+						COMMIT;"
+					)
+				});
+				let mut results = db.batch(queries.to_vec()).parallelism(2).await.unwrap();
+				while results.next().await.is_some() {}
+				let on_call: Vec<RecordId> =
+					db.query("SELECT id FROM doctor WHERE on_call").await.unwrap().take(0).unwrap();
+				on_call.len()
+			}
+			// The second transaction to commit conflicts with the first one
+			assert_eq!(on_call_after_write_skew(IsolationLevel::Serializable).await, 1);
+			// Both transactions commit, as they write different records
+			assert_eq!(on_call_after_write_skew(IsolationLevel::Snapshot).await, 0);
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn transaction_with_retry() {
+			use futures::StreamExt;
+			use std::sync::atomic::AtomicUsize;
+			use std::sync::atomic::Ordering;
+			let path = format!("/tmp/{}.db", Ulid::new());
+			let db = Surreal::new::<SurrealKV>(path).await.unwrap();
+			db.use_ns("namespace").use_db("database").await.unwrap();
+			db.query("CREATE counter:hits SET value = 0").await.unwrap().check().unwrap();
+			let increment = |delay: &str| {
+				format!(
+					"LET $value = (SELECT VALUE value FROM ONLY counter:hits);
+					SLEEP {delay};
+					UPDATE counter:hits SET value = $value + 1;"
+				)
+			};
+			// Another transaction increments the counter while this one is still running
+			let concurrent = format!("BEGIN; {} COMMIT;", increment("100ms"));
+			let mut batch = db.batch(vec![concurrent]).await.unwrap();
+			let attempts = AtomicUsize::new(0);
+			db.transaction_with_retry(3, |_| {
+				attempts.fetch_add(1, Ordering::SeqCst);
+				let sql = increment("300ms");
+				async move { Ok(sql) }
+			})
+			.await
+			.unwrap()
+			.check()
+			.unwrap();
+			let (_, result) = batch.next().await.unwrap();
+			result.unwrap();
+			// The first attempt conflicted, so the transaction was run again from scratch
+			assert_eq!(attempts.load(Ordering::SeqCst), 2);
+			let value: Option<i64> = db
+				.query("SELECT VALUE value FROM ONLY counter:hits")
+				.await
+				.unwrap()
+				.take(0)
+				.unwrap();
+			assert_eq!(value, Some(2));
+		}
+
+		#[test_log::test(tokio::test)]
+		async fn flush_interval() {
+			for interval in [Duration::ZERO, Duration::from_secs(3600)] {
+				let path = format!("/tmp/{}.db", Ulid::new());
+				let config = Config::new().flush_interval(interval);
+				let db = Surreal::new::<SurrealKV>((path.as_str(), config)).await.unwrap();
+				db.use_ns("namespace").use_db("database").await.unwrap();
+				for i in 0..100 {
+					db.query(format!("CREATE person:{i}")).await.unwrap().check().unwrap();
+				}
+				drop(db);
+				// Commits which were not synced yet are still there after a clean shutdown
+				let db = Surreal::new::<SurrealKV>(path.as_str()).await.unwrap();
+				db.use_ns("namespace").use_db("database").await.unwrap();
+				let count: Option<usize> =
+					db.query("count(SELECT * FROM person)").await.unwrap().take(0).unwrap();
+				assert_eq!(count, Some(100));
+			}
+		}
+
 		include!("api/mod.rs");
 		include!("api/live.rs");
 		include!("api/backup.rs");
+		include!("api/metrics.rs");
+		// Background index builds are not tested, as SurrealKV fails to commit a transaction which
+		// deletes a key and writes it again, which is what rebuilding an index does
+		include!("api/record_meta.rs");
+		include!("api/create_if_absent.rs");
+		include!("api/export_schema.rs");
+		include!("api/index_stats.rs");
+		include!("api/explain_cost.rs");
+		include!("api/select_diff.rs");
+		include!("api/record_lock.rs");
+		include!("api/select_graph.rs");
+		include!("api/query_with_stats.rs");
+		include!("api/define_table_from.rs");
 	}
 
 	#[cfg(feature = "protocol-http")]
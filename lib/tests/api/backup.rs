@@ -32,6 +32,38 @@ async fn export_import() {
 	res.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn export_import_default_permissions() {
+	let (permit, db) = new_db().await;
+	let db_name = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&db_name).await.unwrap();
+	drop(permit);
+	db.query("DEFINE TABLE item; DEFINE TABLE locked PERMISSIONS NONE")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let file = format!("{db_name}.sql");
+	let res = async {
+		db.export(&file).await?;
+		db.query("REMOVE TABLE item; REMOVE TABLE locked").await?.check()?;
+		db.import(&file).await?;
+		Result::<(), Error>::Ok(())
+	}
+	.await;
+	remove_file(file).await.unwrap();
+	res.unwrap();
+	// Only the table with a clause of its own gets one back, so the other still uses the default
+	let mut response = db
+		.query("LET $tables = (INFO FOR DB).tables; RETURN $tables.item; RETURN $tables.locked")
+		.await
+		.unwrap();
+	let item: Option<String> = response.take(1).unwrap();
+	let locked: Option<String> = response.take(2).unwrap();
+	assert_eq!(item.as_deref(), Some("DEFINE TABLE item TYPE ANY SCHEMALESS"));
+	assert_eq!(locked.as_deref(), Some("DEFINE TABLE locked TYPE ANY SCHEMALESS PERMISSIONS NONE"));
+}
+
 #[test_log::test(tokio::test)]
 #[cfg(feature = "ml")]
 async fn ml_export_import() {
@@ -45,3 +77,55 @@ async fn ml_export_import() {
 	db.import(&file).ml().await.unwrap();
 	remove_file(file).await.unwrap();
 }
+
+#[test_log::test(tokio::test)]
+#[cfg(feature = "kv-mem")]
+async fn copy_to() {
+	use surrealdb::engine::local::Mem;
+	use surrealdb::opt::Conflict;
+	use surrealdb::opt::CopyOptions;
+
+	let source = Surreal::new::<Mem>(()).await.unwrap();
+	source.use_ns(NS).use_db("source").await.unwrap();
+	source
+		.query(
+			"
+			DEFINE TABLE user SCHEMAFULL;
+			DEFINE FIELD name ON user TYPE string;
+			CREATE |user:1..2500| SET name = 'User';
+			CREATE |post:1..10|;
+			RELATE user:1->wrote->post:1;
+			",
+		)
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let (permit, db) = new_db().await;
+	let db_name = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&db_name).await.unwrap();
+	drop(permit);
+	// Only the selected tables are copied
+	source.copy_to(&db, CopyOptions::new().tables(["user", "wrote"])).await.unwrap();
+	let mut response = db
+		.query("SELECT count() FROM user GROUP ALL")
+		.query("SELECT VALUE out FROM wrote")
+		.query("SELECT * FROM post")
+		.await
+		.unwrap();
+	let count: Option<usize> = response.take((0, "count")).unwrap();
+	assert_eq!(count, Some(2500));
+	let out: Vec<Thing> = response.take(1).unwrap();
+	assert_eq!(out, vec![thing("post:1").unwrap()]);
+	let posts: Vec<RecordId> = response.take(2).unwrap();
+	assert!(posts.is_empty());
+	// The copied definitions are enforced by the target
+	db.query("CREATE user:0 SET name = 0").await.unwrap().check().unwrap_err();
+	// Existing records are skipped by default, or rejected when asked to
+	source.copy_to(&db, CopyOptions::new()).await.unwrap();
+	let posts: Vec<RecordId> = db.select("post").await.unwrap();
+	assert_eq!(posts.len(), 10);
+	let error =
+		source.copy_to(&db, CopyOptions::new().conflict(Conflict::Error)).await.unwrap_err();
+	assert!(error.to_string().contains("already exists"), "{error}");
+}
@@ -0,0 +1,89 @@
+// Tests for creating records only if they don't exist yet
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn create_if_not_exists() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	#[derive(Debug, Serialize, Deserialize)]
+	struct User {
+		name: String,
+	}
+	let created = db
+		.create_if_not_exists::<User>(("user", "john"))
+		.content(User {
+			name: "John".to_owned(),
+		})
+		.await
+		.unwrap();
+	assert!(created.created);
+	assert_eq!(created.record.name, "John");
+	let existing = db
+		.create_if_not_exists::<User>(("user", "john"))
+		.content(User {
+			name: "Jane".to_owned(),
+		})
+		.await
+		.unwrap();
+	assert!(!existing.created);
+	assert_eq!(existing.record.name, "John");
+	let users: Vec<User> = db.select("user").await.unwrap();
+	assert_eq!(users.len(), 1);
+}
+
+#[test_log::test(tokio::test)]
+async fn create_if_not_exists_from_many_tasks() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Counter {
+		writer: i64,
+	}
+	// The connection handles the requests one after another, so only the first one creates
+	let mut handles = Vec::new();
+	for i in 0..10 {
+		let db = db.clone();
+		handles.push(tokio::spawn(async move {
+			db.create_if_not_exists::<Counter>(("counter", "one"))
+				.content(Counter {
+					writer: i,
+				})
+				.await
+				.unwrap()
+		}));
+	}
+	let mut created = Vec::new();
+	for handle in handles {
+		let result = handle.await.unwrap();
+		if result.created {
+			created.push(result.record);
+		}
+	}
+	let [winner] = &created[..] else {
+		panic!("{created:?}");
+	};
+	let stored: Option<Counter> = db.select(("counter", "one")).await.unwrap();
+	assert_eq!(stored.as_ref(), Some(winner));
+}
+
+#[test_log::test(tokio::test)]
+async fn create_if_not_exists_hidden() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("DEFINE TABLE secret PERMISSIONS FOR select NONE, FOR create FULL; CREATE secret:one")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	db.invalidate().await.unwrap();
+	// The record exists but guests can't see it
+	match db.create_if_not_exists::<RecordId>(("secret", "one")).await.unwrap_err() {
+		Error::Db(DbError::RecordExists {
+			..
+		}) => {}
+		error => panic!("{error:?}"),
+	}
+}
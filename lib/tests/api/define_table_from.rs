@@ -0,0 +1,99 @@
+// Tests for defining a table from the fields of a Rust type
+
+#[test_log::test(tokio::test)]
+async fn define_table_from() {
+	use std::collections::BTreeMap;
+	use surrealdb::opt::TableSchema;
+	use surrealdb::sql::Datetime;
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Address {
+		city: String,
+		postcode: Option<String>,
+	}
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Person {
+		id: Option<Thing>,
+		name: String,
+		age: u8,
+		score: f64,
+		joined: Datetime,
+		address: Address,
+		tags: Vec<String>,
+		meta: BTreeMap<String, String>,
+	}
+
+	let fields: Vec<_> = Person::table_fields()
+		.unwrap()
+		.into_iter()
+		.map(|field| (field.name.to_string(), field.kind.to_string(), field.flexible))
+		.collect();
+	let expected = [
+		("name", "string", false),
+		("age", "int", false),
+		("score", "float", false),
+		("joined", "datetime", false),
+		("address", "object", false),
+		("address.city", "string", false),
+		("address.postcode", "option<string>", false),
+		("tags", "array<string>", false),
+		("meta", "object", true),
+	];
+	let expected: Vec<_> = expected
+		.into_iter()
+		.map(|(name, kind, flexible)| (name.to_owned(), kind.to_owned(), flexible))
+		.collect();
+	assert_eq!(fields, expected);
+	// Only structs describe a table
+	Vec::<String>::table_fields().unwrap_err();
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.define_table_from::<Person>("person").await.unwrap();
+	// Values of the type are accepted
+	let person = Person {
+		id: None,
+		name: "Tobie".to_owned(),
+		age: 33,
+		score: 9.5,
+		joined: Datetime::default(),
+		address: Address {
+			city: "London".to_owned(),
+			postcode: None,
+		},
+		tags: vec!["founder".to_owned()],
+		meta: BTreeMap::from([("team".to_owned(), "core".to_owned())]),
+	};
+	let created: Option<Person> = db.create(("person", "tobie")).content(person).await.unwrap();
+	let created = created.unwrap();
+	assert_eq!(created.id, Some(thing("person:tobie").unwrap()));
+	assert_eq!(created.address.city, "London");
+	assert_eq!(created.meta["team"], "core");
+	// Values with fields of the wrong type are rejected
+	let result = db
+		.query(
+			"CREATE person CONTENT {
+				name: 'Jaime', age: 'old', score: 1.5, joined: time::now(),
+				address: { city: 'Paris' }, tags: [], meta: {}
+			}",
+		)
+		.await
+		.unwrap()
+		.check();
+	result.unwrap_err();
+	let result = db
+		.query(
+			"CREATE person CONTENT {
+				name: 'Jaime', age: 40, score: 1.5, joined: time::now(),
+				address: { city: 75 }, tags: [], meta: {}
+			}",
+		)
+		.await
+		.unwrap()
+		.check();
+	result.unwrap_err();
+	// The table can't be defined twice
+	db.define_table_from::<Person>("person").await.unwrap_err();
+}
@@ -0,0 +1,42 @@
+// Tests for estimating the cost of a query
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn explain_cost() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let mut sql = String::from(
+		"DEFINE INDEX person_email ON person FIELDS email UNIQUE;
+		DEFINE INDEX person_team ON person FIELDS team;",
+	);
+	for i in 0..100 {
+		let team = i % 4;
+		sql.push_str(&format!(
+			"CREATE person SET email = 'person{i}', name = 'person{i}', team = {team};"
+		));
+	}
+	db.query(sql).await.unwrap().check().unwrap();
+	let lookup = db.explain_cost("SELECT * FROM person WHERE email = 'person7'").await.unwrap();
+	assert_eq!(lookup.rows, 1);
+	// The entries of the index matched by a lookup are counted
+	let team = db.explain_cost("SELECT * FROM person WHERE team = 3").await.unwrap();
+	assert_eq!(team.rows, 25);
+	let teams = db.explain_cost("SELECT * FROM person WHERE team >= 2").await.unwrap();
+	assert_eq!(teams.rows, 50);
+	let missing = db.explain_cost("SELECT * FROM person WHERE email = 'nobody'").await.unwrap();
+	assert_eq!(missing.rows, 0);
+	// A scan reads every record of the table
+	let scan = db
+		.explain_cost("SELECT * FROM person WHERE name = $name")
+		.bind(("name", "person7"))
+		.await
+		.unwrap();
+	assert_eq!(scan.rows, 100);
+	assert!(lookup.cost < team.cost, "{lookup:?} {team:?}");
+	assert!(team.cost < scan.cost, "{team:?} {scan:?}");
+	// Nothing is executed while estimating
+	db.explain_cost("DELETE person; SELECT * FROM person").await.unwrap();
+	let records: Vec<RecordId> = db.select("person").await.unwrap();
+	assert_eq!(records.len(), 100);
+}
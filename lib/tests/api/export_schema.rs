@@ -0,0 +1,44 @@
+// Tests for exporting the schema of a database
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn export_schema() {
+	let (permit, db) = new_db().await;
+	let db_name = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&db_name).await.unwrap();
+	drop(permit);
+	db.query(
+		"
+		DEFINE PARAM $max_age VALUE 120;
+		DEFINE FUNCTION fn::greet($name: string) { RETURN 'Hello ' + $name; };
+		DEFINE ANALYZER simple TOKENIZERS blank FILTERS lowercase;
+		DEFINE TABLE person SCHEMAFULL;
+		DEFINE FIELD name ON person TYPE string;
+		DEFINE FIELD age ON person TYPE int ASSERT $value <= $max_age;
+		DEFINE INDEX person_name ON person FIELDS name SEARCH ANALYZER simple BM25;
+		DEFINE EVENT person_created ON person WHEN $event = 'CREATE' THEN {};
+		CREATE person:tobie SET name = 'Tobie', age = 30;
+		",
+	)
+	.await
+	.unwrap()
+	.check()
+	.unwrap();
+	let schema = db.export_schema().await.unwrap();
+	assert!(!schema.contains("INSERT"), "{schema}");
+	let mut response = db.query("INFO FOR DB; INFO FOR TABLE person").await.unwrap();
+	let db_info: Value = response.take(0).unwrap();
+	let tb_info: Value = response.take(1).unwrap();
+	// Importing the schema into a fresh datastore reproduces it
+	let (permit, fresh) = new_db().await;
+	fresh.use_ns(NS).use_db(&db_name).await.unwrap();
+	drop(permit);
+	fresh.query(schema).await.unwrap().check().unwrap();
+	let mut response = fresh.query("INFO FOR DB; INFO FOR TABLE person").await.unwrap();
+	let imported: Value = response.take(0).unwrap();
+	assert_eq!(imported, db_info);
+	let imported: Value = response.take(1).unwrap();
+	assert_eq!(imported, tb_info);
+	let people: Vec<RecordId> = fresh.select("person").await.unwrap();
+	assert!(people.is_empty());
+}
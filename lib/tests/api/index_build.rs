@@ -0,0 +1,41 @@
+// Tests for building indexes in the background
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn define_index_async() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("CREATE |user:1000| SET tag = 'x'").await.unwrap().check().unwrap();
+	let build = db.define_index_async("DEFINE INDEX tag ON user FIELDS tag").await.unwrap();
+	// The connection keeps serving requests while the index is being built
+	for i in 1000..1100 {
+		db.query(format!("CREATE user:{i} SET tag = 'x'")).await.unwrap().check().unwrap();
+	}
+	// Records updated during the build are indexed with their new values only
+	for i in 0..50 {
+		db.query(format!("UPDATE user:{i} SET tag = 'y'")).await.unwrap().check().unwrap();
+	}
+	build.await.unwrap();
+	let mut response = db
+		.query("SELECT count() FROM user WITH INDEX tag WHERE tag = 'x' GROUP ALL")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let count: Option<usize> = response.take("count").unwrap();
+	assert_eq!(count, Some(1050));
+	let mut response = db
+		.query("SELECT count() FROM user WITH INDEX tag WHERE tag = 'y' GROUP ALL")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let count: Option<usize> = response.take("count").unwrap();
+	assert_eq!(count, Some(50));
+	// Only a single index definition is accepted
+	match db.define_index_async("SELECT * FROM user").await.unwrap_err() {
+		Error::Api(ApiError::InvalidParams(..)) => {}
+		error => panic!("{error:?}"),
+	}
+}
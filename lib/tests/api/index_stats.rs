@@ -0,0 +1,27 @@
+// Tests for reading the statistics of an index
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn index_stats() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let mut sql = String::from(
+		"DEFINE INDEX person_email ON person FIELDS email UNIQUE;
+		DEFINE INDEX person_country ON person FIELDS country;",
+	);
+	for i in 0..100 {
+		let country = ["uk", "fr", "de"][i % 3];
+		sql.push_str(&format!("CREATE person SET email = 'person{i}', country = '{country}';"));
+	}
+	db.query(sql).await.unwrap().check().unwrap();
+	let email = db.index_stats("person", "person_email").await.unwrap();
+	assert_eq!(email.total_entries, 100);
+	assert_eq!(email.distinct_keys, 100);
+	let country = db.index_stats("person", "person_country").await.unwrap();
+	assert_eq!(country.total_entries, 100);
+	assert_eq!(country.distinct_keys, 3);
+	assert!(email.selectivity > country.selectivity);
+	// Unknown indexes are reported as errors
+	db.index_stats("person", "person_name").await.unwrap_err();
+}
@@ -422,6 +422,135 @@ async fn live_select_query() {
 	drop(permit);
 }
 
+#[test_log::test(tokio::test)]
+async fn live_once() {
+	let (permit, db) = new_db().await;
+
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+
+	let table = format!("table_{}", Ulid::new());
+	if FFLAGS.change_feed_live_queries.enabled() {
+		db.query(format!("DEFINE TABLE {table} CHANGEFEED 10m INCLUDE ORIGINAL")).await.unwrap();
+	} else {
+		db.query(format!("DEFINE TABLE {table}")).await.unwrap();
+	}
+	let sql = format!("LIVE SELECT * FROM {table} WHERE value > 1");
+
+	// Start waiting in the background
+	let once = db.live_once::<RecordId>(&sql, LQ_TIMEOUT).into_owned();
+	let once = tokio::spawn(async move { once.await });
+	wait_for_live_queries(&db, &table, 1).await;
+
+	// Only the first matching write resolves the future
+	for (id, value) in [(1, 1), (2, 2), (3, 3)] {
+		db.query(format!("CREATE {table}:{id} SET value = {value}")).await.unwrap().check().unwrap();
+	}
+	let notification = once.await.unwrap().unwrap();
+	assert_eq!(notification.action, Action::Create);
+	assert_eq!(notification.data.id, format!("{table}:2").parse().unwrap());
+	// The live query is killed afterwards
+	wait_for_live_queries(&db, &table, 0).await;
+
+	// Waiting without any writes times out and kills the live query
+	match db.live_once::<RecordId>(&sql, Duration::from_millis(100)).await.unwrap_err() {
+		Error::Api(ApiError::Timeout(_)) => {}
+		error => panic!("{error:?}"),
+	}
+	wait_for_live_queries(&db, &table, 0).await;
+
+	drop(permit);
+}
+
+#[test_log::test(tokio::test)]
+async fn subscribe_table() {
+	let (permit, db) = new_db().await;
+
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+
+	let table = format!("table_{}", Ulid::new());
+	if FFLAGS.change_feed_live_queries.enabled() {
+		db.query(format!("DEFINE TABLE {table} CHANGEFEED 10m INCLUDE ORIGINAL")).await.unwrap();
+	} else {
+		db.query(format!("DEFINE TABLE {table}")).await.unwrap();
+	}
+
+	let mut records = db.subscribe_table::<RecordId>(&table).await.unwrap();
+	wait_for_live_queries(&db, &table, 1).await;
+
+	// Every write to the table produces a notification
+	let id: Thing = format!("{table}:one").parse().unwrap();
+	db.query(format!("CREATE {id}; UPDATE {id} SET value = 1; DELETE {id}"))
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	for action in [Action::Create, Action::Update, Action::Delete] {
+		let notification =
+			tokio::time::timeout(LQ_TIMEOUT, records.next()).await.unwrap().unwrap().unwrap();
+		assert_eq!(notification.action, action);
+		assert_eq!(notification.data.id, id);
+	}
+
+	// Dropping the stream kills the live query
+	drop(records);
+	wait_for_live_queries(&db, &table, 0).await;
+
+	drop(permit);
+}
+
+#[test_log::test(tokio::test)]
+async fn changes_stream() {
+	let (permit, db) = new_db().await;
+
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+
+	let table = format!("table_{}", Ulid::new());
+	db.query(format!("DEFINE TABLE {table} CHANGEFEED 10m")).await.unwrap().check().unwrap();
+	db.query(format!("CREATE {table}:one; CREATE {table}:two")).await.unwrap().check().unwrap();
+
+	let mut changes = db.changes_stream(&table, 0).await.unwrap();
+	wait_for_live_queries(&db, &table, 1).await;
+
+	// Changes made after subscribing arrive through the same stream
+	db.query(format!("UPDATE {table}:one SET value = 1; DELETE {table}:two; CREATE {table}:three"))
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let mut received = Vec::new();
+	for _ in 0..5 {
+		let change =
+			tokio::time::timeout(LQ_TIMEOUT, changes.next()).await.unwrap().unwrap().unwrap();
+		received.push(change);
+	}
+	let records: Vec<_> = received.iter().map(|change| change.record.id.to_raw()).collect();
+	assert_eq!(records, ["one", "two", "one", "two", "three"]);
+	assert_eq!(received[3].action, Action::Delete);
+	assert!(received.windows(2).all(|pair| pair[0].versionstamp < pair[1].versionstamp));
+
+	// Dropping the stream kills the live query
+	drop(changes);
+	wait_for_live_queries(&db, &table, 0).await;
+
+	drop(permit);
+}
+
+async fn wait_for_live_queries<C: surrealdb::Connection>(db: &Surreal<C>, table: &str, count: usize) {
+	let sql = format!("INFO FOR TABLE {table}");
+	tokio::time::timeout(LQ_TIMEOUT, async {
+		loop {
+			let live_queries: Option<std::collections::BTreeMap<String, String>> =
+				db.query(&sql).await.unwrap().take((0, "lives")).unwrap();
+			if live_queries.unwrap_or_default().len() == count {
+				break;
+			}
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.unwrap();
+}
+
 async fn receive_all_pending_notifications<
 	S: Stream<Item = Result<Notification<I>, Error>> + Unpin,
 	I,
@@ -0,0 +1,63 @@
+// Tests for connection metrics
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn metrics_count_queries() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let before = db.metrics().await.unwrap();
+	let n = 5;
+	for i in 0..n {
+		db.query(format!("CREATE foo:{i}")).await.unwrap().check().unwrap();
+	}
+	let after = db.metrics().await.unwrap();
+	assert_eq!(after.queries - before.queries, n);
+	assert_eq!(after.requests - before.requests, n);
+	assert_eq!(after.errors, before.errors);
+	// Failed statements are counted as both queries and errors
+	db.query("CREATE foo:0").await.unwrap();
+	let last = db.metrics().await.unwrap();
+	assert_eq!(last.queries - after.queries, 1);
+	assert_eq!(last.errors - after.errors, 1);
+}
+
+#[test_log::test(tokio::test)]
+async fn metrics_prometheus() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("CREATE foo:1").await.unwrap().check().unwrap();
+	db.query("THROW 'failed'").await.unwrap();
+	db.query("CREATE foo:1").await.unwrap();
+	let metrics = db.metrics().await.unwrap();
+	assert_eq!(metrics.error_kinds.thrown, 1);
+	assert_eq!(metrics.error_kinds.database, 1);
+	let text = metrics.to_prometheus();
+	// Every sample is a namespaced metric name, optional labels and a number
+	let mut samples = std::collections::HashMap::new();
+	for line in text.lines().filter(|line| !line.starts_with('#')) {
+		let (name, value) = line.rsplit_once(' ').unwrap();
+		assert!(name.starts_with("surrealdb_"), "{line}");
+		samples.insert(name.to_owned(), value.parse::<f64>().unwrap());
+	}
+	let requests = metrics.requests as f64;
+	assert_eq!(samples["surrealdb_requests_total"], requests);
+	assert_eq!(samples["surrealdb_queries_total"], metrics.queries as f64);
+	assert_eq!(samples["surrealdb_errors_total{kind=\"thrown\"}"], 1.0);
+	assert_eq!(samples["surrealdb_errors_total{kind=\"database\"}"], 1.0);
+	assert_eq!(samples["surrealdb_errors_total{kind=\"parse\"}"], 0.0);
+	assert_eq!(samples["surrealdb_request_duration_seconds_bucket{le=\"+Inf\"}"], requests);
+	assert_eq!(samples["surrealdb_request_duration_seconds_count"], requests);
+	assert!(samples["surrealdb_request_duration_seconds_sum"] > 0.0);
+	// Histogram buckets are cumulative
+	let buckets: Vec<_> = text
+		.lines()
+		.filter(|line| line.starts_with("surrealdb_request_duration_seconds_bucket"))
+		.map(|line| line.rsplit_once(' ').unwrap().1.parse::<u64>().unwrap())
+		.collect();
+	assert_eq!(buckets.len(), 9);
+	assert!(buckets.windows(2).all(|pair| pair[0] <= pair[1]));
+	assert!(text.contains("# TYPE surrealdb_request_duration_seconds histogram\n"));
+	assert!(text.contains("# TYPE surrealdb_errors_total counter\n"));
+}
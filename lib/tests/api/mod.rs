@@ -407,6 +407,23 @@ async fn query_with_stats() {
 	let _: Vec<RecordId> = result.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn query_raw() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let mut results = db.query_raw("RETURN 1h30m; RETURN person:tobie").await.unwrap();
+	assert_eq!(results.len(), 2);
+	match results.remove(0).unwrap() {
+		Value::Duration(duration) => assert_eq!(duration.to_string(), "1h30m"),
+		value => panic!("expected a duration, found {value:?}"),
+	}
+	match results.remove(0).unwrap() {
+		Value::Thing(thing) => assert_eq!(thing, surrealdb::sql::thing("person:tobie").unwrap()),
+		value => panic!("expected a record id, found {value:?}"),
+	}
+}
+
 #[test_log::test(tokio::test)]
 async fn query_chaining() {
 	let (permit, db) = new_db().await;
@@ -435,6 +452,28 @@ async fn mixed_results_query() {
 	let _: Option<RecordId> = response.take(1).unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn select_value() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("CREATE foo:1; CREATE foo:2").await.unwrap().check().unwrap();
+	let count: usize = db.select_value("RETURN count(SELECT * FROM foo)").await.unwrap();
+	assert_eq!(count, 2);
+	let id: Thing = db.select_value("SELECT VALUE id FROM foo:1").await.unwrap();
+	assert_eq!(id, thing("foo:1").unwrap());
+	let name: String = db.select_value("RETURN $name").bind(("name", "John")).await.unwrap();
+	assert_eq!(name, "John");
+	match db.select_value::<Thing>("SELECT VALUE id FROM foo").await.unwrap_err() {
+		Error::Api(ApiError::NotSingleValue(2)) => {}
+		error => panic!("{error:?}"),
+	}
+	match db.select_value::<Thing>("SELECT VALUE id FROM bar").await.unwrap_err() {
+		Error::Api(ApiError::NotSingleValue(0)) => {}
+		error => panic!("{error:?}"),
+	}
+}
+
 #[test_log::test(tokio::test)]
 async fn create_record_no_id() {
 	let (permit, db) = new_db().await;
@@ -536,6 +575,123 @@ async fn insert_thing() {
 	);
 }
 
+#[test_log::test(tokio::test)]
+async fn relate_many() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	#[derive(Debug, Deserialize)]
+	struct Follows {
+		#[serde(rename = "in")]
+		from: Thing,
+		#[serde(rename = "out")]
+		to: Thing,
+		since: Option<i64>,
+	}
+	db.query("CREATE |person:1..10| RETURN NONE").await.unwrap().check().unwrap();
+	let person = |i: i64| Thing::from(("person", surrealdb::sql::Id::from(i)));
+	let next = |i: i64| i % 10 + 1;
+	let edges = (1..=10)
+		.map(|i| {
+			let data = (i % 2 == 0).then(|| value(&format!("{{ since: {i} }}")).unwrap());
+			(person(i), "follows".to_owned(), person(next(i)), data)
+		})
+		.collect();
+	let created: Vec<Follows> = db.relate_many(edges).await.unwrap();
+	assert_eq!(created.len(), 10);
+	for (i, edge) in (1..).zip(&created) {
+		assert_eq!(edge.from, person(i));
+		assert_eq!(edge.to, person(next(i)));
+		assert_eq!(edge.since, (i % 2 == 0).then_some(i));
+	}
+	// Every edge can be traversed
+	let mut response =
+		db.query("SELECT VALUE ->follows->person FROM person").await.unwrap();
+	let followed: Vec<Vec<Thing>> = response.take(0).unwrap();
+	let expected: Vec<Vec<Thing>> = (1..=10).map(|i| vec![person(next(i))]).collect();
+	assert_eq!(followed, expected);
+}
+
+#[test_log::test(tokio::test)]
+async fn relate_many_rollback() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("DEFINE FIELD since ON follows TYPE int").await.unwrap().check().unwrap();
+	let person = |id: &str| thing(&format!("person:{id}")).unwrap();
+	let since = |since: &str| Some(value(&format!("{{ since: {since} }}")).unwrap());
+	let edges = vec![
+		(person("a"), "follows".to_owned(), person("b"), since("2020")),
+		(person("b"), "follows".to_owned(), person("c"), since("'yesterday'")),
+		(person("c"), "follows".to_owned(), person("a"), since("2022")),
+	];
+	let error = db.relate_many::<RecordId>(edges).await.unwrap_err();
+	assert!(error.to_string().contains("since"), "{error}");
+	// None of the edges were created
+	let mut response = db.query("SELECT * FROM follows").await.unwrap();
+	let edges: Vec<RecordId> = response.take(0).unwrap();
+	assert!(edges.is_empty());
+}
+
+#[test_log::test(tokio::test)]
+async fn upsert_many() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("CREATE person:a SET name = 'A'; CREATE person:c SET name = 'C'")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let person = |id: &str| thing(&format!("person:{id}")).unwrap();
+	let name = |name: &str| value(&format!("{{ name: '{name}' }}")).unwrap();
+	let records = vec![
+		(person("a"), name("Alice")),
+		(person("b"), name("Bob")),
+		(person("c"), name("Carol")),
+		(person("d"), name("Dave")),
+		(person("b"), name("Robert")),
+	];
+	let upserted: Vec<surrealdb::UpsertedRecord<RecordBuf>> =
+		db.upsert_many(records).await.unwrap();
+	let outcomes: Vec<_> = upserted
+		.into_iter()
+		.map(|upserted| (upserted.id, upserted.created, upserted.record.name))
+		.collect();
+	assert_eq!(
+		outcomes,
+		vec![
+			(person("a"), false, "Alice".to_owned()),
+			(person("b"), true, "Bob".to_owned()),
+			(person("c"), false, "Carol".to_owned()),
+			(person("d"), true, "Dave".to_owned()),
+			(person("b"), false, "Robert".to_owned()),
+		]
+	);
+}
+
+#[test_log::test(tokio::test)]
+async fn upsert_many_rollback() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.query("DEFINE FIELD age ON person TYPE int; CREATE person:a SET age = 1")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	let person = |id: &str| thing(&format!("person:{id}")).unwrap();
+	let age = |age: &str| value(&format!("{{ age: {age} }}")).unwrap();
+	let records =
+		vec![(person("a"), age("2")), (person("b"), age("'old'")), (person("c"), age("3"))];
+	let error = db.upsert_many::<RecordId>(records).await.unwrap_err();
+	assert!(error.to_string().contains("age"), "{error}");
+	// None of the records were written
+	let mut response = db.query("SELECT VALUE age FROM person").await.unwrap();
+	let ages: Vec<i64> = response.take(0).unwrap();
+	assert_eq!(ages, vec![1]);
+}
+
 #[test_log::test(tokio::test)]
 async fn select_table() {
 	let (permit, db) = new_db().await;
@@ -564,6 +720,144 @@ async fn select_record_id() {
 	assert_eq!(value.record(), thing("user:john").ok());
 }
 
+#[test_log::test(tokio::test)]
+async fn select_one() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let table = "user";
+	// No matching records
+	match db.select_one::<RecordId>((table, "john")).await.unwrap_err() {
+		Error::Api(ApiError::NotFound) => {}
+		error => panic!("{error:?}"),
+	}
+	match db.select_one::<RecordId>(table).await.unwrap_err() {
+		Error::Api(ApiError::NotFound) => {}
+		error => panic!("{error:?}"),
+	}
+	// Exactly one matching record
+	let _: Option<RecordId> = db.create((table, "john")).await.unwrap();
+	let record: RecordId = db.select_one((table, "john")).await.unwrap();
+	assert_eq!(record.id, thing("user:john").unwrap());
+	let record: RecordId = db.select_one(table).await.unwrap();
+	assert_eq!(record.id, thing("user:john").unwrap());
+	// More than one matching record
+	let _: Option<RecordId> = db.create((table, "jane")).await.unwrap();
+	match db.select_one::<RecordId>(table).await.unwrap_err() {
+		Error::Api(ApiError::MultipleResults(2)) => {}
+		error => panic!("{error:?}"),
+	}
+	let record: RecordId = db.select_one(table).range("jane".."john").await.unwrap();
+	assert_eq!(record.id, thing("user:jane").unwrap());
+}
+
+#[test_log::test(tokio::test)]
+async fn import_csv() {
+	use surrealdb::opt::CsvOptions;
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let csv = "id,Full Name,age,height,active,notes\n\
+		1,\"Doe, Jane\",32,1.68,true,\n\
+		2,John,not a number,1.8,false,\"said \"\"hi\"\"\"\n\
+		3,Missing,values\n";
+	let options = CsvOptions::new().column("Full Name", "name").batch_size(1);
+	let report = db.import_csv("person", csv.as_bytes(), options).await.unwrap();
+	assert_eq!(report.imported, 2);
+	assert_eq!(report.malformed.len(), 1);
+	assert_eq!(report.malformed[0].line, 4);
+	let mut response = db
+		.query("SELECT * FROM person ORDER BY id")
+		.query("SELECT VALUE [type::is::int(age), type::is::float(height), type::is::bool(active)] FROM person:1")
+		.await
+		.unwrap();
+	let people: Value = response.take(0).unwrap();
+	let expected = value(
+		"[
+			{ id: person:1, name: 'Doe, Jane', age: 32, height: 1.68f, active: true },
+			{ id: person:2, name: 'John', age: 'not a number', height: 1.8f, active: false, notes: 'said \"hi\"' },
+		]",
+	)
+	.unwrap();
+	assert_eq!(people.to_string(), expected.to_string());
+	let types: Vec<Vec<bool>> = response.take(1).unwrap();
+	assert_eq!(types, vec![vec![true, true, true]]);
+	// Malformed rows can fail the whole import instead
+	let options = CsvOptions::new().headers(false).skip_malformed(false);
+	match db.import_csv("other", "a,b\nc\n".as_bytes(), options).await.unwrap_err() {
+		Error::Api(ApiError::MalformedCsvRow {
+			line: 2,
+			..
+		}) => {}
+		error => panic!("{error:?}"),
+	}
+	let count: Option<usize> =
+		db.query("count(SELECT * FROM other)").await.unwrap().take(0).unwrap();
+	assert_eq!(count, Some(0));
+	// Rows are inserted as they are read, so batches read before an error are kept
+	struct Failing;
+	impl std::io::Read for Failing {
+		fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+			Err(std::io::Error::other("disconnected"))
+		}
+	}
+	let reader = std::io::Read::chain("a\n1\n2\n".as_bytes(), Failing);
+	let options = CsvOptions::new().batch_size(1);
+	match db.import_csv("partial", reader, options).await.unwrap_err() {
+		Error::Api(ApiError::CsvRead(_)) => {}
+		error => panic!("{error:?}"),
+	}
+	let count: Option<usize> =
+		db.query("count(SELECT * FROM partial)").await.unwrap().take(0).unwrap();
+	assert_eq!(count, Some(2));
+}
+
+#[test_log::test(tokio::test)]
+async fn select_paginated() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let table = "user";
+	// An empty table has a single empty page
+	let mut pager = db.select_paginated::<RecordId>(table, 10);
+	let page = pager.next_page().await.unwrap().unwrap();
+	assert!(page.items.is_empty());
+	assert_eq!(page.total, 0);
+	assert!(!page.has_next);
+	assert!(pager.next_page().await.unwrap().is_none());
+	for i in 1..=25 {
+		let _: Option<RecordId> = db.create((table, i)).await.unwrap();
+	}
+	let mut pager = db.select_paginated::<RecordId>(table, 10);
+	let mut pages = Vec::new();
+	while let Some(page) = pager.next_page().await.unwrap() {
+		assert_eq!(page.total, 25);
+		if pages.is_empty() {
+			// The total is counted on the first page only, so this record is not reflected in it
+			let _: Option<RecordId> = db.create((table, 0)).await.unwrap();
+		}
+		pages.push(page);
+	}
+	let _: Option<RecordId> = db.delete((table, 0)).await.unwrap();
+	let sizes: Vec<_> = pages.iter().map(|page| page.items.len()).collect();
+	assert_eq!(sizes, vec![10, 10, 5]);
+	let has_next: Vec<_> = pages.iter().map(|page| page.has_next).collect();
+	assert_eq!(has_next, vec![true, true, false]);
+	let ids: Vec<_> =
+		pages.into_iter().flat_map(|page| page.items).map(|user| user.id.id.to_string()).collect();
+	let expected: Vec<_> = (1..=25).map(|i: i64| i.to_string()).collect();
+	assert_eq!(ids, expected);
+	// A table that fills its last page exactly does not report a further page
+	let mut pager = db.select_paginated::<RecordId>(table, 5);
+	let mut last = None;
+	while let Some(page) = pager.next_page().await.unwrap() {
+		last = Some(page);
+	}
+	let last = last.unwrap();
+	assert_eq!(last.items.len(), 5);
+	assert!(!last.has_next);
+}
+
 #[test_log::test(tokio::test)]
 async fn select_record_ranges() {
 	let (permit, db) = new_db().await;
@@ -1079,6 +1373,65 @@ async fn delete_record_range() {
 	);
 }
 
+#[test_log::test(tokio::test)]
+async fn delete_where() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	for age in 10..30 {
+		let _: Option<RecordId> = db.create(("user", age)).content(json!({ "age": age })).await.unwrap();
+	}
+	let matching: Option<u64> = db
+		.query("count(SELECT * FROM user WHERE age < 18)")
+		.await
+		.unwrap()
+		.take(0)
+		.unwrap();
+	assert_eq!(matching, Some(8));
+	let deleted = db.delete_where("user", "age < 18").await.unwrap();
+	assert_eq!(deleted, 8);
+	let users: Vec<RecordId> = db.select("user").await.unwrap();
+	assert_eq!(users.len(), 12);
+	// Nothing matches any more
+	let deleted = db.delete_where("user", "age < 18").await.unwrap();
+	assert_eq!(deleted, 0);
+}
+
+#[test_log::test(tokio::test)]
+async fn define_analyzer_preset() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	db.define_analyzer_preset("person_search", "english").await.unwrap();
+	// Tokens are lowercased and stemmed
+	let mut response =
+		db.query("RETURN search::analyze('person_search', 'Running DOGS quickly')").await.unwrap();
+	let tokens: Vec<String> = response.take(0).unwrap();
+	assert_eq!(tokens, ["run", "dog", "quick"]);
+	// The analyzer can be used by full-text indexes
+	db.query(
+		"
+		DEFINE INDEX person_bio ON person FIELDS bio SEARCH ANALYZER person_search BM25;
+		CREATE person:tobie SET bio = 'Runs the database';
+		CREATE person:jaime SET bio = 'Walks the dogs';
+		",
+	)
+	.await
+	.unwrap()
+	.check()
+	.unwrap();
+	let mut response =
+		db.query("SELECT id FROM person WHERE bio @@ 'running'").await.unwrap();
+	let records: Vec<RecordId> = response.take(0).unwrap();
+	assert_eq!(records, [RecordId { id: thing("person:tobie").unwrap() }]);
+	// Languages can also be given by their code
+	db.define_analyzer_preset("article_search", "FR").await.unwrap();
+	match db.define_analyzer_preset("alien_search", "klingon").await.unwrap_err() {
+		Error::Api(ApiError::UnknownLanguage(language)) => assert_eq!(language, "klingon"),
+		error => panic!("{error:?}"),
+	}
+}
+
 #[test_log::test(tokio::test)]
 async fn changefeed() {
 	let (permit, db) = new_db().await;
@@ -1313,6 +1666,109 @@ async fn changefeed() {
 	);
 }
 
+#[test_log::test(tokio::test)]
+async fn changes() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	// Enable change feeds on one table only
+	let sql = "
+	DEFINE TABLE user CHANGEFEED 1h;
+	DEFINE TABLE post;
+	";
+	let response = db.query(sql).await.unwrap();
+	drop(permit);
+	response.check().unwrap();
+	// Create, update and delete users
+	let sql = "
+        CREATE user:amos SET name = 'Amos';
+        CREATE user:jane SET name = 'Jane';
+        UPDATE user:amos SET name = 'AMOS';
+        DELETE user:jane;
+    ";
+	let response = db.query(sql).await.unwrap();
+	response.check().unwrap();
+	let changes = db.changes("user", 0, 10).await.unwrap();
+	let set = match FFLAGS.change_feed_live_queries.enabled() {
+		true => surrealdb::Action::Create,
+		false => surrealdb::Action::Update,
+	};
+	let actions: Vec<_> =
+		changes.iter().map(|change| (change.action, change.record.to_string())).collect();
+	assert_eq!(
+		actions,
+		vec![
+			(set, "user:amos".to_owned()),
+			(set, "user:jane".to_owned()),
+			(set, "user:amos".to_owned()),
+			(surrealdb::Action::Delete, "user:jane".to_owned()),
+		]
+	);
+	assert!(changes.windows(2).all(|pair| pair[0].versionstamp < pair[1].versionstamp));
+	assert_eq!(changes[2].data, surrealdb::sql::value("{ id: user:amos, name: 'AMOS' }").unwrap());
+	assert_eq!(changes[3].data, Value::None);
+	// Tables without a change feed return an error
+	match db.changes("post", 0, 10).await.unwrap_err() {
+		// Local engines return this error
+		Error::Db(DbError::NoChangeFeed {
+			..
+		}) => {}
+		// Remote engines return this error
+		Error::Api(ApiError::Query(error)) if error.contains("does not have a change feed") => {}
+		error => panic!("{:?}", error),
+	}
+}
+
+#[test_log::test(tokio::test)]
+async fn ping() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let latency = db.ping().await.unwrap();
+	assert!(latency < Duration::from_secs(5), "{latency:?}");
+	// Keep the datastore busy while pinging
+	let load = tokio::spawn({
+		let db = db.clone();
+		async move {
+			for i in 0..100 {
+				db.query(format!("CREATE foo:{i} SET data = rand::string(1000)")).await.unwrap();
+			}
+		}
+	});
+	for _ in 0..10 {
+		let latency = db.ping().await.unwrap();
+		assert!(latency < Duration::from_secs(5), "{latency:?}");
+	}
+	load.await.unwrap();
+}
+
+#[test_log::test(tokio::test)]
+async fn batch() {
+	use futures::StreamExt;
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let n = 20;
+	let mut queries: Vec<_> = (0..n).map(|i| format!("CREATE foo:{i}; RETURN {i}")).collect();
+	queries.push("THROW 'failed'".to_owned());
+	let mut results = db.batch(queries).parallelism(4).await.unwrap();
+	let mut seen = vec![false; n + 1];
+	while let Some((index, result)) = results.next().await {
+		assert!(!seen[index], "index {index} was yielded twice");
+		seen[index] = true;
+		match index {
+			i if i == n => {
+				result.unwrap_err();
+			}
+			i => assert_eq!(result.unwrap(), Value::from(i)),
+		}
+	}
+	assert!(seen.into_iter().all(|seen| seen));
+	let mut response = db.query("SELECT * FROM foo").await.unwrap();
+	let records: Vec<RecordId> = response.take(0).unwrap();
+	assert_eq!(records.len(), n);
+}
+
 #[test_log::test(tokio::test)]
 async fn version() {
 	let (permit, db) = new_db().await;
@@ -1320,6 +1776,16 @@ async fn version() {
 	db.version().await.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn resize_channel() {
+	let (permit, db) = new_db().await;
+	drop(permit);
+	db.resize_channel(1).unwrap();
+	db.version().await.unwrap();
+	db.resize_channel(0).unwrap();
+	db.version().await.unwrap();
+}
+
 #[test_log::test(tokio::test)]
 async fn set_unset() {
 	let (permit, db) = new_db().await;
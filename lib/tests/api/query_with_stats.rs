@@ -0,0 +1,36 @@
+// Tests for reading the execution statistics of a query
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn query_with_stats_rows_examined() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let mut sql = String::from("DEFINE INDEX person_email ON person FIELDS email UNIQUE;");
+	for i in 0..100 {
+		sql.push_str(&format!("CREATE person SET email = 'person{i}', age = {i};"));
+	}
+	db.query(sql).await.unwrap().check().unwrap();
+	let (results, stats) = db
+		.query_with_stats(
+			"SELECT * FROM person WHERE email = 'person42';
+			SELECT * FROM person WHERE age = 42;
+			SELECT * FROM person WHERE age < 10;",
+		)
+		.await
+		.unwrap();
+	assert_eq!(results.len(), 3);
+	assert_eq!(stats.statements.len(), 3);
+	let (indexed, scan, range) = (stats.statements[0], stats.statements[1], stats.statements[2]);
+	assert_eq!(indexed.rows_returned, 1);
+	assert_eq!(indexed.rows_examined, 1);
+	assert_eq!(scan.rows_returned, 1);
+	assert_eq!(scan.rows_examined, 100);
+	assert!(indexed.rows_examined < scan.rows_examined);
+	assert_eq!(range.rows_returned, 10);
+	assert_eq!(range.rows_examined, 100);
+	assert!(stats.statements.iter().all(|statement| statement.duration > Duration::ZERO));
+	assert!(stats.duration() >= scan.duration);
+	// Failed statements are reported as errors
+	db.query_with_stats("SELECT * FROM person; THROW 'fail'").await.unwrap_err();
+}
@@ -0,0 +1,47 @@
+// Tests for advisory record locks
+
+#[test_log::test(tokio::test)]
+async fn lock_record() {
+	use std::time::Duration;
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let job = ("job", "one");
+	let token = db.lock_record(job, Duration::from_secs(60)).await.unwrap();
+	// Locks are not stored as records of the database
+	let mut response = db.query("INFO FOR DB").await.unwrap().check().unwrap();
+	let tables: Option<Value> = response.take("tables").unwrap();
+	assert_eq!(tables, Some(Value::Object(Default::default())));
+	// A second locker is blocked while the lock is held
+	match db.lock_record(job, Duration::from_secs(60)).await.unwrap_err() {
+		Error::Api(ApiError::Locked(record)) => assert_eq!(record.to_string(), "job:one"),
+		error => panic!("{error:?}"),
+	}
+	// Other records can still be locked
+	db.lock_record(("job", "two"), Duration::from_secs(60)).await.unwrap();
+	// Only the holder can release the lock
+	let other = db.lock_record(("job", "three"), Duration::from_secs(60)).await.unwrap();
+	match db.unlock_record(job, other).await.unwrap_err() {
+		Error::Api(ApiError::Locked(..)) => {}
+		error => panic!("{error:?}"),
+	}
+	db.unlock_record(job, token).await.unwrap();
+	// Once released, the record can be locked again
+	let token = db.lock_record(job, Duration::from_secs(60)).await.unwrap();
+	match db.lock_record(job, Duration::from_secs(60)).await.unwrap_err() {
+		Error::Api(ApiError::Locked(..)) => {}
+		error => panic!("{error:?}"),
+	}
+	db.unlock_record(job, token).await.unwrap();
+	// A lock without a time to live expires straight away, after which any token releases it
+	db.lock_record(job, Duration::ZERO).await.unwrap();
+	db.unlock_record(job, other).await.unwrap();
+	// Once expired, the record can be locked again without releasing it
+	let token = db.lock_record(job, Duration::ZERO).await.unwrap();
+	db.lock_record(job, Duration::from_secs(60)).await.unwrap();
+	// The expired token no longer releases the lock
+	match db.unlock_record(job, token).await.unwrap_err() {
+		Error::Api(ApiError::Locked(..)) => {}
+		error => panic!("{error:?}"),
+	}
+}
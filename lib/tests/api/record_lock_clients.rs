@@ -0,0 +1,29 @@
+// Tests for advisory record locks shared between clients
+// Run against the remote engines, where every client connects to the same server
+
+#[test_log::test(tokio::test)]
+async fn lock_record_across_clients() {
+	use std::time::Duration;
+	let database = Ulid::new().to_string();
+	let (permit, worker) = new_db().await;
+	worker.use_ns(NS).use_db(&database).await.unwrap();
+	drop(permit);
+	let (permit, other) = new_db().await;
+	other.use_ns(NS).use_db(&database).await.unwrap();
+	drop(permit);
+	let job = ("job", "one");
+	let token = worker.lock_record(job, Duration::from_secs(60)).await.unwrap();
+	// The other worker is blocked until the lock is released
+	match other.lock_record(job, Duration::from_secs(60)).await.unwrap_err() {
+		Error::Api(ApiError::Locked(record)) => assert_eq!(record.to_string(), "job:one"),
+		error => panic!("{error:?}"),
+	}
+	worker.unlock_record(job, token).await.unwrap();
+	// Or until the lock expires, which a lock without a time to live does straight away
+	let token = other.lock_record(job, Duration::ZERO).await.unwrap();
+	worker.lock_record(job, Duration::from_secs(60)).await.unwrap();
+	match other.unlock_record(job, token).await.unwrap_err() {
+		Error::Api(ApiError::Locked(..)) => {}
+		error => panic!("{error:?}"),
+	}
+}
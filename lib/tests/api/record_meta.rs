@@ -0,0 +1,42 @@
+// Tests for selecting records with their metadata
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn select_with_meta() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	#[derive(Debug, Deserialize)]
+	struct User {
+		age: i64,
+	}
+	db.query("DEFINE TABLE user CHANGEFEED 1h; DEFINE TABLE log")
+		.await
+		.unwrap()
+		.check()
+		.unwrap();
+	db.query("CREATE user:john SET age = 30; CREATE log:1").await.unwrap().check().unwrap();
+	let records = db.select_with_meta::<User>("user").await.unwrap();
+	let [created] = &records[..] else {
+		panic!("{records:?}");
+	};
+	assert_eq!(created.id, Thing::from(("user", "john")));
+	assert_eq!(created.version, Some(1));
+	let created_at = created.last_modified.clone().unwrap();
+	db.query("UPDATE user:john SET age = 31").await.unwrap().check().unwrap();
+	let records = db.select_with_meta::<User>(("user", "john")).await.unwrap();
+	let [updated] = &records[..] else {
+		panic!("{records:?}");
+	};
+	assert_eq!(updated.version, Some(2));
+	assert!(updated.last_modified.clone().unwrap() > created_at);
+	assert_eq!(updated.data.age, 31);
+	// Tables without a change feed have no metadata
+	let records = db.select_with_meta::<RecordId>("log").await.unwrap();
+	let [log] = &records[..] else {
+		panic!("{records:?}");
+	};
+	assert_eq!(log.id, thing("log:1").unwrap());
+	assert_eq!(log.version, None);
+	assert_eq!(log.last_modified, None);
+}
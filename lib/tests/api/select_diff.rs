@@ -0,0 +1,38 @@
+// Tests for diffing a record against an expected state
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn select_diff() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let table = "person";
+	let _: Option<RecordId> = db
+		.create((table, "tobie"))
+		.content(json!({ "name": "Tobie", "tags": ["rust", "go"] }))
+		.await
+		.unwrap();
+	let expected: Option<serde_json::Value> = db.select((table, "tobie")).await.unwrap();
+	// A record which is as expected has an empty patch
+	let patch = db.select_diff((table, "tobie"), &expected).await.unwrap();
+	assert!(patch.is_empty());
+	let _: Option<RecordId> = db
+		.update((table, "tobie"))
+		.merge(json!({ "name": "Tobie Morgan Hitchcock", "tags": ["rust"] }))
+		.await
+		.unwrap();
+	let patch = db.select_diff((table, "tobie"), &expected).await.unwrap();
+	assert_eq!(
+		Value::from(patch),
+		value(
+			"[
+				{ op: 'replace', path: '/name', value: 'Tobie Morgan Hitchcock' },
+				{ op: 'remove', path: '/tags/1' },
+			]"
+		)
+		.unwrap()
+	);
+	// A record which does not exist has every field removed
+	let patch = db.select_diff((table, "jaime"), json!({ "name": "Jaime" })).await.unwrap();
+	assert_eq!(Value::from(patch), value("[{ op: 'remove', path: '/name' }]").unwrap());
+}
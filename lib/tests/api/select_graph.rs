@@ -0,0 +1,53 @@
+// Tests for traversing graphs
+// Supported by the storage engines only
+
+#[test_log::test(tokio::test)]
+async fn select_graph() {
+	use surrealdb::opt::GraphDirection;
+	use surrealdb::opt::GraphSpec;
+
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	// Dave knows Alice back, which closes a cycle
+	let sql = "
+		RELATE person:alice->knows->person:bob;
+		RELATE person:alice->knows->person:carol;
+		RELATE person:bob->knows->person:dave;
+		RELATE person:carol->knows->person:dave;
+		RELATE person:dave->knows->person:alice;
+		RELATE person:dave->knows->person:erin;
+		RELATE person:frank->likes->person:alice;
+	";
+	db.query(sql).await.unwrap().check().unwrap();
+	let names = |graph: &surrealdb::method::Graph| {
+		let mut names: Vec<String> = graph.nodes.iter().map(|node| node.id.to_raw()).collect();
+		names.sort();
+		names
+	};
+	// Friends of friends
+	let spec = GraphSpec::new().edge("knows").depth(2);
+	let graph = db.select_graph(("person", "alice"), spec).await.unwrap();
+	assert_eq!(names(&graph), ["alice", "bob", "carol", "dave"]);
+	assert_eq!(graph.nodes[0], surrealdb::sql::Thing::from(("person", "alice")));
+	assert_eq!(graph.edges.len(), 4);
+	assert!(graph.edges.iter().all(|edge| edge.id.tb == "knows"));
+	// The cycle back to Alice ends the traversal
+	let spec = GraphSpec::new().edge("knows").depth(100);
+	let graph = db.select_graph(("person", "alice"), spec).await.unwrap();
+	assert_eq!(names(&graph), ["alice", "bob", "carol", "dave", "erin"]);
+	assert_eq!(graph.edges.len(), 6);
+	// Incoming edges of any table
+	let spec = GraphSpec::new().direction(GraphDirection::In);
+	let graph = db.select_graph(("person", "alice"), spec).await.unwrap();
+	assert_eq!(names(&graph), ["alice", "dave", "frank"]);
+	// Either way, over some tables
+	let spec = GraphSpec::new().direction(GraphDirection::Both).edge("likes").edge("knows");
+	let graph = db.select_graph(("person", "alice"), spec).await.unwrap();
+	assert_eq!(names(&graph), ["alice", "bob", "carol", "dave", "frank"]);
+	// Depth zero only returns the starting record
+	let spec = GraphSpec::new().depth(0);
+	let graph = db.select_graph(("person", "alice"), spec).await.unwrap();
+	assert_eq!(names(&graph), ["alice"]);
+	assert!(graph.edges.is_empty());
+}
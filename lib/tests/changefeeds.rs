@@ -781,3 +781,15 @@ async fn changefeed_with_original() -> Result<(), Error> {
 
 	Ok(())
 }
+
+#[tokio::test]
+async fn changefeed_without_definition() -> Result<(), Error> {
+	let db = new_ds().await?;
+	let ses = Session::owner().with_ns("test-cf-none").with_db("test-cf-none");
+	db.execute("CREATE user:one SET name = 'Tobie';", &ses, None).await?.remove(0).result?;
+	// Tables without a change feed have no changes to show
+	let array =
+		db.execute("SHOW CHANGES FOR TABLE user SINCE 0", &ses, None).await?.remove(0).result?;
+	assert_eq!(array, Value::Array(Default::default()));
+	Ok(())
+}
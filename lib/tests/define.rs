@@ -120,7 +120,7 @@ async fn define_statement_table_drop() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
-			tables: { test: 'DEFINE TABLE test TYPE ANY DROP SCHEMALESS PERMISSIONS NONE' },
+			tables: { test: 'DEFINE TABLE test TYPE ANY DROP SCHEMALESS' },
 			users: {},
 		}",
 	);
@@ -151,7 +151,7 @@ async fn define_statement_table_schemaless() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
-			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMALESS PERMISSIONS NONE' },
+			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMALESS' },
 			users: {},
 		}",
 	);
@@ -177,7 +177,7 @@ async fn define_statement_table_schemafull() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
-			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMAFULL PERMISSIONS NONE' },
+			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMAFULL' },
 			users: {},
 		}",
 	)?;
@@ -206,7 +206,7 @@ async fn define_statement_table_schemaful() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
-			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMAFULL PERMISSIONS NONE' },
+			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMAFULL' },
 			users: {},
 		}",
 	);
@@ -246,8 +246,8 @@ async fn define_statement_table_foreigntable() -> Result<(), Error> {
 			models: {},
 			params: {},
 			tables: {
-				test: 'DEFINE TABLE test TYPE ANY SCHEMAFULL PERMISSIONS NONE',
-				view: 'DEFINE TABLE view TYPE ANY SCHEMALESS AS SELECT count() FROM test GROUP ALL PERMISSIONS NONE',
+				test: 'DEFINE TABLE test TYPE ANY SCHEMAFULL',
+				view: 'DEFINE TABLE view TYPE ANY SCHEMALESS AS SELECT count() FROM test GROUP ALL',
 			},
 			users: {},
 		}",
@@ -259,7 +259,7 @@ async fn define_statement_table_foreigntable() -> Result<(), Error> {
 		"{
 			events: {},
 			fields: {},
-			tables: { view: 'DEFINE TABLE view TYPE ANY SCHEMALESS AS SELECT count() FROM test GROUP ALL PERMISSIONS NONE' },
+			tables: { view: 'DEFINE TABLE view TYPE ANY SCHEMALESS AS SELECT count() FROM test GROUP ALL' },
 			indexes: {},
 			lives: {},
 		}",
@@ -278,7 +278,7 @@ async fn define_statement_table_foreigntable() -> Result<(), Error> {
 			models: {},
 			params: {},
 			tables: {
-				test: 'DEFINE TABLE test TYPE ANY SCHEMAFULL PERMISSIONS NONE',
+				test: 'DEFINE TABLE test TYPE ANY SCHEMAFULL',
 			},
 			users: {},
 		}",
@@ -1729,7 +1729,7 @@ async fn permissions_checks_define_table() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: { TB: 'DEFINE TABLE TB TYPE ANY SCHEMALESS PERMISSIONS NONE' }, users: {  } }"],
+        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: { TB: 'DEFINE TABLE TB TYPE ANY SCHEMALESS' }, users: {  } }"],
 		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
     ];
 
@@ -1920,7 +1920,7 @@ async fn define_statement_table_permissions() -> Result<(), Error> {
 			models: {},
 			params: {},
 			tables: {
-					default: 'DEFINE TABLE default TYPE ANY SCHEMALESS PERMISSIONS NONE',
+					default: 'DEFINE TABLE default TYPE ANY SCHEMALESS',
 					full: 'DEFINE TABLE full TYPE ANY SCHEMALESS PERMISSIONS FULL',
 					select_full: 'DEFINE TABLE select_full TYPE ANY SCHEMALESS PERMISSIONS FOR select FULL, FOR create, update, delete NONE'
 			},
@@ -2301,7 +2301,7 @@ async fn define_table_relation_redefinition_info() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
-			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person SCHEMALESS PERMISSIONS NONE' },
+			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person SCHEMALESS' },
 			users: {},
 		}",
 	)?;
@@ -2322,7 +2322,7 @@ async fn define_table_relation_redefinition_info() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
-			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person | thing SCHEMALESS PERMISSIONS NONE' },
+			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person | thing SCHEMALESS' },
 			users: {},
 		}",
 	)?;
@@ -2343,7 +2343,7 @@ async fn define_table_relation_redefinition_info() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
-			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person | thing | other SCHEMALESS PERMISSIONS NONE' },
+			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person | thing | other SCHEMALESS' },
 			users: {},
 		}",
 	)?;
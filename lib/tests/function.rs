@@ -161,6 +161,30 @@ RETURN array::boolean_or([true, true], [false]);"#,
 	.await
 }
 
+#[tokio::test]
+async fn function_array_binary_search() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::binary_search([1, 3, 5, 7], 5);
+		RETURN array::binary_search([1, 3, 5, 7], 4);
+		RETURN array::binary_search([1, 3, 5, 7], 0);
+		RETURN array::binary_search([1, 3, 5, 7], 9);
+		RETURN array::binary_search([], 1);
+		RETURN array::binary_search(['a', 'c', 'e'], 'e');
+		RETURN array::binary_search(3, 1);
+	"#;
+	let error = "Incorrect arguments for function array::binary_search(). Argument 1 was the wrong type. Expected a array but found 3";
+	Test::new(sql)
+		.await?
+		.expect_val("2")?
+		.expect_val("-3")?
+		.expect_val("-1")?
+		.expect_val("-5")?
+		.expect_val("-1")?
+		.expect_val("2")?
+		.expect_error(error)?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_boolean_xor() -> Result<(), Error> {
 	test_queries(
@@ -243,9 +267,17 @@ async fn function_array_difference() -> Result<(), Error> {
 		RETURN array::difference([], []);
 		RETURN array::difference(3, true);
 		RETURN array::difference([1,2,3,4], [3,4,5,6]);
+		RETURN array::difference([1,2], [3,4]);
+		RETURN array::difference([1,1,2,3,3], [3,4,4]);
 	"#;
 	let error = "Incorrect arguments for function array::difference(). Argument 1 was the wrong type. Expected a array but found 3";
-	Test::new(sql).await?.expect_val("[]")?.expect_error(error)?.expect_val("[1,2,5,6]")?;
+	Test::new(sql)
+		.await?
+		.expect_val("[]")?
+		.expect_error(error)?
+		.expect_val("[1,2,5,6]")?
+		.expect_val("[1,2,3,4]")?
+		.expect_val("[1,2,4]")?;
 	Ok(())
 }
 
@@ -279,6 +311,25 @@ async fn function_array_distinct() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_fill() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::fill(0, 5);
+		RETURN array::fill({ taken: false }, 2);
+		RETURN array::fill("a", 0);
+		RETURN array::fill(0, -1);
+		RETURN array::fill(0, 2000000);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["[0, 0, 0, 0, 0]", "[{ taken: false }, { taken: false }]", "[]"])?
+		.expect_errors(&[
+			"Incorrect arguments for function array::fill(). The count must not be negative.",
+			"Incorrect arguments for function array::fill(). Output must not exceed 1048576 values.",
+		])?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_filter_index() -> Result<(), Error> {
 	let sql = r#"RETURN array::filter_index([0, 1, 2], 1);
@@ -294,8 +345,20 @@ RETURN array::filter_index(["nothing here"], 0);"#;
 async fn function_array_find_index() -> Result<(), Error> {
 	let sql = r#"RETURN array::find_index([5, 6, 7], 7);
 RETURN array::find_index(["hello world", null, true], null);
-RETURN array::find_index([0, 1, 2], 3);"#;
-	let desired_responses = ["2", "1", "null"];
+RETURN array::find_index([0, 1, 2], 3);
+RETURN array::find_index([1, 2, 1, 2], 2);"#;
+	let desired_responses = ["2", "1", "null", "1"];
+	test_queries(sql, &desired_responses).await?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_array_find_last_index() -> Result<(), Error> {
+	let sql = r#"RETURN array::find_last_index([5, 6, 7], 6);
+RETURN array::find_last_index([0, 1, 2], 3);
+RETURN array::find_last_index([1, 2, 1, 2], 2);
+RETURN array::find_last_index([], 1);"#;
+	let desired_responses = ["1", "null", "3", "null"];
 	test_queries(sql, &desired_responses).await?;
 	Ok(())
 }
@@ -359,6 +422,23 @@ async fn function_array_flatten() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_frequencies() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::frequencies(["a", "b", "a", "c", "a", "b"]);
+		RETURN array::frequencies([1, "1", 1.0, '1', true, NONE, [1, 2], [1, 2], { a: 1 }]);
+		RETURN array::frequencies([]);
+		RETURN array::frequencies("some text");
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val(r#"{ "'a'": 3, "'b'": 2, "'c'": 1 }"#)?
+		.expect_val(r#"{ "'1'": 2, '1': 2, '[1, 2]': 2, NONE: 1, '{ a: 1 }': 1, true: 1 }"#)?
+		.expect_val("{}")?
+		.expect_error("Incorrect arguments for function array::frequencies(). Argument 1 was the wrong type. Expected a array but found 'some text'")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_group() -> Result<(), Error> {
 	let sql = r#"
@@ -424,6 +504,8 @@ async fn function_array_intersect() -> Result<(), Error> {
 		RETURN array::intersect([], []);
 		RETURN array::intersect(3, true);
 		RETURN array::intersect([1,2,3,4], [3,4,5,6]);
+		RETURN array::intersect([1,2], [3,4]);
+		RETURN array::intersect([4,3,3,1,1], [1,1,3,3,5]);
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
@@ -445,6 +527,101 @@ async fn function_array_intersect() -> Result<(), Error> {
 	let val = Value::parse("[3,4]");
 	assert_eq!(tmp, val);
 	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[3,1]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_highlight() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::highlight("The cat sat on the Cat mat", "cat");
+		RETURN string::highlight("The cat sat on the Cat mat", "cAt", { case_insensitive: true });
+		RETURN string::highlight("aaaa", "aa");
+		RETURN string::highlight("héllo wörld", "ö");
+		RETURN string::highlight("anything", "");
+		RETURN string::highlight("text", "t", { case_insensitive: 1 });
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val("[{ start: 4, end: 7 }]")?
+		.expect_val("[{ start: 4, end: 7 }, { start: 19, end: 22 }]")?
+		.expect_val("[{ start: 0, end: 2 }, { start: 2, end: 4 }]")?
+		.expect_val("[{ start: 7, end: 8 }]")?
+		.expect_val("[]")?
+		.expect_error("Incorrect arguments for function string::highlight(). Expected the 'case_insensitive' option to be a bool, found int")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_mask() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::mask("4111 1111 1111 1234");
+		RETURN string::mask("4111111111111234", { keep_start: 4, keep_end: 4, mask_char: "X" });
+		RETURN string::mask("tobie@surrealdb.com", { keep_start: 1, keep_end: 14 });
+		RETURN string::mask("héllo wörld", { keep_start: 2, keep_end: 2, mask_char: "•" });
+		RETURN string::mask("1234");
+		RETURN string::mask("abc", { keep_start: 2, keep_end: 2 });
+		RETURN string::mask("secret", { keep_end: -1 });
+		RETURN string::mask("secret", { mask_char: "**" });
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&[
+		"'***************1234'",
+		"'4111XXXXXXXX1234'",
+		"'t****@surrealdb.com'",
+		"'hé•••••••ld'",
+		"'1234'",
+		"'abc'",
+	])?;
+	test.expect_errors(&[
+		"Incorrect arguments for function string::mask(). Expected the 'keep_end' option to be a non-negative integer, found int",
+		"Incorrect arguments for function string::mask(). Expected the 'mask_char' option to be a single character, found '**'",
+	])?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_ngrams() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::ngrams("search", 3);
+		RETURN string::ngrams("search", 3, { mode: "char" });
+		RETURN string::ngrams("héllo", 2);
+		RETURN string::ngrams("ab", 3);
+		RETURN string::ngrams("the quick  brown fox", 2, { mode: "word" });
+		RETURN string::ngrams("the quick brown fox", 4, { mode: "word" });
+		RETURN string::ngrams("the quick brown fox", 5, { mode: "word" });
+		RETURN string::ngrams("search", 0);
+		RETURN string::ngrams("search", 2, { mode: "line" });
+		RETURN string::ngrams(array::join(array::repeat("ab", 1000000), ""), 1);
+		RETURN string::ngrams(string::repeat("a", 4000), 2000);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&[
+		"['sea', 'ear', 'arc', 'rch']",
+		"['sea', 'ear', 'arc', 'rch']",
+		"['hé', 'él', 'll', 'lo']",
+		"[]",
+		"['the quick', 'quick brown', 'brown fox']",
+		"['the quick brown fox']",
+		"[]",
+	])?;
+	test.expect_errors(&[
+		"Incorrect arguments for function string::ngrams(). The second argument must be a positive number.",
+		"Incorrect arguments for function string::ngrams(). Expected the 'mode' option to be one of 'char' or 'word', found 'line'",
+		"Incorrect arguments for function string::ngrams(). Output must not exceed 1048576 values.",
+		"Incorrect arguments for function string::ngrams(). Output must not exceed 1048576 bytes.",
+	])?;
+	//
 	Ok(())
 }
 
@@ -758,6 +935,22 @@ async fn function_array_remove() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_repeat() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::repeat([1, 2], 3);
+		RETURN array::repeat([], 3);
+		RETURN array::repeat([1, 2], 0);
+		RETURN array::repeat([1, 2], -2);
+		RETURN array::repeat([1, 2], 1000000);
+	"#;
+	Test::new(sql).await?.expect_vals(&["[1, 2, 1, 2, 1, 2]", "[]", "[]"])?.expect_errors(&[
+		"Incorrect arguments for function array::repeat(). The count must not be negative.",
+		"Incorrect arguments for function array::repeat(). Output must not exceed 1048576 values.",
+	])?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_reverse() -> Result<(), Error> {
 	let sql = r#"
@@ -788,6 +981,89 @@ async fn function_array_reverse() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_rotate() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::rotate([], 2);
+		RETURN array::rotate([1,2,3,4,5], 2);
+		RETURN array::rotate([1,2,3,4,5], -1);
+		RETURN array::rotate([1,2,3,4,5], 12);
+		RETURN array::rotate([1,2,3,4,5], -7);
+		RETURN array::rotate([1,2,3], 0);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_val("[]")?;
+	test.expect_val("[3,4,5,1,2]")?;
+	test.expect_val("[5,1,2,3,4]")?;
+	test.expect_val("[3,4,5,1,2]")?;
+	test.expect_val("[4,5,1,2,3]")?;
+	test.expect_val("[1,2,3]")?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_array_sample() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::sample([], 2);
+		RETURN array::sample([1,2,3], 0);
+		RETURN array::sample([1,2,3], -1);
+		LET $sample = array::sample([1,2,3,4,5], 3);
+		RETURN array::len($sample);
+		RETURN array::len(array::distinct($sample));
+		RETURN array::complement($sample, [1,2,3,4,5]);
+		RETURN array::sort(array::sample([1,2,3], 10));
+		RETURN array::sample([0,1,2,3,4,5,6,7,8,9], 3, 42) == array::sample([0,1,2,3,4,5,6,7,8,9], 3, 42);
+		RETURN array::len(array::distinct(array::sample([0,1,2,3,4,5,6,7,8,9], 3, 42)));
+		LET $values = [0,1,2,3,4,5,6,7,8,9];
+		RETURN array::len(array::distinct([
+			array::sample($values, 3, 1),
+			array::sample($values, 3, 2),
+			array::sample($values, 3, 3),
+			array::sample($values, 3, 4),
+		]));
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&["[]", "[]"])?;
+	test.expect_error(
+		"Incorrect arguments for function array::sample(). The second argument must be a non-negative number.",
+	)?;
+	test.expect_vals(&["None", "3", "3", "[]", "[1,2,3]"])?;
+	// The same seed always picks the same elements
+	test.expect_vals(&["true", "3", "None"])?;
+	let distinct = test.next()?.result?;
+	assert!(distinct > Value::from(1), "{distinct}");
+	// Every element should be chosen about as often as any other
+	let runs = 1000;
+	let sql = "RETURN array::sample([0,1,2,3,4,5,6,7,8,9], 3);".repeat(runs);
+	let mut test = Test::new(&sql).await?;
+	let mut counts = [0; 10];
+	for _ in 0..runs {
+		let Value::Array(sample) = test.next()?.result? else {
+			panic!("expected an array");
+		};
+		assert_eq!(sample.len(), 3);
+		let mut seen = [false; 10];
+		for value in sample {
+			let Value::Number(index) = value else {
+				panic!("expected a number");
+			};
+			let index = index.as_usize();
+			assert!(!seen[index], "sampled the same element twice");
+			seen[index] = true;
+			counts[index] += 1;
+		}
+	}
+	// Each element is expected 300 times, with a standard deviation of about 14.5
+	for count in counts {
+		assert!((200..=400).contains(&count), "{counts:?}");
+	}
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_shuffle() -> Result<(), Error> {
 	let sql = r#"
@@ -876,6 +1152,34 @@ async fn function_array_slice() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_array_splice() -> Result<(), Error> {
+	let sql = r#"
+		RETURN array::splice([1, 2, 3], 1, 0, ['a', 'b']);
+		RETURN array::splice([1, 2, 3, 4, 5], 1, 2);
+		RETURN array::splice([1, 2, 3, 4, 5], -2, 1, ['x']);
+		RETURN array::splice([1, 2, 3], 10, 1, ['end']);
+		RETURN array::splice([1, 2, 3], -10, 1);
+		RETURN array::splice([1, 2, 3], 1, 10, []);
+		RETURN array::splice([1, 2, 3], 1, -1, ['y']);
+		RETURN array::splice([], 0, 0, [1]);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&[
+		"[1, 'a', 'b', 2, 3]",
+		"[1, 4, 5]",
+		"[1, 2, 3, 'x', 5]",
+		"[1, 2, 3, 'end']",
+		"[2, 3]",
+		"[1]",
+		"[1, 'y', 2, 3]",
+		"[1]",
+	])?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_array_sort() -> Result<(), Error> {
 	let sql = r#"
@@ -990,10 +1294,14 @@ async fn function_array_sort_desc() -> Result<(), Error> {
 async fn function_array_transpose() -> Result<(), Error> {
 	let sql = r#"
 		RETURN array::transpose([[0, 1], [2, 3]]);
-		RETURN array::transpose([[0, 1, 2], [3, 4]]);
-		RETURN array::transpose([[0, 1], [2, 3, 4]]);
+		RETURN array::transpose([[0, 1, 2], [3, 4]], { ragged: "compact" });
+		RETURN array::transpose([[0, 1], [2, 3, 4]], { ragged: "compact" });
 		RETURN array::transpose([[0, 1], [2, 3], [4, 5]]);
-		RETURN array::transpose([[0, 1, 2], "oops", [null, "sorry"]]);
+		RETURN array::transpose([[0, 1, 2], "oops", [null, "sorry"]], { ragged: "compact" });
+		RETURN array::transpose([]);
+		RETURN array::transpose([[1, 2], [3, 4], [5, 6]], { ragged: "error" });
+		RETURN array::transpose([[0, 1, 2], [3, 4]], { ragged: "pad" });
+		RETURN array::transpose([[0, 1, 2], "oops"], { ragged: "pad" });
 	"#;
 	let desired_responses = [
 		"[[0, 2], [1, 3]]",
@@ -1001,8 +1309,23 @@ async fn function_array_transpose() -> Result<(), Error> {
 		"[[0, 2], [1, 3], [4]]",
 		"[[0, 2, 4], [1, 3, 5]]",
 		"[[0, \"oops\", null], [1, \"sorry\"], [2]]",
+		"[]",
+		"[[1, 3, 5], [2, 4, 6]]",
+		"[[0, 3], [1, 4], [2, null]]",
+		"[[0, \"oops\"], [1, null], [2, null]]",
 	];
 	test_queries(sql, &desired_responses).await?;
+	//
+	let sql = r#"
+		RETURN array::transpose([[0, 1], [2, 3, 4], [5, 6]]);
+		RETURN array::transpose([[0, 1], [2, 3, 4], [5, 6]], { ragged: "error" });
+		RETURN array::transpose([[0, 1]], { ragged: "drop" });
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_error("Incorrect arguments for function array::transpose(). Every row must have the same length, but row 0 has 2 values and the longest row has 3.")?
+		.expect_error("Incorrect arguments for function array::transpose(). Every row must have the same length, but row 0 has 2 values and the longest row has 3.")?
+		.expect_error("Incorrect arguments for function array::transpose(). Expected the 'ragged' option to be one of 'compact', 'error' or 'pad', found 'drop'")?;
 	Ok(())
 }
 
@@ -1012,6 +1335,7 @@ async fn function_array_union() -> Result<(), Error> {
 		RETURN array::union([], []);
 		RETURN array::union(3, true);
 		RETURN array::union([1,2,1,6], [1,3,4,5,6]);
+		RETURN array::union([1,2], [3,4]);
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
@@ -1033,6 +1357,10 @@ async fn function_array_union() -> Result<(), Error> {
 	let val = Value::parse("[1,2,6,3,4,5]");
 	assert_eq!(tmp, val);
 	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("[1,2,3,4]");
+	assert_eq!(tmp, val);
+	//
 	Ok(())
 }
 
@@ -1117,6 +1445,40 @@ async fn function_count() -> Result<(), Error> {
 // crypto
 // --------------------------------------------------
 
+#[tokio::test]
+async fn function_crypto_adler32() -> Result<(), Error> {
+	let sql = r#"
+		RETURN crypto::adler32('');
+		RETURN crypto::adler32('Wikipedia');
+		RETURN crypto::adler32('123456789');
+		RETURN crypto::adler32(<bytes>'123456789');
+		RETURN crypto::adler32(encoding::base64::decode('AP8'));
+		RETURN crypto::adler32(123);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["1", "300286872", "152961502", "152961502", "16843008"])?
+		.expect_error("Incorrect arguments for function crypto::adler32(). The first argument must be a string or bytes.")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_crypto_crc32() -> Result<(), Error> {
+	let sql = r#"
+		RETURN crypto::crc32('');
+		RETURN crypto::crc32('hello world');
+		RETURN crypto::crc32('123456789');
+		RETURN crypto::crc32(<bytes>'123456789');
+		RETURN crypto::crc32(encoding::base64::decode('AP8'));
+		RETURN crypto::crc32(123);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["0", "222957957", "3421780262", "3421780262", "1826356594"])?
+		.expect_error("Incorrect arguments for function crypto::crc32(). The first argument must be a string or bytes.")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_crypto_md5() -> Result<(), Error> {
 	let sql = r#"
@@ -1175,10 +1537,126 @@ async fn function_crypto_sha512() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_crypto_jwt() -> Result<(), Error> {
+	let sql = r#"
+		LET $jwt = crypto::jwt::encode({ sub: 'tobie', roles: ['admin'] }, 'secret', { alg: 'HS384', exp: 1h });
+		LET $claims = crypto::jwt::decode($jwt, 'secret');
+		RETURN [$claims.sub, $claims.roles];
+		RETURN $claims.exp > time::unix() AND $claims.exp <= time::unix() + 3600;
+		RETURN crypto::jwt::decode(crypto::jwt::encode({ n: 1 }, 'secret'), 'secret');
+		RETURN crypto::jwt::decode(crypto::jwt::encode({ exp: time::unix() - 10 }, 'secret'), 'secret');
+		RETURN crypto::jwt::decode($jwt, 'wrong');
+		LET $parts = string::split($jwt, '.');
+		LET $forged = string::split(crypto::jwt::encode({ sub: 'admin' }, 'other'), '.');
+		RETURN crypto::jwt::decode(array::join([$parts[0], $forged[1], $parts[2]], '.'), 'secret');
+		RETURN crypto::jwt::decode('not a token', 'secret');
+		RETURN crypto::jwt::encode({}, 'secret', { alg: 'RS256' });
+	"#;
+	let mut test = Test::new(sql).await?;
+	test.skip_ok(2)?
+		.expect_vals(&["['tobie', ['admin']]", "true", "{ n: 1 }"])?
+		.expect_errors(&[
+		"Incorrect arguments for function crypto::jwt::decode(). The token has expired.",
+		"Incorrect arguments for function crypto::jwt::decode(). The token signature is invalid.",
+	])?;
+	test.skip_ok(2)?.expect_errors(&[
+		"Incorrect arguments for function crypto::jwt::decode(). The token signature is invalid.",
+		"Incorrect arguments for function crypto::jwt::decode(). The token is malformed.",
+		"Incorrect arguments for function crypto::jwt::encode(). The 'alg' option must be one of 'HS256', 'HS384' or 'HS512'.",
+	])?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_crypto_pbkdf2_compare() -> Result<(), Error> {
+	let sql = r#"
+		RETURN crypto::pbkdf2::compare('$pbkdf2-sha256$i=1,l=32$c2FsdA$Eg+2z/z4syxD5yJSVsT4N6hlSMkszDVICAWYfLcL4Xs', 'password');
+		RETURN crypto::pbkdf2::compare('$pbkdf2-sha256$i=4096,l=32$c2FsdA$xeR41ZKIyEGqUw22hFxMjZYok6ABzk4RpJY4c6qYE0o', 'password');
+		RETURN crypto::pbkdf2::compare('$pbkdf2-sha512$i=1,l=32$c2FsdA$hn9wzxreAs/zdSWZo6U9xK80x6ZpgVrl1RNVThyM8lI', 'password');
+		RETURN crypto::pbkdf2::compare('$pbkdf2-sha256$i=1,l=32$c2FsdA$Eg+2z/z4syxD5yJSVsT4N6hlSMkszDVICAWYfLcL4Xs', 'passw0rd');
+		RETURN crypto::pbkdf2::compare('$pbkdf2-sha512$i=1,l=32$c2FsdA$Eg+2z/z4syxD5yJSVsT4N6hlSMkszDVICAWYfLcL4Xs', 'password');
+		RETURN crypto::pbkdf2::compare('not a hash', 'password');
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&["true", "true", "true", "false", "false", "false"])?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_crypto_pbkdf2_generate() -> Result<(), Error> {
+	let sql = r#"
+		RETURN crypto::pbkdf2::generate('password', { iterations: 1, salt: 'salt' });
+		RETURN crypto::pbkdf2::generate('password', { iterations: 4096, salt: 'salt', algo: 'sha256' });
+		RETURN crypto::pbkdf2::generate('password', { iterations: 1, salt: 'salt', algo: 'sha512' });
+		LET $hash = crypto::pbkdf2::generate('password', { iterations: 1000, algo: 'sha512' });
+		RETURN string::startsWith($hash, '$pbkdf2-sha512$i=1000,l=32$');
+		RETURN crypto::pbkdf2::compare($hash, 'password');
+		RETURN crypto::pbkdf2::compare($hash, 'passw0rd');
+		RETURN crypto::pbkdf2::generate('password', { iterations: 0 });
+		RETURN crypto::pbkdf2::generate('password', { salt: 'ab' });
+		RETURN crypto::pbkdf2::generate('password', { algo: 'md5' });
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&[
+		"'$pbkdf2-sha256$i=1,l=32$c2FsdA$Eg+2z/z4syxD5yJSVsT4N6hlSMkszDVICAWYfLcL4Xs'",
+		"'$pbkdf2-sha256$i=4096,l=32$c2FsdA$xeR41ZKIyEGqUw22hFxMjZYok6ABzk4RpJY4c6qYE0o'",
+		"'$pbkdf2-sha512$i=1,l=32$c2FsdA$hn9wzxreAs/zdSWZo6U9xK80x6ZpgVrl1RNVThyM8lI'",
+		"None",
+		"true",
+		"true",
+		"false",
+	])?;
+	test.expect_errors(&[
+		"Incorrect arguments for function crypto::pbkdf2::generate(). Expected the 'iterations' option to be an integer between 1 and 2400000, found 0",
+		"Incorrect arguments for function crypto::pbkdf2::generate(). Expected the 'salt' option to be a string between 3 and 48 bytes long",
+		"Incorrect arguments for function crypto::pbkdf2::generate(). Expected the 'algo' option to be one of 'sha256' or 'sha512', found 'md5'",
+	])?;
+	//
+	Ok(())
+}
+
 // --------------------------------------------------
 // duration
 // --------------------------------------------------
 
+#[tokio::test]
+async fn function_duration_components() -> Result<(), Error> {
+	let sql = r#"
+		RETURN duration::components(90m);
+		RETURN duration::components(2w3d25h61m61s1ms5ns);
+		RETURN duration::components(0ns);
+		RETURN duration::from_components(duration::components(2w3d25h61m61s1ms5ns));
+		RETURN duration::from_components({ minutes: 90, seconds: 3661, nanos: 1500000000 });
+		RETURN duration::from_components({ hours: 1 });
+		RETURN duration::from_components({});
+		RETURN duration::from_components({ hours: -1 });
+		RETURN duration::from_components({ weeks: 1 });
+		RETURN duration::from_components({ days: 9223372036854775807 });
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&[
+		"{ days: 0, hours: 1, minutes: 30, seconds: 0, nanos: 0 }",
+		"{ days: 18, hours: 2, minutes: 2, seconds: 1, nanos: 1000005 }",
+		"{ days: 0, hours: 0, minutes: 0, seconds: 0, nanos: 0 }",
+		"2w3d25h61m61s1ms5ns",
+		"2h31m2s500ms",
+		"1h",
+		"0ns",
+	])?;
+	test.expect_errors(&[
+		"Incorrect arguments for function duration::from_components(). Expected the 'hours' field to be a non-negative integer, found int",
+		"Incorrect arguments for function duration::from_components(). Unexpected field 'weeks', expected one of 'days', 'hours', 'minutes', 'seconds' or 'nanos'",
+		"Incorrect arguments for function duration::from_components(). The resulting duration is too large.",
+	])?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_duration_days() -> Result<(), Error> {
 	let sql = r#"
@@ -1589,6 +2067,57 @@ async fn function_encoding_base64_encode() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_encoding_json_parse() -> Result<(), Error> {
+	let sql = r#"
+		RETURN encoding::json::parse('{"a":{"b":[1,2.5,"c",null,true]},"d":"e"}');
+		RETURN encoding::json::parse('"text"');
+		RETURN encoding::json::parse('{"a": [1, 2}');
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("{ a: { b: [1, 2.5f, 'c', null, true] }, d: 'e' }");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("'text'");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result.unwrap_err().to_string();
+	assert_eq!(tmp, "Incorrect arguments for function encoding::json::parse(). Invalid JSON at line 1, column 12: Expected closing delimiter ']'");
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_encoding_json_stringify() -> Result<(), Error> {
+	let sql = r#"
+		RETURN encoding::json::stringify({ a: { b: [1, 2.5, 'c', NULL, true] }, d: 'e' });
+		RETURN encoding::json::stringify({ a: { b: [1] } }, { pretty: true });
+		RETURN encoding::json::parse(encoding::json::stringify({ a: { b: [1, 'c'] } }, { pretty: true }));
+		RETURN encoding::json::stringify(1, { pretty: 'yes' });
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from(r#"{"a":{"b":[1,2.5,"c",null,true]},"d":"e"}"#);
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::from("{\n  \"a\": {\n    \"b\": [\n      1\n    ]\n  }\n}");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result?;
+	let val = Value::parse("{ a: { b: [1, 'c'] } }");
+	assert_eq!(tmp, val);
+	//
+	let tmp = test.next()?.result.unwrap_err().to_string();
+	assert_eq!(tmp, "Incorrect arguments for function encoding::json::stringify(). Expected the 'pretty' option to be a bool, found string");
+	//
+	Ok(())
+}
+
 // --------------------------------------------------
 // geo
 // --------------------------------------------------
@@ -1608,12 +2137,44 @@ async fn function_parse_geo_area() -> Result<(), Error> {
 	let mut test = Test::new(sql).await?;
 	//
 	let tmp = test.next()?.result?;
-	let val = Value::from(1029944667.4192368);
+	let val = Value::from(1031483074.1162109);
 	assert_eq!(tmp, val);
 	//
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_geo_area_length() -> Result<(), Error> {
+	let sql = r#"
+		LET $box = {
+			type: 'Polygon',
+			coordinates: [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0]]]
+		};
+		LET $equator = { type: 'LineString', coordinates: [[0.0, 0.0], [1.0, 0.0]] };
+		RETURN geo::area($box);
+		RETURN geo::length($box);
+		RETURN geo::length($equator);
+		RETURN geo::length({ type: 'MultiLineString', coordinates: [[[0.0, 0.0], [1.0, 0.0]], [[0.0, 0.0], [0.0, 1.0]]] });
+		RETURN geo::area($equator);
+		RETURN geo::area({ type: 'Polygon', coordinates: [[[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [0.0, 0.0]]] });
+		RETURN geo::length({ type: 'Point', coordinates: [0.0, 0.0] });
+		RETURN geo::length('not a geometry');
+	"#;
+	let mut test = Test::new(sql).await?;
+	test.skip_ok(2)?;
+	// Known geodesic measurements on the WGS84 ellipsoid, in metres
+	for expected in [12_308_778_361.469, 443_770.9, 111_319.491, 221_893.88] {
+		let Value::Number(tmp) = test.next()?.result? else {
+			panic!("expected a number");
+		};
+		let tmp = tmp.as_float();
+		assert!((tmp - expected).abs() < expected * 1e-6, "{tmp} is not {expected}");
+	}
+	// Degenerate geometries have no area or length
+	test.expect_vals(&["0f", "0f", "0f", "NONE"])?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_parse_geo_bearing() -> Result<(), Error> {
 	let sql = r#"
@@ -1728,6 +2289,43 @@ async fn function_parse_geo_hash_decode() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_parse_geo_simplify() -> Result<(), Error> {
+	let sql = r#"
+		LET $line = { type: 'LineString', coordinates: [[0, 0], [1, 0.01], [2, -0.01], [3, 0.02], [4, 0]] };
+		LET $polygon = {
+			type: 'Polygon',
+			coordinates: [[[0, 0], [1, 0.01], [2, 0], [2, 1], [2.01, 2], [0, 2], [0, 0]]]
+		};
+		RETURN geo::simplify($line, 0.1);
+		RETURN geo::simplify($line, 0.015);
+		RETURN geo::simplify($line, 0) == $line;
+		RETURN geo::simplify($polygon, 0.1);
+		RETURN geo::simplify($polygon, 10) == $polygon;
+		RETURN geo::simplify({ type: 'GeometryCollection', geometries: [{ type: 'Point', coordinates: [1, 2] }, { type: 'LineString', coordinates: [[0, 0], [1, 0.01], [4, 0]] }] }, 0.1);
+		RETURN geo::simplify($line, -1);
+		RETURN geo::simplify('not a geometry', 1);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&[
+		"None",
+		"None",
+		"{ type: 'LineString', coordinates: [[0, 0], [4, 0]] }",
+		"{ type: 'LineString', coordinates: [[0, 0], [2, -0.01], [3, 0.02], [4, 0]] }",
+		"true",
+		"{ type: 'Polygon', coordinates: [[[0, 0], [2, 0], [2.01, 2], [0, 2], [0, 0]]] }",
+		"true",
+		"{ type: 'GeometryCollection', geometries: [(1, 2), { type: 'LineString', coordinates: [[0, 0], [4, 0]] }] }",
+	])?;
+	test.expect_error(
+		"Incorrect arguments for function geo::simplify(). The second argument must be a non-negative number.",
+	)?;
+	test.expect_val("NONE")?;
+	//
+	Ok(())
+}
+
 // --------------------------------------------------
 // math
 // --------------------------------------------------
@@ -1846,6 +2444,26 @@ async fn function_math_clamp() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_combinations() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::combinations(5, 0);
+		RETURN math::combinations(5, 2);
+		RETURN math::combinations(52, 5);
+		RETURN math::combinations(66, 33);
+		RETURN math::combinations(68, 34);
+		RETURN math::combinations(5, 6);
+		RETURN math::combinations(-5, 2);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["1", "10", "2598960", "7219428434016265740"])?
+		.expect_error("Incorrect arguments for function math::combinations(). The result is too large to be represented as an integer.")?
+		.expect_error("Incorrect arguments for function math::combinations(). The second argument must be a non-negative integer no greater than the first.")?
+		.expect_error("Incorrect arguments for function math::combinations(). The first argument must be a non-negative integer.")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_cos() -> Result<(), Error> {
 	let sql = r#"
@@ -1872,6 +2490,22 @@ async fn function_math_cot() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_cumsum() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::cumsum([1, 2, 3]);
+		RETURN math::cumsum([1.5dec, 2.25dec, -0.75dec]);
+		RETURN math::cumsum([1, 2.5]);
+		RETURN math::cumsum([7]);
+		RETURN math::cumsum([]);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&["[1, 3, 6]", "[1.5dec, 3.75dec, 3dec]", "[1, 3.5]", "[7]", "[]"])?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_deg2rad() -> Result<(), Error> {
 	let sql = r#"
@@ -1887,6 +2521,22 @@ async fn function_math_deg2rad() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_diff() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::diff([1, 3, 6]);
+		RETURN math::diff([1.5dec, 3.75dec, 3dec]);
+		RETURN math::diff([10, 7.5]);
+		RETURN math::diff([7]);
+		RETURN math::diff([]);
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&["[2, 3]", "[2.25dec, -0.75dec]", "[-2.5]", "[]", "[]"])?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_fixed() -> Result<(), Error> {
 	let sql = r#"
@@ -1936,6 +2586,89 @@ async fn function_math_floor() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_factorial() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::factorial(0);
+		RETURN math::factorial(1);
+		RETURN math::factorial(5);
+		RETURN math::factorial(20);
+		RETURN math::factorial(21);
+		RETURN math::factorial(-1);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["1", "1", "120", "2432902008176640000"])?
+		.expect_error("Incorrect arguments for function math::factorial(). The result is too large to be represented as an integer.")?
+		.expect_error("Incorrect arguments for function math::factorial(). The argument must be a non-negative integer.")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_math_gcd() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::gcd(0, 0);
+		RETURN math::gcd(12, 18);
+		RETURN math::gcd(-12, 18);
+		RETURN math::gcd(-12, -18);
+		RETURN math::gcd(7, 0);
+		RETURN math::gcd(17, 5);
+		RETURN math::gcd(1.5, 3);
+	"#;
+	let error = "Incorrect arguments for function math::gcd(). Argument 1 was the wrong type. Expected a i64 but found 1.5f";
+	Test::new(sql).await?.expect_vals(&["0", "6", "6", "6", "7", "1"])?.expect_error(error)?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_math_histogram() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::histogram([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 2);
+		RETURN math::histogram([0, 1, 2, 3, 4, 5, 6, 7, 8], 4);
+		RETURN math::histogram([5, 5, 5], 2);
+		RETURN math::histogram([-5, 0, 9, 10, 50, 100, 150], [0, 10, 100]);
+		RETURN math::histogram([], 3);
+		RETURN math::histogram([1, 2], 0);
+		RETURN math::histogram([1, 2], [10, 0]);
+		RETURN math::histogram([1], 1000000000000);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val(
+			"[
+				{ min: 1f, max: 5.5f, count: 5 },
+				{ min: 5.5f, max: 10f, count: 5 }
+			]",
+		)?
+		.expect_val(
+			"[
+				{ min: 0f, max: 2f, count: 2 },
+				{ min: 2f, max: 4f, count: 2 },
+				{ min: 4f, max: 6f, count: 2 },
+				{ min: 6f, max: 8f, count: 3 }
+			]",
+		)?
+		.expect_val(
+			"[
+				{ min: 5f, max: 5f, count: 3 },
+				{ min: 5f, max: 5f, count: 0 }
+			]",
+		)?
+		.expect_val(
+			"[
+				{ max: 0f, count: 1 },
+				{ min: 0f, max: 10f, count: 2 },
+				{ min: 10f, max: 100f, count: 3 },
+				{ min: 100f, count: 1 }
+			]",
+		)?
+		.expect_error("Incorrect arguments for function math::histogram(). The first argument must be a non-empty array of numbers.")?
+		.expect_error("Incorrect arguments for function math::histogram(). The second argument must be an integer greater than 0 or an array of boundaries.")?
+		.expect_error("Incorrect arguments for function math::histogram(). The boundaries must be a non-empty array of numbers in ascending order.")?
+		.expect_error("Incorrect arguments for function math::histogram(). Output must not exceed 1048576 values.")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_interquartile() -> Result<(), Error> {
 	let sql = r#"
@@ -1959,6 +2692,22 @@ async fn function_math_interquartile() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_lcm() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::lcm(0, 0);
+		RETURN math::lcm(4, 6);
+		RETURN math::lcm(-4, 6);
+		RETURN math::lcm(-4, -6);
+		RETURN math::lcm(7, 0);
+		RETURN math::lcm(21, 6);
+		RETURN math::lcm(4, 6.5);
+	"#;
+	let error = "Incorrect arguments for function math::lcm(). Argument 2 was the wrong type. Expected a i64 but found 6.5f";
+	Test::new(sql).await?.expect_vals(&["0", "12", "12", "12", "0", "42"])?.expect_error(error)?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_lerp() -> Result<(), Error> {
 	let sql = r#"
@@ -2170,21 +2919,24 @@ async fn function_math_min() -> Result<(), Error> {
 async fn function_math_mode() -> Result<(), Error> {
 	let sql = r#"
 		RETURN math::mode([]);
-		RETURN math::mode([101, 213, 202]);
+		RETURN math::mode([101, 213, 202, 213]);
 		RETURN math::mode([101.5, 213.5, 202.5]);
+		RETURN math::mode([1, 2, 2, 3, 3, 1, 4]);
+		RETURN math::mode(['red', 'blue', 'red', true, true, false]);
+		RETURN math::mode(['red', 'blue', 'blue']);
+		RETURN math::mode([2, 1, 1.0, 2f, 1dec]);
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
-	let tmp = test.next()?.result?;
-	assert!(tmp.is_nan());
-	//
-	let tmp = test.next()?.result?;
-	let val = Value::from(213);
-	assert_eq!(tmp, val);
-	//
-	let tmp = test.next()?.result?;
-	let val = Value::from(213.5);
-	assert_eq!(tmp, val);
+	test.expect_error(
+		"Incorrect arguments for function math::mode(). The array must not be empty.",
+	)?;
+	test.expect_val("213")?;
+	test.expect_val("[101.5, 213.5, 202.5]")?;
+	test.expect_val("[1, 2, 3]")?;
+	test.expect_val("['red', true]")?;
+	test.expect_val("'blue'")?;
+	test.expect_val("1")?;
 	//
 	Ok(())
 }
@@ -2212,6 +2964,32 @@ async fn function_math_nearestrank() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_normalize() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::normalize([2, 4, 6, 10]);
+		RETURN math::normalize([-5, 0, 5], { min: -1, max: 1 });
+		RETURN math::normalize([10, 20], { min: 100, max: 0 });
+		RETURN math::normalize([3, 3, 3]);
+		RETURN math::normalize([3, 3], { min: 0, max: 10 });
+		RETURN math::normalize([]);
+		RETURN math::normalize([1, 2], { min: 0 });
+	"#;
+	let error = "Incorrect arguments for function math::normalize(). The second argument must be an object with numeric `min` and `max` fields.";
+	Test::new(sql)
+		.await?
+		.expect_vals(&[
+			"[0f, 0.25f, 0.5f, 1f]",
+			"[-1f, 0f, 1f]",
+			"[100f, 0f]",
+			"[0.5f, 0.5f, 0.5f]",
+			"[5f, 5f]",
+			"[]",
+		])?
+		.expect_error(error)?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_percentile() -> Result<(), Error> {
 	let sql = r#"
@@ -2235,6 +3013,24 @@ async fn function_math_percentile() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_permutations() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::permutations(5, 0);
+		RETURN math::permutations(5, 2);
+		RETURN math::permutations(10, 10);
+		RETURN math::permutations(20, 20);
+		RETURN math::permutations(21, 21);
+		RETURN math::permutations(3, 4);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["1", "20", "3628800", "2432902008176640000"])?
+		.expect_error("Incorrect arguments for function math::permutations(). The result is too large to be represented as an integer.")?
+		.expect_error("Incorrect arguments for function math::permutations(). The second argument must be a non-negative integer no greater than the first.")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_pow() -> Result<(), Error> {
 	let sql = r#"
@@ -2304,6 +3100,27 @@ async fn function_math_round() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_math_scale() -> Result<(), Error> {
+	let sql = r#"
+		RETURN math::scale(512, 0, 1024, 0, 100);
+		RETURN math::scale(0.25, 0, 1, 10, 20);
+		RETURN math::scale(25, 0, 100, 100, 0);
+		RETURN math::scale(2048, 0, 1024, 0, 100);
+		RETURN math::scale(-5, 0, 10, 0, 1);
+		RETURN math::scale(2048, 0, 1024, 0, 100, true);
+		RETURN math::scale(-5, 0, 10, 0, 1, true);
+		RETURN math::scale(5, 10, 10, 0, 100);
+	"#;
+	let error =
+		"Incorrect arguments for function math::scale(). The input range must not be empty.";
+	Test::new(sql)
+		.await?
+		.expect_vals(&["50f", "12.5f", "75f", "100f", "0f", "200f", "-0.5f"])?
+		.expect_error(error)?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_math_sign() -> Result<(), Error> {
 	let sql = r#"
@@ -2551,6 +3368,46 @@ async fn function_parse_meta_table() -> Result<(), Error> {
 // object
 // --------------------------------------------------
 
+#[tokio::test]
+async fn function_object_deep_get() -> Result<(), Error> {
+	let sql = r#"
+		RETURN object::deep_get({ a: { b: [{ c: 1 }, { c: 2 }] } }, 'a.b.1.c');
+		RETURN object::deep_get({ a: { b: [{ c: 1 }] } }, 'a.b');
+		RETURN object::deep_get({ a: { b: [{ c: 1 }] } }, 'a.b.5.c');
+		RETURN object::deep_get({ a: { b: 1 } }, 'a.b.c');
+		RETURN object::deep_get({ a: [1, 2] }, 'a.first');
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val("2")?
+		.expect_val("[{ c: 1 }]")?
+		.expect_val("NONE")?
+		.expect_val("NONE")?
+		.expect_val("NONE")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_object_deep_set() -> Result<(), Error> {
+	let sql = r#"
+		RETURN object::deep_set({ a: 1 }, 'b.c.d', true);
+		RETURN object::deep_set({ a: { b: [{ c: 1 }, { c: 2 }] } }, 'a.b.1.c', 3);
+		RETURN object::deep_set({ a: [1] }, 'a.2', 3);
+		RETURN object::deep_set({ a: { b: 1 } }, 'a.b', { c: 2 });
+		RETURN object::deep_set({ a: { b: 1 } }, 'a.b.c', 2);
+		RETURN object::deep_set({ a: [1] }, 'a.b', 2);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val("{ a: 1, b: { c: { d: true } } }")?
+		.expect_val("{ a: { b: [{ c: 1 }, { c: 3 }] } }")?
+		.expect_val("{ a: [1, NONE, 3] }")?
+		.expect_val("{ a: { b: { c: 2 } } }")?
+		.expect_error("Incorrect arguments for function object::deep_set(). Cannot set the field 'c' of the path 'a.b.c' on a value of type int")?
+		.expect_error("Incorrect arguments for function object::deep_set(). Expected an array index in the path 'a.b', found 'b'")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_object_entries() -> Result<(), Error> {
 	let sql = r#"
@@ -2565,6 +3422,23 @@ async fn function_object_entries() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_object_flatten() -> Result<(), Error> {
+	let sql = r#"
+		RETURN object::flatten({ a: { b: { c: 1 } }, d: [1, { e: 2 }], f: {}, g: [] });
+		RETURN object::flatten({});
+		LET $nested = { a: { b: [{ c: 1, d: [true, false] }, 'x'], e: { f: NULL } }, g: 'h' };
+		RETURN object::unflatten(object::flatten($nested)) == $nested;
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val("{ 'a.b.c': 1, 'd.0': 1, 'd.1.e': 2, f: {}, g: [] }")?
+		.expect_val("{}")?
+		.expect_val("None")?
+		.expect_val("true")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_object_from_entries() -> Result<(), Error> {
 	let sql = r#"
@@ -2607,6 +3481,59 @@ async fn function_object_len() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_object_omit() -> Result<(), Error> {
+	let sql = r#"
+		RETURN object::omit({ a: 1, b: 2, secret: 3 }, ["secret"]);
+		RETURN object::omit({ a: { b: 1, c: 2 }, d: 3 }, ["a.b", "d"]);
+		RETURN object::omit({ a: 1 }, ["missing", "a.b"]);
+		RETURN object::omit({ a: 1 }, [1]);
+	"#;
+	let error = "Incorrect arguments for function object::omit(). Expected an array of field paths, found int";
+	Test::new(sql)
+		.await?
+		.expect_val("{ a: 1, b: 2 }")?
+		.expect_val("{ a: { c: 2 } }")?
+		.expect_val("{ a: 1 }")?
+		.expect_error(error)?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_object_pick() -> Result<(), Error> {
+	let sql = r#"
+		RETURN object::pick({ a: 1, b: 2, c: 3 }, ["a", "b"]);
+		RETURN object::pick({ a: { b: 1, c: 2 }, d: 3 }, ["a.b", "d"]);
+		RETURN object::pick({ a: 1 }, ["missing", "a.b"]);
+		RETURN object::pick({ a: 1 }, [1]);
+	"#;
+	let error = "Incorrect arguments for function object::pick(). Expected an array of field paths, found int";
+	Test::new(sql)
+		.await?
+		.expect_val("{ a: 1, b: 2 }")?
+		.expect_val("{ a: { b: 1 }, d: 3 }")?
+		.expect_val("{}")?
+		.expect_error(error)?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_object_unflatten() -> Result<(), Error> {
+	let sql = r#"
+		RETURN object::unflatten({ 'a.b.c': 1, 'a.d': 2, e: 3 });
+		RETURN object::unflatten({ 'a.0': 1, 'a.2.b': 2, 'a.10': 3 });
+		RETURN object::unflatten({ a: 1, 'a.b': 2 });
+		RETURN object::unflatten({ 'a.0': 1, 'a.b': 2 });
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val("{ a: { b: { c: 1 }, d: 2 }, e: 3 }")?
+		.expect_val("{ a: [1, NONE, { b: 2 }, NONE, NONE, NONE, NONE, NONE, NONE, NONE, 3] }")?
+		.expect_error("Incorrect arguments for function object::unflatten(). Conflicting values found for the key 'a.b'")?
+		.expect_error("Incorrect arguments for function object::unflatten(). Conflicting values found for the key 'a.b'")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_object_values() -> Result<(), Error> {
 	let sql = r#"
@@ -2673,6 +3600,36 @@ async fn function_not() -> Result<(), Error> {
 // parse
 // --------------------------------------------------
 
+#[tokio::test]
+async fn function_parse_bytes() -> Result<(), Error> {
+	let sql = r#"
+		RETURN parse::bytes("256MiB");
+		RETURN parse::bytes("256MB");
+		RETURN parse::bytes("1.5 KiB");
+		RETURN parse::bytes("512");
+		RETURN parse::bytes("12 parsecs");
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["268435456", "256000000", "1536", "512"])?
+		.expect_error("Incorrect arguments for function parse::bytes(). Invalid byte size token '12parsecs' in '12 parsecs'")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_duration() -> Result<(), Error> {
+	let sql = r#"
+		RETURN parse::duration("1.5h");
+		RETURN parse::duration("1d12h");
+		RETURN parse::duration("0.5s");
+		RETURN parse::duration("3h5q");
+	"#;
+	Test::new(sql).await?.expect_vals(&["1h30m", "1d12h", "500ms"])?.expect_error(
+		"Incorrect arguments for function parse::duration(). Invalid duration token '5q' in '3h5q'",
+	)?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_parse_email_host() -> Result<(), Error> {
 	let sql = r#"
@@ -2918,6 +3875,47 @@ async fn function_rand_string() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_rand_string_pattern() -> Result<(), Error> {
+	let sql = r#"
+		RETURN rand::string::pattern('AB-####-??');
+		RETURN rand::string::pattern('\\?\\#\\\\-#');
+		RETURN [rand::string::pattern('??-####', 42), rand::string::pattern('??-####', 42)];
+		RETURN rand::string::pattern('#\\');
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?.as_raw_string();
+	let chars: Vec<char> = tmp.chars().collect();
+	assert_eq!(chars.len(), 10, "{tmp}");
+	assert_eq!(&tmp[..3], "AB-");
+	assert!(chars[3..7].iter().all(char::is_ascii_digit), "{tmp}");
+	assert_eq!(chars[7], '-');
+	assert!(chars[8..].iter().all(char::is_ascii_alphabetic), "{tmp}");
+	//
+	let tmp = test.next()?.result?.as_raw_string();
+	assert_eq!(&tmp[..4], "?#\\-");
+	assert!(tmp[4..].len() == 1 && tmp[4..].chars().all(|c| c.is_ascii_digit()), "{tmp}");
+	//
+	let tmp = test.next()?.result?;
+	let Value::Array(seeded) = tmp else {
+		panic!("{tmp}");
+	};
+	assert_eq!(seeded[0], seeded[1]);
+	assert_eq!(seeded[0].clone().as_raw_string().len(), 7);
+	//
+	let tmp = test.next()?.result;
+	assert!(
+		matches!(
+			&tmp,
+			Err(e) if e.to_string() == "Incorrect arguments for function rand::string::pattern(). The template must not end with an unescaped backslash."
+		),
+		"{tmp:?}"
+	);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_rand_time() -> Result<(), Error> {
 	let sql = r#"
@@ -2987,6 +3985,17 @@ async fn function_rand_uuid_v7() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_rand_uuid_v7_ordered() -> Result<(), Error> {
+	let sql = r#"
+		LET $ids = CREATE |item:5000| SET uuid = rand::uuid::v7() RETURN VALUE uuid;
+		RETURN $ids == array::sort($ids);
+		RETURN array::len(array::distinct($ids));
+	"#;
+	Test::new(sql).await?.expect_val("None")?.expect_val("true")?.expect_val("5000")?;
+	Ok(())
+}
+
 // --------------------------------------------------
 // string
 // --------------------------------------------------
@@ -3099,6 +4108,74 @@ async fn function_string_contains() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_string_count_matches() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::count_matches("aaa", "aa");
+		RETURN string::count_matches("aaa", "aa", { overlapping: true });
+		RETURN string::count_matches("aaa", "aa", { overlapping: false });
+		RETURN string::count_matches("ประเทศไทยประเทศ", "ประเทศ");
+		RETURN string::count_matches("abc", "");
+		RETURN string::count_matches("abc", "", { overlapping: true });
+		RETURN string::count_matches("abc", "d");
+		RETURN string::count_matches("aaa", "aa", { overlapping: "yes" });
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["1", "2", "1", "2", "0", "0", "0"])?
+		.expect_error("Incorrect arguments for function string::count_matches(). Expected the 'overlapping' option to be a bool, found string")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_diff() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::diff("a\nc\n", "a\nb\nc\n");
+		RETURN string::diff("a\nb\nc\n", "a\nc\n");
+		RETURN string::diff("a\nb\nc", "a\nB\nc\nd", { mode: "line" });
+		RETURN string::diff("the quick brown fox", "the slow brown dog", { mode: "word" });
+		RETURN string::diff("same text", "same text");
+		RETURN string::diff("", "");
+		RETURN string::diff("", "new");
+		RETURN string::diff("a", "b", { mode: "char" });
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&[
+			r#"[{ op: 'equal', text: 'a\n' }, { op: 'insert', text: 'b\n' }, { op: 'equal', text: 'c\n' }]"#,
+			r#"[{ op: 'equal', text: 'a\n' }, { op: 'delete', text: 'b\n' }, { op: 'equal', text: 'c\n' }]"#,
+			r#"[{ op: 'equal', text: 'a\n' }, { op: 'delete', text: 'b\nc' }, { op: 'insert', text: 'B\nc\nd' }]"#,
+			"[
+				{ op: 'equal', text: 'the ' }, { op: 'delete', text: 'quick' }, { op: 'insert', text: 'slow' },
+				{ op: 'equal', text: ' brown ' }, { op: 'delete', text: 'fox' }, { op: 'insert', text: 'dog' }
+			]",
+			"[{ op: 'equal', text: 'same text' }]",
+			"[{ op: 'equal', text: '' }]",
+			"[{ op: 'insert', text: 'new' }]",
+		])?
+		.expect_error("Incorrect arguments for function string::diff(). Expected the 'mode' option to be 'line' or 'word', found 'char'")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_extract() -> Result<(), Error> {
+	let sql = r#"
+		LET $text = "Order 66 shipped 3.5 tonnes to tobie@surrealdb.com on day -2, cc jaime@example.co.uk.
+			Release 1.2.3 and build v2 are ignored, as are user@@example.com and @example.com.";
+		RETURN string::extract_numbers($text);
+		RETURN string::extract_emails($text);
+		RETURN string::extract_numbers("");
+		RETURN string::extract_emails("nobody here");
+	"#;
+	Test::new(sql).await?.skip_ok(1)?.expect_vals(&[
+		"[66, 3.5f, -2]",
+		"['tobie@surrealdb.com', 'jaime@example.co.uk']",
+		"[]",
+		"[]",
+	])?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_string_ends_with() -> Result<(), Error> {
 	let sql = r#"
@@ -3560,6 +4637,46 @@ async fn function_parse_is_uuid() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_parse_is_strict() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::is::email("tobie@surrealdb.com");
+		RETURN string::is::email("tobie@surrealdb.com and more");
+		RETURN string::is::email("@surrealdb.com");
+		RETURN string::is::url("http://localhost:8000/sql?a=1#b");
+		RETURN string::is::url(" https://surrealdb.com");
+		RETURN string::is::url("https://surreal\ndb.com");
+		RETURN string::is::url("surrealdb.com");
+		RETURN string::is::uuid("e72bee20-f49b-11ec-b939-0242ac120002");
+		RETURN string::is::uuid("e72bee20f49b11ecb9390242ac120002");
+		RETURN string::is::uuid("{e72bee20-f49b-11ec-b939-0242ac120002}");
+		RETURN string::is::uuid("urn:uuid:e72bee20-f49b-11ec-b939-0242ac120002");
+		RETURN string::is::uuid("e72bee20-f49b-11ec-b939-0242ac12000");
+		RETURN string::is::uuid("e72bee20-f49b-11ec-b939-0242ac120002 ");
+		RETURN string::is::ipv4("192.168.0.1");
+		RETURN string::is::ipv4("192.168.0.256");
+		RETURN string::is::ipv4("192.168.00.1");
+		RETURN string::is::ipv4("::ffff:192.168.0.1");
+		RETURN string::is::ipv6("2001:db8::ff00:42:8329");
+		RETURN string::is::ipv6("::ffff:192.168.0.1");
+		RETURN string::is::ipv6("2001:db8:::1");
+		RETURN string::is::ipv6("192.168.0.1");
+		RETURN string::is::numeric("0123");
+		RETURN string::is::numeric("");
+		RETURN string::is::numeric("12.5");
+		RETURN string::is::numeric(" 12");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_vals(&[
+		"true", "false", "false", "true", "false", "false", "false", "true", "true", "true",
+		"true", "false", "false", "true", "false", "false", "false", "true", "true", "false",
+		"false", "true", "false", "false", "false",
+	])?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_string_join() -> Result<(), Error> {
 	let sql = r#"
@@ -3670,6 +4787,57 @@ async fn function_string_matches() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_string_pad_end() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::pad_end("id", 6, ".");
+		RETURN string::pad_end("id", 7, "-=");
+		RETURN string::pad_end("identifier", 4, " ");
+	"#;
+	Test::new(sql).await?.expect_vals(&["'id....'", "'id-=-=-'", "'identifier'"])?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_pad_start() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::pad_start("42", 6, "0");
+		RETURN string::pad_start("42", 7, "ab");
+		RETURN string::pad_start("1234567", 6, "0");
+		RETURN string::pad_start("42", 6, "");
+	"#;
+	let error =
+		"Incorrect arguments for function string::pad_start(). The fill string must not be empty.";
+	Test::new(sql)
+		.await?
+		.expect_vals(&["'000042'", "'ababa42'", "'1234567'"])?
+		.expect_error(error)?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_string_redact_json() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::redact_json('{"password":"secret","user":"tobie"}', ["password"]);
+		RETURN string::redact_json('{"id":1,"user":{"name":"tobie","token":"abc"}}', ["user.token"]);
+		RETURN string::redact_json('{"keys":[{"secret":"a"},{"secret":"b"}]}', ["keys.1.secret", "keys.0"]);
+		RETURN string::redact_json('{"user":"tobie"}', ["password", "user.token", "user.0"]);
+		RETURN string::redact_json('[1, 2, 3]', ["1"]);
+		RETURN string::redact_json('{"user":', ["user"]);
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&[
+			r#"'{"password":"[REDACTED]","user":"tobie"}'"#,
+			r#"'{"id":1,"user":{"name":"tobie","token":"[REDACTED]"}}'"#,
+			r#"'{"keys":["[REDACTED]",{"secret":"[REDACTED]"}]}'"#,
+			r#"'{"user":"tobie"}'"#,
+			r#"'[1,"[REDACTED]",3]'"#,
+		])?
+		.expect_error("Incorrect arguments for function string::redact_json(). Invalid JSON: EOF while parsing a value at line 1 column 8")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_string_repeat() -> Result<(), Error> {
 	let sql = r#"
@@ -3724,6 +4892,8 @@ async fn function_string_reverse() -> Result<(), Error> {
 		RETURN string::reverse("");
 		RETURN string::reverse("test");
 		RETURN string::reverse("test this string");
+		RETURN string::reverse("café");
+		RETURN string::reverse("a👨‍👩‍👧b");
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
@@ -3738,6 +4908,14 @@ async fn function_string_reverse() -> Result<(), Error> {
 	let tmp = test.next()?.result?;
 	let val = Value::from("gnirts siht tset");
 	assert_eq!(tmp, val);
+	// Combining marks stay attached to the character they modify
+	let tmp = test.next()?.result?;
+	let val = Value::from("e\u{301}fac");
+	assert_eq!(tmp, val);
+	// Emoji sequences are kept whole
+	let tmp = test.next()?.result?;
+	let val = Value::from("b\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}a");
+	assert_eq!(tmp, val);
 	//
 	Ok(())
 }
@@ -3916,6 +5094,29 @@ async fn function_string_starts_with() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_string_to_case() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::to_case("fooBar_baz", "snake");
+		RETURN string::to_case("fooBar_baz", "kebab");
+		RETURN string::to_case("foo bar-baz", "camel");
+		RETURN string::to_case("foo_bar_baz", "pascal");
+		RETURN string::to_case("HTTPServer error", "constant");
+		RETURN string::to_case("hello-world", "title");
+		RETURN string::to_case("test", "shout");
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val("'foo_bar_baz'")?
+		.expect_val("'foo-bar-baz'")?
+		.expect_val("'fooBarBaz'")?
+		.expect_val("'FooBarBaz'")?
+		.expect_val("'HTTP_SERVER_ERROR'")?
+		.expect_val("'Hello World'")?
+		.expect_error("Incorrect arguments for function string::to_case(). Expected one of 'snake', 'kebab', 'camel', 'pascal', 'constant' or 'title', but found 'shout'.")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_string_trim() -> Result<(), Error> {
 	let sql = r#"
@@ -3988,10 +5189,115 @@ async fn function_string_words() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_string_wrap() -> Result<(), Error> {
+	let sql = r#"
+		RETURN string::wrap("the quick brown fox jumps over the lazy dog", 10);
+		RETURN string::wrap("a  few   spaced words", 40);
+		RETURN string::wrap("see https://surrealdb.com/docs now", 8);
+		RETURN string::wrap("see https://surrealdb.com/docs now", 8, { break_long_words: true });
+		RETURN string::wrap("first line\n\nsecond line is longer", 11);
+		RETURN string::wrap("text", 0);
+		RETURN string::wrap("text", 10, { break_long_words: 'yes' });
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_val("'the quick\nbrown fox\njumps over\nthe lazy\ndog'")?
+		.expect_val("'a few spaced words'")?
+		.expect_val("'see\nhttps://surrealdb.com/docs\nnow'")?
+		.expect_val("'see\nhttps://\nsurreald\nb.com/do\ncs now'")?
+		.expect_val("'first line\n\nsecond line\nis longer'")?
+		.expect_error("Incorrect arguments for function string::wrap(). The second argument must be a positive number.")?
+		.expect_error("Incorrect arguments for function string::wrap(). Expected the 'break_long_words' option to be a boolean, found string")?;
+	Ok(())
+}
+
 // --------------------------------------------------
 // time
 // --------------------------------------------------
 
+#[tokio::test]
+async fn function_time_add() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::add(d"2023-01-31T10:00:00Z", 1, "month");
+		RETURN time::add(d"2024-01-31T10:00:00Z", 1, "month");
+		RETURN time::add(d"2024-02-29T00:00:00Z", 1, "year");
+		RETURN time::add(d"2024-02-29T00:00:00Z", 4, "years");
+		RETURN time::add(d"2024-03-31T00:00:00Z", -1, "month");
+		RETURN time::add(d"2024-12-30T00:00:00Z", 1, "week");
+		RETURN time::add(d"2024-12-31T23:00:00Z", 2, "hour");
+		RETURN time::add(d"2024-12-31T23:59:30Z", 90, "second");
+		RETURN time::add(d"2024-01-01T00:00:00Z", 1, "fortnight");
+		RETURN time::add(d"2024-01-01T00:00:00Z", 9223372036854775807, "year");
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&[
+			"d'2023-02-28T10:00:00Z'",
+			"d'2024-02-29T10:00:00Z'",
+			"d'2025-02-28T00:00:00Z'",
+			"d'2028-02-29T00:00:00Z'",
+			"d'2024-02-29T00:00:00Z'",
+			"d'2025-01-06T00:00:00Z'",
+			"d'2025-01-01T01:00:00Z'",
+			"d'2025-01-01T00:01:00Z'",
+		])?
+		.expect_errors(&[
+			"Incorrect arguments for function time::add(). The third argument must be one of 'year', 'month', 'week', 'day', 'hour', 'minute' or 'second'.",
+			"Incorrect arguments for function time::add(). The resulting datetime is out of range.",
+		])?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_time_age() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::age(d"1990-05-15T00:00:00Z", d"2024-05-15T12:00:00Z");
+		RETURN time::age(d"1990-05-15T00:00:00Z", d"2024-05-14T00:00:00Z");
+		RETURN time::age(d"2024-05-03T00:00:00Z", d"2024-05-20T00:00:00Z");
+		RETURN time::age(d"2024-01-31T00:00:00Z", d"2024-03-01T00:00:00Z");
+		RETURN time::age(d"2000-02-29T00:00:00Z", d"2001-02-27T00:00:00Z");
+		RETURN time::age(d"2000-02-29T00:00:00Z", d"2001-02-28T00:00:00Z");
+		RETURN time::age(d"2000-02-29T00:00:00Z", d"2004-02-29T00:00:00Z");
+		RETURN time::age(d"2000-01-01T00:00:00Z").years >= 24;
+		RETURN time::age(d"2024-05-15T00:00:00Z", d"2024-05-14T00:00:00Z");
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&[
+			"{ years: 34, months: 0, days: 0 }",
+			"{ years: 33, months: 11, days: 29 }",
+			"{ years: 0, months: 0, days: 17 }",
+			"{ years: 0, months: 1, days: 1 }",
+			"{ years: 0, months: 11, days: 29 }",
+			"{ years: 1, months: 0, days: 0 }",
+			"{ years: 4, months: 0, days: 0 }",
+			"true",
+		])?
+		.expect_error("Incorrect arguments for function time::age(). The birth date must not be after the reference date.")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_time_business_days_between() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::business_days_between(d"2024-12-02T00:00:00Z", d"2024-12-09T00:00:00Z");
+		RETURN time::business_days_between(d"2024-12-06T23:59:59Z", d"2024-12-09T00:00:00Z");
+		RETURN time::business_days_between(d"2024-12-07T00:00:00Z", d"2024-12-09T00:00:00Z");
+		RETURN time::business_days_between(d"2024-12-02T00:00:00Z", d"2024-12-02T18:00:00Z");
+		RETURN time::business_days_between(d"2024-12-01T00:00:00Z", d"2025-01-01T00:00:00Z");
+		RETURN time::business_days_between(d"2024-12-23T00:00:00Z", d"2024-12-30T00:00:00Z", ["2024-12-25", "2024-12-25", "2024-12-28"]);
+		RETURN time::business_days_between(d"2024-12-30T00:00:00Z", d"2024-12-23T00:00:00Z", [d"2024-12-25T00:00:00Z"]);
+		RETURN time::business_days_between(d"2024-12-23T00:00:00Z", d"2024-12-30T00:00:00Z", ["tomorrow"]);
+	"#;
+	let error = "Incorrect arguments for function time::business_days_between(). The third argument must be an array of datetimes.";
+	Test::new(sql)
+		.await?
+		.expect_vals(&["5", "1", "0", "0", "22", "4", "-4"])?
+		.expect_error(error)?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_time_ceil() -> Result<(), Error> {
 	let sql = r#"
@@ -4034,6 +5340,29 @@ async fn function_time_day() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_time_day_of_year() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::day_of_year();
+		RETURN time::day_of_year(d"1987-06-22T08:30:45Z");
+		RETURN time::day_of_year(d"2024-12-31T12:00:00Z");
+		RETURN time::day_of_year(d"2023-12-31T20:00:00Z", "Asia/Tokyo");
+		RETURN time::day_of_year(d"2024-01-01T02:00:00Z", "America/New_York");
+		RETURN time::day_of_year(d"2024-01-01T00:00:00Z", "Mars/Olympus_Mons");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	assert!(tmp.is_number());
+	//
+	test.expect_vals(&["173", "366", "1", "365"])?;
+	test.expect_error(
+		"Incorrect arguments for function time::day_of_year(). The second argument must be a known timezone, but found 'Mars/Olympus_Mons'.",
+	)?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_time_floor() -> Result<(), Error> {
 	let sql = r#"
@@ -4114,6 +5443,32 @@ async fn function_time_hour() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_time_is() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::is::weekend(d"2024-12-07T12:00:00Z");
+		RETURN time::is::weekend(d"2024-12-06T12:00:00Z");
+		RETURN time::is::weekend(d"2024-12-06T23:30:00Z", "Asia/Tokyo");
+		RETURN time::is::leap_year(d"2024-06-01T00:00:00Z");
+		RETURN time::is::leap_year(d"2023-06-01T00:00:00Z");
+		RETURN time::is::leap_year(d"2100-06-01T00:00:00Z");
+		RETURN time::is::leap_year(d"2023-12-31T20:00:00Z", "Asia/Tokyo");
+		RETURN time::is::business_day(d"2024-12-25T12:00:00Z");
+		RETURN time::is::business_day(d"2024-12-25T12:00:00Z", [d"2024-12-25T00:00:00Z", "2024-12-26"]);
+		RETURN time::is::business_day(d"2024-12-07T12:00:00Z");
+		RETURN time::is::business_day(d"2024-12-24T20:00:00Z", ["2024-12-25"], "Asia/Tokyo");
+		RETURN time::is::business_day(d"2024-12-24T20:00:00Z", [], "Mars/Olympus_Mons");
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&[
+			"true", "false", "true", "true", "false", "false", "true", "true", "false", "false",
+			"false",
+		])?
+		.expect_error("Incorrect arguments for function time::is::business_day(). The third argument must be a known timezone, but found 'Mars/Olympus_Mons'.")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_time_min() -> Result<(), Error> {
 	let sql = r#"
@@ -4245,6 +5600,26 @@ async fn function_time_now() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_time_quarter() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::quarter();
+		RETURN time::quarter(d"1987-06-22T08:30:45Z");
+		RETURN time::quarter(d"2024-01-01T00:00:00Z");
+		RETURN time::quarter(d"2024-10-01T00:00:00Z");
+		RETURN time::quarter(d"2024-12-31T23:59:59Z");
+		RETURN time::quarter(d"2024-12-31T20:00:00Z", "Asia/Tokyo");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	let tmp = test.next()?.result?;
+	assert!(tmp.is_number());
+	//
+	test.expect_vals(&["2", "1", "4", "4", "1"])?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_time_round() -> Result<(), Error> {
 	let sql = r#"
@@ -4282,6 +5657,27 @@ async fn function_time_second() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_time_subtract() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::subtract(d"2024-03-31T12:00:00Z", 1, "month");
+		RETURN time::subtract(d"2023-03-31T12:00:00Z", 1, "month");
+		RETURN time::subtract(d"2026-02-28T00:00:00Z", 2, "year");
+		RETURN time::subtract(d"2024-02-29T00:00:00Z", 1, "year");
+		RETURN time::subtract(d"2024-03-01T00:00:00Z", 1, "day");
+		RETURN time::subtract(d"2024-01-01T00:00:30Z", 1, "minute");
+	"#;
+	Test::new(sql).await?.expect_vals(&[
+		"d'2024-02-29T12:00:00Z'",
+		"d'2023-02-28T12:00:00Z'",
+		"d'2024-02-28T00:00:00Z'",
+		"d'2023-02-28T00:00:00Z'",
+		"d'2024-02-29T00:00:00Z'",
+		"d'2023-12-31T23:59:30Z'",
+	])?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_time_unix() -> Result<(), Error> {
 	let sql = r#"
@@ -4323,6 +5719,11 @@ async fn function_time_week() -> Result<(), Error> {
 	let sql = r#"
 		RETURN time::week();
 		RETURN time::week(d"1987-06-22T08:30:45Z");
+		RETURN time::week(d"2024-12-30T12:00:00Z");
+		RETURN time::week(d"2021-01-03T12:00:00Z");
+		RETURN time::week(d"2024-12-29T20:00:00Z");
+		RETURN time::week(d"2024-12-29T20:00:00Z", "Asia/Tokyo");
+		RETURN time::week(d"2021-01-04T02:00:00Z", "America/New_York");
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
@@ -4332,6 +5733,8 @@ async fn function_time_week() -> Result<(), Error> {
 	let tmp = test.next()?.result?;
 	let val = Value::from(26);
 	assert_eq!(tmp, val);
+	// Late December days can belong to the first week of the next year
+	test.expect_vals(&["1", "53", "52", "1", "53"])?;
 	//
 	Ok(())
 }
@@ -4490,6 +5893,40 @@ async fn function_type_bool() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_coerce() -> Result<(), Error> {
+	let sql = r#"
+		RETURN type::coerce("42", "int");
+		RETURN type::coerce(13, "float");
+		RETURN type::coerce("13.5", "decimal");
+		RETURN type::coerce(123, "string");
+		RETURN type::coerce("true", "bool");
+		RETURN type::coerce("2024-01-01T00:00:00Z", "datetime");
+		RETURN type::coerce("1h30m", "duration");
+		RETURN type::coerce(["1", "2"], "array<int>");
+		RETURN type::coerce("abc", "int");
+		RETURN type::coerce("soon", "duration");
+		RETURN type::coerce(1, "integer");
+	"#;
+	let mut test = Test::new(sql).await?;
+	//
+	test.expect_val("42")?;
+	test.expect_val("13f")?;
+	test.expect_val("13.5dec")?;
+	test.expect_val("'123'")?;
+	test.expect_val("true")?;
+	test.expect_val("d'2024-01-01T00:00:00Z'")?;
+	test.expect_val("1h30m")?;
+	test.expect_val("[1, 2]")?;
+	test.expect_error("Expected a int but cannot convert 'abc' into a int")?;
+	test.expect_error("Expected a duration but cannot convert 'soon' into a duration")?;
+	test.expect_error(
+		"Incorrect arguments for function type::coerce(). The second argument must be a valid type, but found 'integer'.",
+	)?;
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_datetime() -> Result<(), Error> {
 	let sql = r#"
@@ -4766,6 +6203,35 @@ async fn function_type_is_geometry() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_type_is_geometry_object() -> Result<(), Error> {
+	let sql = r#"
+		LET $lng = -0.118092;
+		LET $point = { type: "Point", coordinates: [$lng, 51.509865] };
+		LET $invalid = { type: "Point", coordinates: [$lng, "north"] };
+		LET $line = { type: "LineString", coordinates: [[$lng, 0], [10, 10]] };
+		LET $extra = { type: "Point", coordinates: [$lng, 0], name: "origin" };
+		RETURN [type::is::object($point), type::is::geometry($point), type::is::point($point), type::is::line($point)];
+		RETURN [type::is::geometry($invalid), type::is::point($invalid)];
+		RETURN [type::is::geometry($line), type::is::line($line), type::is::polygon($line)];
+		RETURN [type::is::geometry($extra), type::is::point($extra)];
+		RETURN type::is::geometry({ type: "Circle", coordinates: [0, 0] });
+		RETURN type::is::polygon({ type: "Polygon", coordinates: [[[0, 0], [1, 0], [1, 1], [0, 0]]] });
+		RETURN type::is::collection({ type: "GeometryCollection", geometries: [$point, $line] });
+	"#;
+	Test::new(sql)
+		.await?
+		.expect_vals(&["NONE", "NONE", "NONE", "NONE", "NONE"])?
+		.expect_val("[true, true, true, false]")?
+		.expect_val("[false, false]")?
+		.expect_val("[true, true, false]")?
+		.expect_val("[false, false]")?
+		.expect_val("false")?
+		.expect_val("true")?
+		.expect_val("true")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_type_is_int() -> Result<(), Error> {
 	let sql = r#"
@@ -5177,6 +6643,9 @@ async fn function_type_table() -> Result<(), Error> {
 	let sql = r#"
 		RETURN type::table("person");
 		RETURN type::table("animal");
+		RETURN type::table(person:tobie);
+		RETURN type::table("");
+		RETURN type::table(NONE);
 	"#;
 	let mut test = Test::new(sql).await?;
 	//
@@ -5188,6 +6657,31 @@ async fn function_type_table() -> Result<(), Error> {
 	let val = Value::Table("animal".into());
 	assert_eq!(tmp, val);
 	//
+	let tmp = test.next()?.result?;
+	let val = Value::Table("person".into());
+	assert_eq!(tmp, val);
+	//
+	let error = "Incorrect arguments for function type::table(). The table name must not be empty.";
+	test.expect_error(error)?.expect_error(error)?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_type_table_select() -> Result<(), Error> {
+	let sql = r#"
+		CREATE person:tobie, animal:koala;
+		LET $name = "person";
+		SELECT VALUE id FROM type::table($name);
+		SELECT VALUE type::field("id") FROM type::table("ani" + "mal");
+		SELECT VALUE type::field("id..") FROM person;
+	"#;
+	let mut test = Test::new(sql).await?;
+	test.skip_ok(2)?.expect_val("[person:tobie]")?.expect_val("[animal:koala]")?;
+	// Invalid field paths are rejected
+	let tmp = test.next()?.result;
+	assert!(tmp.is_err(), "{tmp:?}");
+	//
 	Ok(())
 }
 